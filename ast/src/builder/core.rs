@@ -1,5 +1,6 @@
+use super::shared::process_shared_files;
 use super::utils::*;
-use crate::lang::{graphs::{Graph, Edge, EdgeType}, linker::link_tests, NodeRef};
+use crate::lang::{graphs::{Graph, GraphEditor, Edge, EdgeType}, linker::{link_e2e_tests, link_e2e_tests_pages, link_tests}, docker, lockfile, notebook, NodeRef};
 #[cfg(feature = "neo4j")]
 use crate::lang::graphs::Neo4jGraph;
 #[cfg(feature = "neo4j")]
@@ -31,6 +32,15 @@ impl Repo {
         self.build_graph_inner().await
     }
     pub async fn build_graph_inner<G: Graph>(&self) -> Result<G> {
+        let (graph, _) = self.build_repo_graph(true).await?;
+        Ok(graph)
+    }
+    // the graph of this repo's language, and the files it read. A build of several
+    // repos runs the passes over the shared files once, on the merged graph
+    pub(crate) async fn build_repo_graph<G: Graph>(
+        &self,
+        shared: bool,
+    ) -> Result<(G, Vec<(String, String)>)> {
         let graph_root = strip_tmp(&self.root).display().to_string();
         let mut graph = G::new(graph_root, self.lang.kind.clone());
         let mut stats = std::collections::HashMap::new();
@@ -86,6 +96,9 @@ impl Repo {
     #[cfg(feature = "neo4j")]
     if let Some(ctx) = &mut streaming_ctx { let (dn,de)=drain_deltas(); let _ = ctx.uploader.flush_stage(&ctx.neo, "endpoints", &dn, &de).await; }
         self.finalize_graph(&mut graph, &filez, &mut stats).await?;
+        if shared {
+            process_shared_files(&mut graph, &filez);
+        }
     #[cfg(feature = "neo4j")]
     if let Some(ctx) = &mut streaming_ctx { let (dn,de)=drain_deltas(); let _ = ctx.uploader.flush_stage(&ctx.neo, "finalize", &dn, &de).await; }
         let graph = filter_by_revs(
@@ -103,7 +116,7 @@ impl Repo {
         stats.insert("total_nodes".to_string(), num_of_nodes as usize);
         stats.insert("total_edges".to_string(), num_of_edges as usize);
         self.send_status_with_stats(stats);
-        Ok(graph)
+        Ok((graph, filez))
     }
 }
 
@@ -150,13 +163,15 @@ impl Repo {
                 let parent = parts.join("/");
                 (NodeType::Directory, parent)
             } else {
-                let repo_file = strip_tmp(&self.root).display().to_string();
-                (NodeType::Repository, repo_file)
+                self.root_parent()
             };
 
             let dir_name = dir_no_tmp.rsplit('/').next().unwrap().to_string();
             let mut dir_data = NodeData::in_file(&dir_no_tmp);
             dir_data.name = dir_name;
+            if self.project.is_some() {
+                add_project_meta(&mut dir_data, dir);
+            }
 
             graph.add_node_with_parent(NodeType::Directory, dir_data, parent_type, &parent_file);
         }
@@ -187,6 +202,12 @@ impl Repo {
             } else {
                 match std::fs::read_to_string(&filepath) {
                     Ok(content) => {
+                        // notebooks are parsed as the python source of their code cells
+                        let content = if notebook::is_notebook(&file_name) {
+                            notebook::notebook_source(&content).unwrap_or_default()
+                        } else {
+                            content
+                        };
                         ret.push((file_name, content.clone()));
                         content
                     }
@@ -281,6 +302,8 @@ impl Repo {
             }
         }
 
+        lib_count += self.process_lockfiles(graph, filez);
+
         let mut stats = std::collections::HashMap::new();
         stats.insert("libraries".to_string(), lib_count);
         self.send_status_with_stats(stats);
@@ -289,6 +312,99 @@ impl Repo {
         info!("=> got {} libs", lib_count);
         Ok(())
     }
+    fn process_lockfiles<G: Graph>(&self, graph: &mut G, filez: &[(String, String)]) -> usize {
+        info!("=> get_lockfiles...");
+        let mut lib_count = 0;
+        for (lockfile_name, code) in filez {
+            if !lockfile::is_lockfile_for(&self.lang.kind, lockfile_name) {
+                continue;
+            }
+            let dir = docker::parent_dir(lockfile_name);
+            let manifest = lockfile::manifest_name(lockfile_name).and_then(|name| {
+                let path = docker::join_path(&dir, name);
+                filez.iter().find(|(f, _)| f == &path).map(|(_, c)| c.as_str())
+            });
+            let Some(lock) = lockfile::parse_lockfile(lockfile_name, code, manifest) else {
+                continue;
+            };
+
+            // the libraries of the manifest next to the lockfile
+            let manifest_libs: Vec<NodeData> = graph
+                .find_nodes_by_type(NodeType::Library)
+                .into_iter()
+                .filter(|l| docker::parent_dir(&l.file) == dir && !lockfile::is_lockfile(&l.file))
+                .collect();
+            let manifest_names: Vec<String> = manifest_libs.iter().map(|l| l.name.clone()).collect();
+            let direct = lock.direct_dependencies(&manifest_names);
+
+            // a manifest library is the locked package of the same name
+            let mut from_manifest = std::collections::HashMap::new();
+            for lib in &manifest_libs {
+                let same_name = |p: &&lockfile::LockedPackage| {
+                    lockfile::normalize(&p.name) == lockfile::normalize(&lib.name)
+                };
+                let index = lock
+                    .packages
+                    .iter()
+                    .position(|p| same_name(&p) && lockfile::is_direct(p, &direct))
+                    .or_else(|| lock.packages.iter().position(|p| same_name(&p)));
+                if let Some(index) = index {
+                    from_manifest.entry(index).or_insert_with(|| lib.clone());
+                }
+            }
+
+            let mut nodes = Vec::new();
+            for (i, package) in lock.packages.iter().enumerate() {
+                let is_direct = lockfile::is_direct(package, &direct).to_string();
+                let node = match from_manifest.get(&i) {
+                    Some(lib) => {
+                        graph.set_node_meta(NodeType::Library, lib, "resolved_version", &package.version);
+                        graph.set_node_meta(NodeType::Library, lib, "direct", &is_direct);
+                        graph.set_node_meta(NodeType::Library, lib, "lockfile", lockfile_name);
+                        lib.clone()
+                    }
+                    None => {
+                        let mut lib = NodeData::name_file(&package.name, lockfile_name);
+                        lib.start = package.start;
+                        lib.end = package.start;
+                        lib.body = format!("{} {}", package.name, package.version);
+                        lib.add_version(&package.version);
+                        lib.meta
+                            .insert("resolved_version".to_string(), package.version.clone());
+                        lib.meta.insert("direct".to_string(), is_direct);
+                        graph.add_node_with_parent(
+                            NodeType::Library,
+                            lib.clone(),
+                            NodeType::File,
+                            lockfile_name,
+                        );
+                        lib_count += 1;
+                        lib
+                    }
+                };
+                nodes.push(node);
+            }
+
+            let mut edges = HashSet::new();
+            for (i, package) in lock.packages.iter().enumerate() {
+                for dep in &package.dependencies {
+                    let Some(j) = lock.resolve(dep) else {
+                        continue;
+                    };
+                    if i != j && edges.insert((i, j)) {
+                        graph.add_edge(Edge::library_depends_on(&nodes[i], &nodes[j]));
+                    }
+                }
+            }
+            info!(
+                "=> got {} locked packages and {} dependencies in {}",
+                lock.packages.len(),
+                edges.len(),
+                lockfile_name
+            );
+        }
+        lib_count
+    }
     fn process_import_sections<G: Graph>(
         &self,
         graph: &mut G,
@@ -364,17 +480,25 @@ impl Repo {
     }
     async fn add_repository_and_language_nodes<G: Graph>(&self, graph: &mut G) -> Result<()> {
         info!("Root: {:?}", self.root);
-        let commit_hash = get_commit_hash(&self.root.to_str().unwrap()).await?;
+        // sub-projects of a monorepo all hang off the one Repository
+        let repo_root = match &self.project {
+            Some(project) => project.repo_root.clone(),
+            None => self.root.clone(),
+        };
+        let commit_hash = get_commit_hash(&repo_root.to_str().unwrap()).await?;
         info!("Commit(commit_hash): {:?}", commit_hash);
 
         let (org, repo_name) = if !self.url.is_empty() {
             let gurl = GitUrl::parse(&self.url)?;
             (gurl.owner.unwrap_or_default(), gurl.name)
+        } else if self.project.is_some() {
+            let dir_name = repo_root.file_name().unwrap_or_default();
+            ("".to_string(), dir_name.to_string_lossy().to_string())
         } else {
             ("".to_string(), format!("{:?}", self.lang.kind))
         };
-        info!("add repository... {}", self.root.display());
-        let repo_file = strip_tmp(&self.root).display().to_string();
+        info!("add repository... {}", repo_root.display());
+        let repo_file = strip_tmp(&repo_root).display().to_string();
         let mut repo_data = NodeData {
             name: format!("{}/{}", org, repo_name),
             file: repo_file.clone(),
@@ -384,18 +508,29 @@ impl Repo {
         repo_data.add_source_link(&self.url);
         graph.add_node_with_parent(NodeType::Repository, repo_data, NodeType::Repository, "");
 
+        // the Directories down to the project root
+        if let Ok(project_path) = self.root.strip_prefix(&repo_root) {
+            let mut parent = (NodeType::Repository, repo_file.clone());
+            let mut dir = repo_root.clone();
+            for part in project_path.components() {
+                dir.push(part);
+                let dir_file = strip_tmp(&dir).display().to_string();
+                let mut dir_data = NodeData::in_file(&dir_file);
+                dir_data.name = part.as_os_str().to_string_lossy().to_string();
+                add_project_meta(&mut dir_data, &dir);
+                graph.add_node_with_parent(NodeType::Directory, dir_data, parent.0, &parent.1);
+                parent = (NodeType::Directory, dir_file);
+            }
+        }
+
         debug!("add language...");
         let lang_data = NodeData {
             name: self.lang.kind.to_string(),
             file: strip_tmp(&self.root).display().to_string(),
             ..Default::default()
         };
-        graph.add_node_with_parent(
-            NodeType::Language,
-            lang_data,
-            NodeType::Repository,
-            &repo_file,
-        );
+        let (parent_type, parent_file) = self.root_parent();
+        graph.add_node_with_parent(NodeType::Language, lang_data, parent_type, &parent_file);
 
         let mut stats = std::collections::HashMap::new();
         stats.insert("repository".to_string(), 1);
@@ -537,9 +672,16 @@ impl Repo {
                 }
             }
             let q = self.lang.lang().data_model_query();
-            let structs = self
+            let structs: Vec<NodeData> = self
                 .lang
-                .get_query_opt::<G>(q, &code, &filename, NodeType::DataModel)?;
+                .get_query_opt::<G>(q, &code, &filename, NodeType::DataModel)?
+                .into_iter()
+                .map(|st| {
+                    self.lang.lang().resolve_data_model(st, &|name| {
+                        graph.find_nodes_by_name(NodeType::Class, name).into_iter().next()
+                    })
+                })
+                .collect();
             datamodel_count += structs.len();
 
             for st in &structs {
@@ -592,6 +734,9 @@ impl Repo {
                 .get_functions_and_tests(&code, &filename, graph, &self.lsp_tx)?;
             function_count += funcs.len();
             graph.add_functions(funcs.clone());
+            self.lang
+                .lang()
+                .add_annotation_models(&mut GraphEditor(graph), &funcs);
 
             let func_nodes: Vec<NodeData> = funcs.iter().map(|f| f.0.clone()).collect();
             let nested_pairs = self.lang.find_nested_functions(&func_nodes);
@@ -676,6 +821,15 @@ impl Repo {
             }
         }
 
+        let route_count = self
+            .lang
+            .lang()
+            .add_extra_pages(&mut GraphEditor(graph), filez);
+        if route_count > 0 {
+            info!("=> got {} route pages", route_count);
+        }
+        page_count += route_count;
+
         let mut _i = 0;
         info!("=> get_component_templates");
         for (filename, code) in filez {
@@ -744,6 +898,7 @@ impl Repo {
         let total = filez.len();
 
         info!("=> get_endpoints...");
+        let mut endpoints = Vec::new();
         for (filename, code) in filez {
             _i += 1;
             if _i % 10 == 0 || _i == total {
@@ -762,13 +917,19 @@ impl Repo {
                 continue;
             }
             debug!("get_endpoints in {:?}", filename);
-            let endpoints =
+            endpoints.extend(
                 self.lang
-                    .collect_endpoints(&code, &filename, Some(graph), &self.lsp_tx)?;
-            endpoint_count += endpoints.len();
-
-            graph.add_endpoints(endpoints);
+                    .collect_endpoints(&code, &filename, Some(graph), &self.lsp_tx)?,
+            );
         }
+        let endpoints = self.lang.lang().mount_endpoints(endpoints, filez);
+        endpoint_count += endpoints.len();
+        graph.add_endpoints(endpoints);
+
+        endpoint_count += self
+            .lang
+            .lang()
+            .add_extra_endpoints(&mut GraphEditor(graph), filez);
 
         let mut stats = std::collections::HashMap::new();
         stats.insert("endpoints".to_string(), endpoint_count);
@@ -882,7 +1043,18 @@ impl Repo {
             info!("=> got {} function calls", _i);
         }
 
-        link_tests(graph)?;
+        self.lang.lang().add_script_links(&mut GraphEditor(graph), filez);
+
+        let root = strip_tmp(&self.root).display().to_string();
+        self.lang.lang().add_jobs(&mut GraphEditor(graph), filez, &root);
+
+        if self.lang.lang().links_tests_by_request() {
+            // integration tests were linked to the endpoints they request while parsing
+            link_e2e_tests_pages(graph)?;
+            link_e2e_tests(graph)?;
+        } else {
+            link_tests(graph)?;
+        }
 
         self.lang
             .lang()
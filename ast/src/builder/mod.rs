@@ -1,5 +1,6 @@
 pub mod core;
 pub mod progress;
+pub mod shared;
 pub mod utils;
 #[cfg(feature = "neo4j")]
 pub mod streaming;
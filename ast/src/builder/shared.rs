@@ -0,0 +1,284 @@
+use crate::lang::graphs::{Graph, NodeType};
+use crate::lang::{db_schema, docker, graphql, notebook, openapi, proto, Edge, NodeData};
+use lsp::language::PROGRAMMING_LANGUAGES;
+use tracing::info;
+
+// the files no language stack owns (like .proto) are read by every language Repo,
+// so these passes run once per build, on the merged graph of all the languages
+pub fn process_shared_files<G: Graph>(graph: &mut G, filez: &[(String, String)]) {
+    // before the other passes add data models, so only the code's models are matched to tables
+    process_db_tables(graph, filez);
+    process_proto(graph, filez);
+    process_graphql(graph, filez);
+    process_openapi(graph, filez);
+    process_notebook_cells(graph, filez);
+    process_services(graph, filez);
+}
+
+fn is_source_file(file: &str) -> bool {
+    PROGRAMMING_LANGUAGES.iter().any(|l| l.is_source_file(file))
+}
+
+fn process_db_tables<G: Graph>(graph: &mut G, filez: &[(String, String)]) {
+    info!("=> get_db_tables...");
+    let models: Vec<_> = graph
+        .find_nodes_by_type(NodeType::DataModel)
+        .into_iter()
+        .filter(|m| is_source_file(&m.file))
+        .collect();
+    let tables = db_schema::collect_tables(filez);
+    let mut table_nodes = Vec::new();
+    for table in &tables {
+        let nd = table.to_node_data();
+        // rails db/schema.rb tables are already data models, so just add the columns
+        match graph.find_node_by_name_in_file(NodeType::DataModel, &nd.name, &nd.file) {
+            Some(existing) => {
+                for (key, value) in &nd.meta {
+                    graph.set_node_meta(NodeType::DataModel, &existing, key, value);
+                }
+                table_nodes.push(existing);
+            }
+            None => {
+                graph.add_node_with_parent(NodeType::DataModel, nd.clone(), NodeType::File, &nd.file);
+                table_nodes.push(nd);
+            }
+        }
+    }
+    let table_node = |name: &str| table_nodes.iter().find(|t| t.name.eq_ignore_ascii_case(name));
+    for (table, source) in tables.iter().zip(table_nodes.iter()) {
+        for fk in &table.foreign_keys {
+            if let Some(target) = table_node(&fk.table) {
+                graph.add_edge(Edge::references(source, target));
+            }
+        }
+    }
+    for model in &models {
+        if table_nodes.iter().any(|t| t.name == model.name && t.file == model.file) {
+            continue;
+        }
+        if let Some(target) = db_schema::find_model_table(model, &tables).and_then(|t| table_node(&t.name)) {
+            graph.add_edge(Edge::references(model, target));
+        }
+    }
+    info!("=> got {} db tables", tables.len());
+}
+
+fn process_proto<G: Graph>(graph: &mut G, filez: &[(String, String)]) {
+    info!("=> get_grpc_endpoints...");
+    let mut endpoint_count = 0;
+    for (filename, code) in filez {
+        if proto::is_proto_file(filename) {
+            let parsed = proto::parse_proto(code, filename);
+            for m in parsed.messages {
+                let file = m.file.clone();
+                graph.add_node_with_parent(NodeType::DataModel, m, NodeType::File, &file);
+            }
+            endpoint_count += parsed.rpcs.len();
+            let endpoints = parsed
+                .rpcs
+                .into_iter()
+                .map(|rpc| {
+                    let handler = proto::find_grpc_handler(&rpc, filez, graph);
+                    (rpc, handler)
+                })
+                .collect();
+            graph.add_endpoints(endpoints);
+        } else if is_source_file(filename) {
+            let functions = graph.find_nodes_by_file_ends_with(NodeType::Function, filename);
+            for (req, func) in proto::find_grpc_requests(code, filename, &functions) {
+                graph.add_node(NodeType::Request, req.clone());
+                graph.add_edge(Edge::calls(NodeType::Function, &func, NodeType::Request, &req));
+            }
+        }
+    }
+    info!("=> got {} grpc endpoints", endpoint_count);
+}
+
+fn process_graphql<G: Graph>(graph: &mut G, filez: &[(String, String)]) {
+    info!("=> get_graphql_endpoints...");
+    let mut endpoint_count = 0;
+    let operations = graphql::collect_graphql_operations(filez);
+    for (filename, code) in filez {
+        let is_source = is_source_file(filename);
+        if !graphql::is_graphql_file(filename) && !is_source {
+            continue;
+        }
+        if graphql::has_graphql_schema(filename, code) {
+            let schema = graphql::parse_graphql_schema(code, filename);
+            for t in schema.data_models {
+                let file = t.file.clone();
+                graph.add_node_with_parent(NodeType::DataModel, t, NodeType::File, &file);
+            }
+            endpoint_count += schema.fields.len();
+            let endpoints = schema
+                .fields
+                .into_iter()
+                .map(|field| {
+                    let handler = graphql::find_graphql_handler(&field, filez, graph);
+                    (field, handler)
+                })
+                .collect();
+            graph.add_endpoints(endpoints);
+        }
+        if is_source {
+            let functions = graph.find_nodes_by_file_ends_with(NodeType::Function, filename);
+            for (req, func) in graphql::find_graphql_requests(filename, &functions, &operations) {
+                graph.add_node(NodeType::Request, req.clone());
+                graph.add_edge(Edge::calls(NodeType::Function, &func, NodeType::Request, &req));
+            }
+        }
+    }
+    info!("=> got {} graphql endpoints", endpoint_count);
+}
+
+fn process_openapi<G: Graph>(graph: &mut G, filez: &[(String, String)]) {
+    info!("=> get_openapi_endpoints...");
+    let mut endpoint_count = 0;
+    for (filename, code) in filez {
+        let Some(spec) = openapi::parse_openapi(code, filename) else {
+            continue;
+        };
+        for schema in spec.schemas {
+            let file = schema.file.clone();
+            graph.add_node_with_parent(NodeType::DataModel, schema, NodeType::File, &file);
+        }
+        endpoint_count += spec.endpoints.len();
+        for endpoint in spec.endpoints {
+            graph.add_node_with_parent(
+                NodeType::Endpoint,
+                endpoint.clone(),
+                NodeType::File,
+                filename,
+            );
+            // the request and response schemas
+            for schema in ["request", "response"]
+                .iter()
+                .filter_map(|k| endpoint.meta.get(*k))
+            {
+                let name = schema.trim_end_matches("[]");
+                if let Some(dm) = graph.find_node_by_name_in_file(NodeType::DataModel, name, filename) {
+                    graph.add_edge(Edge::contains(NodeType::Endpoint, &endpoint, NodeType::DataModel, &dm));
+                }
+            }
+        }
+    }
+    info!("=> got {} openapi endpoints", endpoint_count);
+}
+
+// the nodes of a notebook get the cell they are in, and their line in that cell
+fn process_notebook_cells<G: Graph>(graph: &mut G, filez: &[(String, String)]) {
+    info!("=> get_notebook_cells...");
+    for (filename, code) in filez {
+        if !notebook::is_notebook(filename) {
+            continue;
+        }
+        for nt in [
+            NodeType::Function,
+            NodeType::Class,
+            NodeType::DataModel,
+            NodeType::Var,
+            NodeType::Request,
+            NodeType::Endpoint,
+        ] {
+            for node in graph.find_nodes_by_file_ends_with(nt.clone(), filename) {
+                if let Some((cell, line)) = notebook::cell_position(code, node.start) {
+                    graph.set_node_meta(nt.clone(), &node, "cell", &cell.to_string());
+                    graph.set_node_meta(nt.clone(), &node, "cell_line", &line.to_string());
+                }
+            }
+        }
+    }
+}
+
+fn process_services<G: Graph>(graph: &mut G, filez: &[(String, String)]) {
+    info!("=> get_services...");
+    let mut services = Vec::new();
+    for (filename, code) in filez {
+        services.extend(docker::parse_compose(code, filename));
+    }
+    let mut dockerfiles = std::collections::HashMap::new();
+    for (filename, code) in filez {
+        if !docker::is_dockerfile(filename) {
+            continue;
+        }
+        dockerfiles.insert(filename.clone(), docker::parse_dockerfile(code));
+        if !services
+            .iter()
+            .any(|s| s.dockerfile.as_ref() == Some(filename))
+        {
+            services.push(docker::dockerfile_service(filename, code));
+        }
+    }
+
+    for service in services.iter_mut() {
+        let dockerfile = service.dockerfile.as_ref().and_then(|d| dockerfiles.get(d));
+        if let Some(df) = dockerfile.filter(|df| !df.expose.is_empty()) {
+            service
+                .node
+                .meta
+                .insert("expose".to_string(), df.expose.join(","));
+        }
+        let file = service.node.file.clone();
+        graph.add_node_with_parent(NodeType::Service, service.node.clone(), NodeType::File, &file);
+    }
+
+    let repositories = graph.find_nodes_by_type(NodeType::Repository);
+    let directories = graph.find_nodes_by_type(NodeType::Directory);
+    let files = graph.find_nodes_by_type(NodeType::File);
+    for service in &services {
+        for dep in &service.depends_on {
+            if let Some(target) = services
+                .iter()
+                .find(|s| &s.node.name == dep && s.node.file == service.node.file)
+            {
+                graph.add_edge(Edge::depends_on(&service.node, &target.node));
+            }
+        }
+
+        let Some(context) = &service.build_context else {
+            continue;
+        };
+        if let Some(repo) = repositories.iter().find(|r| &r.file == context) {
+            graph.add_edge(Edge::builds(&service.node, NodeType::Repository, repo));
+        } else if let Some(dir) = directories.iter().find(|d| &d.file == context) {
+            graph.add_edge(Edge::builds(&service.node, NodeType::Directory, dir));
+        }
+
+        let in_context = |file: &str| file.starts_with(&format!("{}/", context));
+        let dockerfile = service.dockerfile.as_ref().and_then(|d| dockerfiles.get(d));
+        let (args, workdir) = match dockerfile {
+            Some(df) => (df.run_args(service), df.workdir.as_deref()),
+            None => (Vec::new(), None),
+        };
+        let mut found = false;
+        for target in docker::entry_targets(&args, workdir) {
+            let suffix = format!("/{}", target.path);
+            let Some(file) = files
+                .iter()
+                .find(|f| in_context(&f.file) && f.file.ends_with(&suffix))
+            else {
+                continue;
+            };
+            found = true;
+            graph.add_edge(Edge::runs(&service.node, NodeType::File, file));
+            let function = target.function.as_ref().and_then(|name| {
+                graph.find_node_by_name_in_file(NodeType::Function, name, &file.file)
+            });
+            if let Some(function) = function {
+                graph.add_edge(Edge::runs(&service.node, NodeType::Function, &function));
+            }
+        }
+        // compiled binaries, like a go server, run the one main in their build context
+        if !found && dockerfile.is_some() {
+            let mains: Vec<NodeData> = graph
+                .find_nodes_by_name(NodeType::Function, "main")
+                .into_iter()
+                .filter(|f| in_context(&f.file))
+                .collect();
+            if let [main] = mains.as_slice() {
+                graph.add_edge(Edge::runs(&service.node, NodeType::Function, main));
+            }
+        }
+    }
+    info!("=> got {} services", services.len());
+}
@@ -1,13 +1,23 @@
 use crate::lang::{asg::NodeData, graphs::NodeType};
 use crate::lang::{Graph, Node};
-use crate::repo::{check_revs_files, Repo};
+use crate::repo::{check_revs_files, project_files, Repo};
 use crate::utils::create_node_key;
 use lsp::{strip_tmp, Language};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub const MAX_FILE_SIZE: u64 = 500_000;
 
+// marks the root Directory of a monorepo sub-project with its package files
+pub fn add_project_meta(dir_data: &mut NodeData, dir: &Path) {
+    let project_files = project_files(dir);
+    if !project_files.is_empty() {
+        dir_data
+            .meta
+            .insert("project".to_string(), project_files.join(","));
+    }
+}
+
 pub fn filter_by_revs<G: Graph>(root: &str, revs: Vec<String>, graph: G, lang_kind: Language) -> G {
     if revs.is_empty() {
         return graph;
@@ -116,8 +126,16 @@ impl Repo {
             let fin = strip_tmp(&PathBuf::from(dirpath)).display().to_string();
             (NodeType::Directory, fin)
         } else {
-            let repo_file = strip_tmp(&self.root).display().to_string();
-            (NodeType::Repository, repo_file)
+            self.root_parent()
+        }
+    }
+    // top level files and directories belong to the Repository, or to the
+    // project Directory when the repo is a sub-project of a monorepo
+    pub fn root_parent(&self) -> (NodeType, String) {
+        let root_file = strip_tmp(&self.root).display().to_string();
+        match &self.project {
+            Some(project) if project.repo_root != self.root => (NodeType::Directory, root_file),
+            _ => (NodeType::Repository, root_file),
         }
     }
 }
@@ -251,6 +251,9 @@ impl FromStr for NodeType {
             "Feature" => Ok(NodeType::Feature),
             "Page" => Ok(NodeType::Page),
             "Var" => Ok(NodeType::Var),
+            "Service" => Ok(NodeType::Service),
+            "Job" => Ok(NodeType::Job),
+            "Queue" => Ok(NodeType::Queue),
             _ => Err(Error::Custom(format!("Invalid NodeType string: {}", s))),
         }
     }
@@ -277,6 +280,9 @@ impl ToString for NodeType {
             NodeType::Feature => "Feature".to_string(),
             NodeType::Page => "Page".to_string(),
             NodeType::Var => "Var".to_string(),
+            NodeType::Service => "Service".to_string(),
+            NodeType::Job => "Job".to_string(),
+            NodeType::Queue => "Queue".to_string(),
         }
     }
 }
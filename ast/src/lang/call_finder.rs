@@ -19,7 +19,7 @@ pub fn node_data_finder<G: Graph>(
 
 pub fn func_target_file_finder<G: Graph>(
     func_name: &str,
-    _operand: &Option<String>,
+    operand: &Option<String>,
     graph: &G,
     current_file: &str, // Add current file parameter
     source_start: usize,
@@ -42,11 +42,11 @@ pub fn func_target_file_finder<G: Graph>(
     }
 
     // Second try: find function with operand
-    // if let Some(op) = operand {
-    //     if let Some(tf) = find_function_with_operand(&op, func_name, graph) {
-    //         return Some(tf);
-    //     }
-    // }
+    if let Some(op) = operand {
+        if let Some(tf) = find_function_with_operand(&op, func_name, current_file, graph) {
+            return Some(tf);
+        }
+    }
 
     // Third try: find in the same file
     if let Some(tf) = find_function_in_same_file(func_name, current_file, graph, source_start) {
@@ -98,31 +98,24 @@ fn find_only_one_function_file<G: Graph>(
     None
 }
 
-fn _find_function_with_operand<G: Graph>(
+// the operand is an instance (like an injected service), so look for
+// the method on the instance's type
+fn find_function_with_operand<G: Graph>(
     operand: &str,
     func_name: &str,
+    current_file: &str,
     graph: &G,
-) -> Option<String> {
-    let mut target_file = None;
-    let mut instance = None;
-
-    let operand_nodes = graph.find_nodes_by_name(NodeType::Instance, operand);
-    for node in operand_nodes {
-        instance = Some(node.clone());
-        break;
-    }
-    if let Some(i) = instance {
-        if let Some(dt) = &i.data_type {
-            let function_nodes = graph.find_nodes_by_name(NodeType::Function, func_name);
-            for node in function_nodes {
-                if node.meta.get("operand") == Some(dt) {
-                    target_file = Some(node.file.clone());
-                    break;
-                }
-            }
-        }
-    }
-    target_file
+) -> Option<NodeData> {
+    let instances = graph.find_nodes_by_name(NodeType::Instance, operand);
+    let instance = instances
+        .iter()
+        .find(|i| i.file == current_file)
+        .or(instances.first())?;
+    let dt = instance.data_type.as_ref()?;
+    graph
+        .find_nodes_by_name(NodeType::Function, func_name)
+        .into_iter()
+        .find(|node| node.meta.get("operand") == Some(dt))
 }
 
 fn find_function_in_same_file<G: Graph>(
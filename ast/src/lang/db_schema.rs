@@ -0,0 +1,868 @@
+use crate::lang::proto::line_of;
+use crate::lang::NodeData;
+use convert_case::{Case, Casing};
+use inflection_rs::inflection;
+use regex::Regex;
+use std::cmp::Ordering;
+
+// database tables from sql schemas and migrations (plain sql, flyway, liquibase
+// formatted sql, golang-migrate), rails db/schema.rb and alembic revisions.
+// Migrations are replayed in version order, so each table ends up as one
+// DataModel with its final columns, indexes and foreign keys
+
+const IDENT: &str = r#"[\w."`\[\]]+"#;
+
+pub fn is_schema_file(file: &str, code: &str) -> bool {
+    if file.ends_with(".sql") {
+        return !is_down_migration(file);
+    }
+    if file.ends_with("db/schema.rb") {
+        return true;
+    }
+    // alembic revisions
+    file.ends_with(".py") && code.contains("op.create_table")
+}
+
+// golang-migrate down files and flyway undo scripts
+fn is_down_migration(file: &str) -> bool {
+    let name = file.rsplit('/').next().unwrap_or_default();
+    let flyway_undo = name.starts_with('U') && name[1..].starts_with(|c: char| c.is_ascii_digit());
+    name.ends_with(".down.sql") || flyway_undo
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ForeignKey {
+    pub column: String,
+    pub table: String,
+    pub ref_column: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    pub name: String,
+    pub file: String,
+    pub start: usize,
+    pub end: usize,
+    pub body: String,
+    // (name, type)
+    pub columns: Vec<(String, String)>,
+    pub primary_key: Vec<String>,
+    pub indexes: Vec<String>,
+    pub foreign_keys: Vec<ForeignKey>,
+}
+
+impl Table {
+    fn new(name: &str, file: &str, code: &str, start: usize, end: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            file: file.to_string(),
+            start: line_of(code, start),
+            end: line_of(code, end),
+            body: code[start..end].to_string(),
+            ..Default::default()
+        }
+    }
+    fn add_column(&mut self, name: &str, kind: &str) {
+        self.columns.retain(|(c, _)| c != name);
+        self.columns.push((name.to_string(), kind.to_string()));
+    }
+    fn drop_column(&mut self, name: &str) {
+        self.columns.retain(|(c, _)| c != name);
+        self.foreign_keys.retain(|fk| fk.column != name);
+    }
+    fn add_foreign_key(&mut self, column: &str, table: &str, ref_column: &str) {
+        let fk = ForeignKey {
+            column: column.to_string(),
+            table: table.to_string(),
+            ref_column: ref_column.to_string(),
+        };
+        if !self.foreign_keys.contains(&fk) {
+            self.foreign_keys.push(fk);
+        }
+    }
+    fn add_index(&mut self, name: &str, columns: &[String], unique: bool) {
+        let name = if name.is_empty() {
+            format!("{}_{}_idx", self.name, columns.join("_"))
+        } else {
+            name.to_string()
+        };
+        let unique = if unique { " unique" } else { "" };
+        self.indexes
+            .push(format!("{}({}){}", name, columns.join(","), unique));
+    }
+
+    // list values are separated by ";", since column types can contain commas
+    pub fn to_node_data(&self) -> NodeData {
+        let mut nd = NodeData::name_file(&self.name, &self.file);
+        nd.start = self.start;
+        nd.end = self.end;
+        nd.body = self.body.clone();
+        nd.meta.insert("table".to_string(), "true".to_string());
+        let columns: Vec<String> = self
+            .columns
+            .iter()
+            .map(|(name, kind)| format!("{} {}", name, kind))
+            .collect();
+        nd.meta.insert("columns".to_string(), columns.join(";"));
+        if !self.primary_key.is_empty() {
+            nd.meta
+                .insert("primary_key".to_string(), self.primary_key.join(","));
+        }
+        if !self.indexes.is_empty() {
+            nd.meta.insert("indexes".to_string(), self.indexes.join(";"));
+        }
+        if !self.foreign_keys.is_empty() {
+            let fks: Vec<String> = self
+                .foreign_keys
+                .iter()
+                .map(|fk| format!("{} -> {}.{}", fk.column, fk.table, fk.ref_column))
+                .collect();
+            nd.meta.insert("foreign_keys".to_string(), fks.join(";"));
+        }
+        nd
+    }
+}
+
+pub fn collect_tables(filez: &[(String, String)]) -> Vec<Table> {
+    let mut files: Vec<&(String, String)> = filez
+        .iter()
+        .filter(|(file, code)| is_schema_file(file, code))
+        .collect();
+    // V2__ before V10__
+    files.sort_by(|a, b| natural_cmp(&a.0, &b.0));
+    let mut tables = Vec::new();
+    for (file, code) in files {
+        if file.ends_with(".sql") {
+            apply_sql(&mut tables, code, file);
+        } else if file.ends_with(".rb") {
+            apply_schema_rb(&mut tables, code, file);
+        } else {
+            apply_alembic(&mut tables, code, file);
+        }
+    }
+    tables
+}
+
+// the table an ORM model maps to: an explicit table name in its body,
+// or the (pluralized) snake case of the model name
+pub fn find_model_table<'a>(model: &NodeData, tables: &'a [Table]) -> Option<&'a Table> {
+    let explicit = [
+        r#"__tablename__\s*=\s*["'](\w+)["']"#,
+        r#"@Table\s*\(\s*name\s*=\s*"(\w+)""#,
+        r#"db_table\s*=\s*["'](\w+)["']"#,
+        r#"self\.table_name\s*=\s*["'](\w+)["']"#,
+    ];
+    let mut candidates: Vec<String> = explicit
+        .iter()
+        .filter_map(|re| Regex::new(re).unwrap().captures(&model.body))
+        .map(|c| c[1].to_string())
+        .collect();
+    if candidates.is_empty() {
+        let snake = model.name.to_case(Case::Snake);
+        candidates.push(inflection::pluralize(&snake));
+        candidates.push(snake);
+    }
+    candidates.iter().find_map(|c| find_table(tables, c))
+}
+
+fn find_table<'a>(tables: &'a [Table], name: &str) -> Option<&'a Table> {
+    tables.iter().find(|t| t.name.eq_ignore_ascii_case(name))
+}
+
+fn find_table_mut<'a>(tables: &'a mut [Table], name: &str) -> Option<&'a mut Table> {
+    tables.iter_mut().find(|t| t.name.eq_ignore_ascii_case(name))
+}
+
+fn apply_sql(tables: &mut Vec<Table>, code: &str, file: &str) {
+    let create_re = Regex::new(&format!(
+        r"(?is)^create\s+(?:or\s+replace\s+)?(?:(?:global|local)\s+)?(?:temporary\s+|temp\s+|unlogged\s+)?table\s+(?:if\s+not\s+exists\s+)?({IDENT})\s*\("
+    ))
+    .unwrap();
+    let alter_re = Regex::new(&format!(
+        r"(?is)^alter\s+table\s+(?:if\s+exists\s+)?(?:only\s+)?({IDENT})\s+(.*)$"
+    ))
+    .unwrap();
+    let index_re = Regex::new(&format!(
+        r"(?is)^create\s+(unique\s+)?index\s+(?:concurrently\s+)?(?:if\s+not\s+exists\s+)?({IDENT})?\s*on\s+(?:only\s+)?({IDENT})\s*(?:using\s+\w+\s*)?\((.*)\)"
+    ))
+    .unwrap();
+    let drop_re = Regex::new(r"(?is)^drop\s+table\s+(?:if\s+exists\s+)?(.*)$").unwrap();
+
+    let src = strip_sql_comments(code);
+    for (start, stmt) in statements(&src) {
+        let end = start + stmt.len();
+        if let Some(cap) = create_re.captures(stmt) {
+            let name = ident(&cap[1]);
+            let open = cap.get(0).unwrap().end() - 1;
+            let Some(close) = matching_paren(stmt, open) else {
+                continue;
+            };
+            let mut table = Table::new(&name, file, code, start, end);
+            for item in split_top_level(&stmt[open + 1..close]) {
+                apply_table_item(&mut table, item);
+            }
+            tables.retain(|t| !t.name.eq_ignore_ascii_case(&name));
+            tables.push(table);
+        } else if let Some(cap) = alter_re.captures(stmt) {
+            let Some(table) = find_table_mut(tables, &ident(&cap[1])) else {
+                continue;
+            };
+            for action in split_top_level(&cap[2]) {
+                apply_alter_action(table, action);
+            }
+        } else if let Some(cap) = index_re.captures(stmt) {
+            let Some(table) = find_table_mut(tables, &ident(&cap[3])) else {
+                continue;
+            };
+            let name = cap.get(2).map(|n| ident(n.as_str())).unwrap_or_default();
+            let columns = idents(&cap[4]);
+            table.add_index(&name, &columns, cap.get(1).is_some());
+        } else if let Some(cap) = drop_re.captures(stmt) {
+            for name in idents(&cap[1]) {
+                tables.retain(|t| !t.name.eq_ignore_ascii_case(&name));
+            }
+        }
+    }
+}
+
+// a column or constraint inside create table (...)
+fn apply_table_item(table: &mut Table, item: &str) {
+    let constraint_re = Regex::new(&format!(r"(?is)^constraint\s+{IDENT}\s+")).unwrap();
+    let item = constraint_re.replace(item.trim(), "").to_string();
+    let primary_re = Regex::new(r"(?is)^primary\s+key\s*\((.*)\)").unwrap();
+    let foreign_re = Regex::new(&format!(
+        r"(?is)^foreign\s+key\s*\((.*?)\)\s*references\s+({IDENT})\s*(?:\((.*?)\))?"
+    ))
+    .unwrap();
+    let unique_re =
+        Regex::new(&format!(r"(?is)^unique\s*(?:key\s+|index\s+)?({IDENT})?\s*\((.*)\)")).unwrap();
+    let key_re = Regex::new(&format!(r"(?is)^(?:key|index)\s+({IDENT})?\s*\((.*)\)")).unwrap();
+    let column_re = Regex::new(&format!(r"(?is)^({IDENT})\s+(.*)$")).unwrap();
+
+    if let Some(cap) = primary_re.captures(&item) {
+        table.primary_key = idents(&cap[1]);
+    } else if let Some(cap) = foreign_re.captures(&item) {
+        let ref_table = ident(&cap[2]);
+        let ref_columns = cap.get(3).map(|c| idents(c.as_str())).unwrap_or_default();
+        for (i, column) in idents(&cap[1]).iter().enumerate() {
+            let ref_column = ref_columns.get(i).map(|c| c.as_str()).unwrap_or("id");
+            table.add_foreign_key(column, &ref_table, ref_column);
+        }
+    } else if let Some(cap) = unique_re.captures(&item) {
+        let name = cap.get(1).map(|n| ident(n.as_str())).unwrap_or_default();
+        table.add_index(&name, &idents(&cap[2]), true);
+    } else if let Some(cap) = key_re.captures(&item) {
+        let name = cap.get(1).map(|n| ident(n.as_str())).unwrap_or_default();
+        table.add_index(&name, &idents(&cap[2]), false);
+    } else if Regex::new(r"(?i)^(check|exclude)\b").unwrap().is_match(&item) {
+    } else if let Some(cap) = column_re.captures(&item) {
+        let name = ident(&cap[1]);
+        let rest = &cap[2];
+        table.add_column(&name, &column_type(rest));
+        if Regex::new(r"(?i)\bprimary\s+key\b").unwrap().is_match(rest) {
+            table.primary_key = vec![name.clone()];
+        }
+        let references_re =
+            Regex::new(&format!(r"(?i)\breferences\s+({IDENT})\s*(?:\(\s*({IDENT})\s*\))?")).unwrap();
+        if let Some(r) = references_re.captures(rest) {
+            let ref_column = r.get(2).map(|c| ident(c.as_str())).unwrap_or("id".to_string());
+            table.add_foreign_key(&name, &ident(&r[1]), &ref_column);
+        }
+    }
+}
+
+fn apply_alter_action(table: &mut Table, action: &str) {
+    let action = action.trim();
+    let add_re =
+        Regex::new(r"(?is)^add\s+(?:column\s+)?(?:if\s+not\s+exists\s+)?(.*)$").unwrap();
+    let drop_constraint_re = Regex::new(r"(?i)^drop\s+constraint\b").unwrap();
+    let drop_re =
+        Regex::new(&format!(r"(?i)^drop\s+(?:column\s+)?(?:if\s+exists\s+)?({IDENT})")).unwrap();
+    let rename_column_re = Regex::new(&format!(
+        r"(?i)^rename\s+(?:column\s+)?({IDENT})\s+to\s+({IDENT})"
+    ))
+    .unwrap();
+    let rename_re = Regex::new(&format!(r"(?i)^rename\s+to\s+({IDENT})")).unwrap();
+
+    if let Some(cap) = add_re.captures(action) {
+        apply_table_item(table, &cap[1]);
+    } else if drop_constraint_re.is_match(action) {
+    } else if let Some(cap) = drop_re.captures(action) {
+        table.drop_column(&ident(&cap[1]));
+    } else if let Some(cap) = rename_re.captures(action) {
+        table.name = ident(&cap[1]);
+    } else if let Some(cap) = rename_column_re.captures(action) {
+        let (from, to) = (ident(&cap[1]), ident(&cap[2]));
+        for (name, _) in table.columns.iter_mut().filter(|(n, _)| *n == from) {
+            *name = to.clone();
+        }
+    }
+}
+
+fn apply_schema_rb(tables: &mut Vec<Table>, code: &str, file: &str) {
+    let create_re =
+        Regex::new(r#"(?m)^([ \t]*)create_table\s+"(\w+)"([^\n]*?)\s+do\s+\|(\w+)\|"#).unwrap();
+    let column_re = Regex::new(r#"^(\w+)\.(\w+)\s+"(\w+)"(.*)$"#).unwrap();
+    let index_re = Regex::new(r#"^(\w+)\.index\s+\[([^\]]*)\](.*)$"#).unwrap();
+    let name_re = Regex::new(r#"name:\s*"(\w+)""#).unwrap();
+    let id_re = Regex::new(r"id:\s*:(\w+)").unwrap();
+
+    for cap in create_re.captures_iter(code) {
+        let whole = cap.get(0).unwrap();
+        let end_line = format!("\n{}end", &cap[1]);
+        let end = code[whole.end()..]
+            .find(&end_line)
+            .map(|e| whole.end() + e + end_line.len())
+            .unwrap_or(code.len());
+        let name = &cap[2];
+        let options = &cap[3];
+        let var = &cap[4];
+        let mut table = Table::new(name, file, code, whole.start() + cap[1].len(), end);
+        if !options.contains("id: false") {
+            let id_type = id_re
+                .captures(options)
+                .map(|c| c[1].to_string())
+                .unwrap_or("bigint".to_string());
+            table.add_column("id", &id_type);
+            table.primary_key = vec!["id".to_string()];
+        }
+        for line in code[whole.end()..end].lines().map(|l| l.trim()) {
+            if let Some(c) = index_re.captures(line).filter(|c| &c[1] == var) {
+                let name = name_re.captures(&c[3]).map(|n| n[1].to_string()).unwrap_or_default();
+                table.add_index(&name, &quoted(&c[2]), c[3].contains("unique: true"));
+            } else if line == format!("{}.timestamps", var) {
+                table.add_column("created_at", "datetime");
+                table.add_column("updated_at", "datetime");
+            } else if let Some(c) = column_re.captures(line).filter(|c| &c[1] == var) {
+                let (kind, column, options) = (&c[2], &c[3], &c[4]);
+                if kind == "references" || kind == "belongs_to" {
+                    let column_name = format!("{}_id", column);
+                    table.add_column(&column_name, "bigint");
+                    if options.contains("foreign_key: true") {
+                        table.add_foreign_key(&column_name, &inflection::pluralize(column), "id");
+                    }
+                } else {
+                    table.add_column(column, kind);
+                }
+            }
+        }
+        tables.retain(|t| t.name != name);
+        tables.push(table);
+    }
+
+    let fk_re = Regex::new(r#"(?m)^\s*add_foreign_key\s+"(\w+)",\s*"(\w+)"(.*)$"#).unwrap();
+    let column_opt = Regex::new(r#"column:\s*"(\w+)""#).unwrap();
+    let primary_opt = Regex::new(r#"primary_key:\s*"(\w+)""#).unwrap();
+    for cap in fk_re.captures_iter(code) {
+        let to = &cap[2];
+        let column = column_opt
+            .captures(&cap[3])
+            .map(|c| c[1].to_string())
+            .unwrap_or(format!("{}_id", inflection::singularize(to)));
+        let ref_column = primary_opt
+            .captures(&cap[3])
+            .map(|c| c[1].to_string())
+            .unwrap_or("id".to_string());
+        if let Some(table) = find_table_mut(tables, &cap[1]) {
+            table.add_foreign_key(&column, to, &ref_column);
+        }
+    }
+}
+
+fn apply_alembic(tables: &mut Vec<Table>, code: &str, file: &str) {
+    // only the upgrade, the downgrade undoes it
+    let Some(upgrade) = code.find("def upgrade(") else {
+        return;
+    };
+    let upgrade_end = code[upgrade + 1..]
+        .find("\ndef ")
+        .map(|e| upgrade + 1 + e)
+        .unwrap_or(code.len());
+    let op_re = Regex::new(
+        r"\bop\.(create_table|drop_table|add_column|drop_column|create_index|create_foreign_key)\s*\(",
+    )
+    .unwrap();
+    for cap in op_re.captures_iter(&code[..upgrade_end]) {
+        let whole = cap.get(0).unwrap();
+        if whole.start() < upgrade {
+            continue;
+        }
+        let open = whole.end() - 1;
+        let Some(close) = matching_paren(code, open) else {
+            continue;
+        };
+        let args = split_top_level(&code[open + 1..close]);
+        let Some(target) = args.first().and_then(|a| quoted(a).into_iter().next()) else {
+            continue;
+        };
+        match &cap[1] {
+            "create_table" => {
+                let mut table = Table::new(&target, file, code, whole.start(), close + 1);
+                for arg in &args[1..] {
+                    apply_sa_item(&mut table, arg);
+                }
+                tables.retain(|t| t.name != target);
+                tables.push(table);
+            }
+            "drop_table" => tables.retain(|t| t.name != target),
+            "add_column" => {
+                if let (Some(table), Some(column)) = (find_table_mut(tables, &target), args.get(1)) {
+                    apply_sa_item(table, column);
+                }
+            }
+            "drop_column" => {
+                let column = args.get(1).and_then(|a| quoted(a).into_iter().next());
+                if let (Some(table), Some(column)) = (find_table_mut(tables, &target), column) {
+                    table.drop_column(&column);
+                }
+            }
+            "create_index" => {
+                // op.create_index(op.f("ix_users_email"), "users", ["email"], unique=True)
+                let table_name = args.get(1).and_then(|a| quoted(a).into_iter().next());
+                let columns = args.get(2).map(|a| quoted(a)).unwrap_or_default();
+                let unique = args.iter().any(|a| a.replace(' ', "") == "unique=True");
+                if let Some(table) = table_name.and_then(|t| find_table_mut(tables, &t)) {
+                    table.add_index(&target, &columns, unique);
+                }
+            }
+            "create_foreign_key" => {
+                // op.create_foreign_key("fk_name", "posts", "users", ["user_id"], ["id"])
+                let names: Vec<Option<String>> = args
+                    .iter()
+                    .take(3)
+                    .map(|a| quoted(a).into_iter().next())
+                    .collect();
+                let (Some(Some(source)), Some(Some(referent))) = (names.get(1), names.get(2)) else {
+                    continue;
+                };
+                let local = args.get(3).map(|a| quoted(a)).unwrap_or_default();
+                let remote = args.get(4).map(|a| quoted(a)).unwrap_or_default();
+                if let Some(table) = find_table_mut(tables, source) {
+                    for (i, column) in local.iter().enumerate() {
+                        let ref_column = remote.get(i).map(|c| c.as_str()).unwrap_or("id");
+                        table.add_foreign_key(column, referent, ref_column);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+// sa.Column(...), sa.PrimaryKeyConstraint(...), sa.ForeignKeyConstraint(...), sa.UniqueConstraint(...)
+fn apply_sa_item(table: &mut Table, item: &str) {
+    let item = item.trim();
+    let call_re = Regex::new(r"^(?:\w+\.)?(\w+)\s*\(").unwrap();
+    let Some(cap) = call_re.captures(item) else {
+        return;
+    };
+    let open = cap.get(0).unwrap().end() - 1;
+    let Some(close) = matching_paren(item, open) else {
+        return;
+    };
+    let args = split_top_level(&item[open + 1..close]);
+    match &cap[1] {
+        "Column" => {
+            let Some(name) = args.first().and_then(|a| quoted(a).into_iter().next()) else {
+                return;
+            };
+            let kind = args
+                .get(1)
+                .map(|a| {
+                    let a = a.trim();
+                    let a = a.rsplit_once('.').map(|(_, t)| t).unwrap_or(a);
+                    let a = a.strip_suffix("()").unwrap_or(a);
+                    match a.split_once('(') {
+                        Some((t, params)) => format!("{}({}", t.to_lowercase(), params),
+                        None => a.to_lowercase(),
+                    }
+                })
+                .unwrap_or_default();
+            table.add_column(&name, &kind);
+            if args.iter().any(|a| a.replace(' ', "") == "primary_key=True") {
+                table.primary_key = vec![name.clone()];
+            }
+            let fk_re = Regex::new(r#"ForeignKey\(\s*["'](\w+)\.(\w+)["']"#).unwrap();
+            if let Some(fk) = args.iter().find_map(|a| fk_re.captures(a)) {
+                table.add_foreign_key(&name, &fk[1], &fk[2]);
+            }
+        }
+        "PrimaryKeyConstraint" => {
+            table.primary_key = args
+                .iter()
+                .filter(|a| !a.contains('='))
+                .flat_map(|a| quoted(a))
+                .collect();
+        }
+        "ForeignKeyConstraint" => {
+            let local = args.first().map(|a| quoted(a)).unwrap_or_default();
+            let remote = args.get(1).map(|a| quoted(a)).unwrap_or_default();
+            for (column, target) in local.iter().zip(remote.iter()) {
+                if let Some((ref_table, ref_column)) = target.split_once('.') {
+                    table.add_foreign_key(column, ref_table, ref_column);
+                }
+            }
+        }
+        "UniqueConstraint" => {
+            let columns: Vec<String> = args
+                .iter()
+                .filter(|a| !a.contains('='))
+                .flat_map(|a| quoted(a))
+                .collect();
+            let name = args
+                .iter()
+                .find(|a| a.trim_start().starts_with("name"))
+                .and_then(|a| quoted(a).into_iter().next())
+                .unwrap_or_default();
+            table.add_index(&name, &columns, true);
+        }
+        _ => (),
+    }
+}
+
+// the type of a column definition, without its constraints
+fn column_type(rest: &str) -> String {
+    let re = Regex::new(
+        r"(?i)^(\w+(?:\s+(?:varying|precision|unsigned))?(?:\s*\([^)]*\))?(?:\[\])?)",
+    )
+    .unwrap();
+    re.captures(rest.trim())
+        .map(|c| c[1].split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase())
+        .unwrap_or_default()
+}
+
+// "public"."users" -> users
+fn ident(name: &str) -> String {
+    name.trim()
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .trim_matches(|c| c == '"' || c == '`' || c == '[' || c == ']')
+        .to_string()
+}
+
+// a, "b" desc, c(10) -> [a, b, c]
+fn idents(list: &str) -> Vec<String> {
+    split_top_level(list)
+        .iter()
+        .filter_map(|i| i.split_whitespace().next())
+        .map(|i| ident(i.split('(').next().unwrap_or(i)))
+        .filter(|i| !i.is_empty() && !i.eq_ignore_ascii_case("cascade"))
+        .collect()
+}
+
+// the quoted strings in a ruby or python expression
+fn quoted(text: &str) -> Vec<String> {
+    Regex::new(r#"["']([^"']+)["']"#)
+        .unwrap()
+        .captures_iter(text)
+        .map(|c| c[1].to_string())
+        .collect()
+}
+
+// the statements of a sql file, with their byte offset
+fn statements(src: &str) -> Vec<(usize, &str)> {
+    let mut res = Vec::new();
+    let mut start = 0;
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in src.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ';') if depth <= 0 => {
+                push_statement(&mut res, src, start, i);
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    push_statement(&mut res, src, start, src.len());
+    res
+}
+
+fn push_statement<'a>(res: &mut Vec<(usize, &'a str)>, src: &'a str, start: usize, end: usize) {
+    let stmt = &src[start..end];
+    let trimmed = stmt.trim_start();
+    if !trimmed.trim().is_empty() {
+        res.push((start + stmt.len() - trimmed.len(), trimmed.trim_end()));
+    }
+}
+
+// splits on commas outside of parentheses and quotes
+fn split_top_level(list: &str) -> Vec<&str> {
+    let mut res = Vec::new();
+    let mut start = 0;
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in list.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                res.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    res.push(list[start..].trim());
+    res.retain(|i| !i.is_empty());
+    res
+}
+
+fn matching_paren(src: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in src[open..].char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+// blanks out -- and /* */ comments, keeping byte offsets intact
+fn strip_sql_comments(code: &str) -> String {
+    let line_re = Regex::new(r"--[^\n]*").unwrap();
+    let block_re = Regex::new(r"(?s)/\*.*?\*/").unwrap();
+    let blank = |c: &regex::Captures| -> String {
+        c[0].chars()
+            .map(|ch| if ch == '\n' { '\n' } else { ' ' })
+            .collect()
+    };
+    let code = block_re.replace_all(code, blank).to_string();
+    line_re.replace_all(&code, blank).to_string()
+}
+
+// compares numbers in file names by value, so V2__ sorts before V10__
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let chunks = |s: &str| -> Vec<(bool, String)> {
+        let mut res: Vec<(bool, String)> = Vec::new();
+        for c in s.chars() {
+            let digit = c.is_ascii_digit();
+            match res.last_mut() {
+                Some((d, chunk)) if *d == digit => chunk.push(c),
+                _ => res.push((digit, c.to_string())),
+            }
+        }
+        res
+    };
+    for (x, y) in chunks(a).iter().zip(chunks(b).iter()) {
+        let ord = match (x, y) {
+            ((true, x), (true, y)) => x
+                .trim_start_matches('0')
+                .len()
+                .cmp(&y.trim_start_matches('0').len())
+                .then_with(|| x.trim_start_matches('0').cmp(y.trim_start_matches('0'))),
+            ((_, x), (_, y)) => x.cmp(y),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(files: &[(&str, &str)]) -> Vec<(String, String)> {
+        files
+            .iter()
+            .map(|(f, c)| (f.to_string(), c.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_sql_migrations() {
+        let filez = files(&[
+            (
+                "db/migration/V10__add_published.sql",
+                "ALTER TABLE posts ADD COLUMN published_at TIMESTAMP WITH TIME ZONE, DROP COLUMN draft;\n",
+            ),
+            (
+                "db/migration/V1__init.sql",
+                r#"-- users; and their posts
+CREATE TABLE IF NOT EXISTS public."users" (
+    id BIGSERIAL PRIMARY KEY,
+    email VARCHAR(255) NOT NULL UNIQUE,
+    balance NUMERIC(10, 2) DEFAULT 0
+);
+CREATE UNIQUE INDEX idx_users_email ON users (email);
+
+CREATE TABLE posts (
+    id BIGINT NOT NULL,
+    author_id BIGINT NOT NULL,
+    draft BOOLEAN,
+    /* the title; required */
+    title character varying(120),
+    CONSTRAINT posts_pkey PRIMARY KEY (id),
+    CONSTRAINT posts_author_fk FOREIGN KEY (author_id) REFERENCES users (id) ON DELETE CASCADE
+);
+"#,
+            ),
+            (
+                "db/migration/V2__tags.sql",
+                "CREATE TABLE tags (id int primary key, post_id int references posts);\nDROP TABLE tags;\n",
+            ),
+            ("db/migration/U1__undo.sql", "DROP TABLE users;"),
+            ("migrations/000001_init.down.sql", "DROP TABLE posts;"),
+        ]);
+        let tables = collect_tables(&filez);
+        let names: Vec<&str> = tables.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["users", "posts"]);
+
+        let users = tables[0].to_node_data();
+        assert_eq!(users.file, "db/migration/V1__init.sql");
+        assert_eq!((users.start, users.end), (1, 5));
+        assert_eq!(
+            users.meta.get("columns").unwrap(),
+            "id bigserial;email varchar(255);balance numeric(10, 2)"
+        );
+        assert_eq!(users.meta.get("primary_key").unwrap(), "id");
+        assert_eq!(
+            users.meta.get("indexes").unwrap(),
+            "idx_users_email(email) unique"
+        );
+
+        let posts = tables[1].to_node_data();
+        assert_eq!((posts.start, posts.end), (8, 16));
+        assert_eq!(
+            posts.meta.get("columns").unwrap(),
+            "id bigint;author_id bigint;title character varying(120);published_at timestamp"
+        );
+        assert_eq!(posts.meta.get("primary_key").unwrap(), "id");
+        assert_eq!(
+            posts.meta.get("foreign_keys").unwrap(),
+            "author_id -> users.id"
+        );
+    }
+
+    #[test]
+    fn test_schema_rb() {
+        let code = r#"ActiveRecord::Schema[8.0].define(version: 2025_04_07_222218) do
+  create_table "articles", force: :cascade do |t|
+    t.string "title"
+    t.references "author", null: false, foreign_key: true
+    t.timestamps
+    t.index ["title"], name: "index_articles_on_title", unique: true
+  end
+
+  create_table "people", id: :uuid, force: :cascade do |t|
+    t.string "name"
+  end
+
+  add_foreign_key "articles", "people"
+end
+"#;
+        let tables = collect_tables(&files(&[("db/schema.rb", code)]));
+        assert_eq!(tables.len(), 2);
+        let articles = tables[0].to_node_data();
+        assert_eq!(articles.name, "articles");
+        assert_eq!((articles.start, articles.end), (1, 6));
+        assert_eq!(
+            articles.meta.get("columns").unwrap(),
+            "id bigint;title string;author_id bigint;created_at datetime;updated_at datetime"
+        );
+        assert_eq!(
+            articles.meta.get("indexes").unwrap(),
+            "index_articles_on_title(title) unique"
+        );
+        assert_eq!(
+            articles.meta.get("foreign_keys").unwrap(),
+            "author_id -> authors.id;person_id -> people.id"
+        );
+        let people = tables[1].to_node_data();
+        assert_eq!(people.meta.get("columns").unwrap(), "id uuid;name string");
+    }
+
+    #[test]
+    fn test_alembic() {
+        let code = r#""""create users and posts"""
+from alembic import op
+import sqlalchemy as sa
+
+
+def upgrade():
+    op.create_table(
+        "users",
+        sa.Column("id", sa.Integer(), nullable=False),
+        sa.Column("email", sa.String(length=255), nullable=False),
+        sa.PrimaryKeyConstraint("id"),
+        sa.UniqueConstraint("email", name="uq_users_email"),
+    )
+    op.create_table(
+        "posts",
+        sa.Column("id", sa.Integer(), primary_key=True),
+        sa.Column("user_id", sa.Integer(), sa.ForeignKey("users.id")),
+    )
+    op.add_column("posts", sa.Column("title", sa.Text()))
+    op.create_index(op.f("ix_posts_title"), "posts", ["title"], unique=False)
+
+
+def downgrade():
+    op.drop_table("posts")
+    op.drop_table("users")
+"#;
+        let tables = collect_tables(&files(&[("alembic/versions/0001_init.py", code)]));
+        assert_eq!(tables.len(), 2);
+        let users = tables[0].to_node_data();
+        assert_eq!((users.start, users.end), (6, 12));
+        assert_eq!(
+            users.meta.get("columns").unwrap(),
+            "id integer;email string(length=255)"
+        );
+        assert_eq!(users.meta.get("primary_key").unwrap(), "id");
+        assert_eq!(
+            users.meta.get("indexes").unwrap(),
+            "uq_users_email(email) unique"
+        );
+        let posts = tables[1].to_node_data();
+        assert_eq!(
+            posts.meta.get("columns").unwrap(),
+            "id integer;user_id integer;title text"
+        );
+        assert_eq!(posts.meta.get("primary_key").unwrap(), "id");
+        assert_eq!(posts.meta.get("foreign_keys").unwrap(), "user_id -> users.id");
+        assert_eq!(posts.meta.get("indexes").unwrap(), "ix_posts_title(title)");
+    }
+
+    #[test]
+    fn test_find_model_table() {
+        let tables = collect_tables(&files(&[(
+            "schema.sql",
+            "CREATE TABLE blog_posts (id int);\nCREATE TABLE accounts (id int);\nCREATE TABLE person (id int);",
+        )]));
+        let model = |name: &str, body: &str| {
+            let mut nd = NodeData::name_file(name, "models.py");
+            nd.body = body.to_string();
+            nd
+        };
+        let table = |m: &NodeData| find_model_table(m, &tables).map(|t| t.name.clone());
+        assert_eq!(table(&model("BlogPost", "type BlogPost struct {}")), Some("blog_posts".to_string()));
+        assert_eq!(
+            table(&model("User", "class User(Base):\n    __tablename__ = \"accounts\"")),
+            Some("accounts".to_string())
+        );
+        assert_eq!(table(&model("Person", "")), Some("person".to_string()));
+        assert_eq!(table(&model("Order", "")), None);
+    }
+}
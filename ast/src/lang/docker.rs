@@ -0,0 +1,422 @@
+use crate::lang::openapi::{block_end, key_line, to_json};
+use crate::lang::NodeData;
+use serde_json::Value;
+
+// docker-compose services and Dockerfiles, as Service nodes. A compose service
+// that builds an image picks up the CMD / ENTRYPOINT and EXPOSE of its
+// Dockerfile; a Dockerfile that no compose service builds is a service of its own
+
+pub fn is_compose_file(file: &str) -> bool {
+    let name = file.rsplit('/').next().unwrap_or_default();
+    let yaml = name.ends_with(".yml") || name.ends_with(".yaml");
+    yaml && (name.starts_with("docker-compose") || name.starts_with("compose"))
+}
+
+pub fn is_dockerfile(file: &str) -> bool {
+    let name = file.rsplit('/').next().unwrap_or_default();
+    name == "Dockerfile" || name.starts_with("Dockerfile.") || name.ends_with(".Dockerfile")
+}
+
+#[derive(Debug, Clone)]
+pub struct Service {
+    pub node: NodeData,
+    pub depends_on: Vec<String>,
+    // repo paths of the build context and the Dockerfile, when the image is built here
+    pub build_context: Option<String>,
+    pub dockerfile: Option<String>,
+    // overrides of the Dockerfile ENTRYPOINT and CMD
+    pub entrypoint: Option<Vec<String>>,
+    pub command: Option<Vec<String>>,
+}
+
+pub fn parse_compose(code: &str, file: &str) -> Vec<Service> {
+    let mut res = Vec::new();
+    if !is_compose_file(file) {
+        return res;
+    }
+    let Ok(doc) = serde_norway::from_str(code).map(to_json) else {
+        return res;
+    };
+    let Some(services) = doc.get("services").and_then(|s| s.as_object()) else {
+        return res;
+    };
+    let dir = parent_dir(file);
+    let lines: Vec<&str> = code.lines().collect();
+    let services_line = key_line(&lines, "services", 0).unwrap_or(0);
+    for (name, svc) in services {
+        let mut node = NodeData::name_file(name, file);
+        if let Some(start) = key_line(&lines, name, services_line + 1) {
+            node.start = start;
+            node.end = block_end(&lines, start);
+            node.body = lines[start..=node.end].join("\n");
+        }
+        if let Some(image) = svc.get("image").and_then(|i| i.as_str()) {
+            node.meta.insert("image".to_string(), image.to_string());
+        }
+        let ports: Vec<String> = list(svc.get("ports"))
+            .iter()
+            .map(|p| match p {
+                Value::Object(o) => {
+                    let target = o.get("target").map(scalar).unwrap_or_default();
+                    match o.get("published").map(scalar) {
+                        Some(published) => format!("{}:{}", published, target),
+                        None => target,
+                    }
+                }
+                other => scalar(other),
+            })
+            .collect();
+        if !ports.is_empty() {
+            node.meta.insert("ports".to_string(), ports.join(","));
+        }
+        // only the names, the values are often secrets
+        let env: Vec<String> = match svc.get("environment") {
+            Some(Value::Object(o)) => o.keys().cloned().collect(),
+            other => list(other)
+                .iter()
+                .map(|e| scalar(e).split('=').next().unwrap_or_default().to_string())
+                .collect(),
+        };
+        if !env.is_empty() {
+            node.meta.insert("environment".to_string(), env.join(","));
+        }
+        let depends_on: Vec<String> = match svc.get("depends_on") {
+            Some(Value::Object(o)) => o.keys().cloned().collect(),
+            other => list(other).iter().map(scalar).collect(),
+        };
+        if !depends_on.is_empty() {
+            node.meta.insert("depends_on".to_string(), depends_on.join(","));
+        }
+        let (context, dockerfile) = match svc.get("build") {
+            Some(Value::String(context)) => (Some(context.as_str()), None),
+            Some(Value::Object(build)) => (
+                Some(build.get("context").and_then(|c| c.as_str()).unwrap_or(".")),
+                build.get("dockerfile").and_then(|d| d.as_str()),
+            ),
+            _ => (None, None),
+        };
+        let build_context = context.map(|c| join_path(&dir, c));
+        let dockerfile = build_context
+            .as_ref()
+            .map(|c| join_path(c, dockerfile.unwrap_or("Dockerfile")));
+        if let Some(context) = &build_context {
+            node.meta.insert("build_context".to_string(), context.to_string());
+        }
+        if let Some(dockerfile) = &dockerfile {
+            node.meta.insert("dockerfile".to_string(), dockerfile.to_string());
+        }
+        let entrypoint = svc.get("entrypoint").map(command_args);
+        let command = svc.get("command").map(command_args);
+        if let Some(command) = &command {
+            node.meta.insert("command".to_string(), command.join(" "));
+        }
+        res.push(Service {
+            node,
+            depends_on,
+            build_context,
+            dockerfile,
+            entrypoint,
+            command,
+        });
+    }
+    res
+}
+
+// a Dockerfile that is not built by any compose service, named after its directory
+pub fn dockerfile_service(file: &str, code: &str) -> Service {
+    let dir = parent_dir(file);
+    let name = file
+        .rsplit('/')
+        .next()
+        .and_then(|n| n.strip_suffix(".Dockerfile"))
+        .or(dir.rsplit('/').next())
+        .unwrap_or("app");
+    let mut node = NodeData::name_file(name, file);
+    node.end = code.lines().count().saturating_sub(1);
+    node.body = code.to_string();
+    node.meta.insert("build_context".to_string(), dir.clone());
+    node.meta.insert("dockerfile".to_string(), file.to_string());
+    Service {
+        node,
+        depends_on: Vec::new(),
+        build_context: Some(dir),
+        dockerfile: Some(file.to_string()),
+        entrypoint: None,
+        command: None,
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Dockerfile {
+    pub entrypoint: Vec<String>,
+    pub cmd: Vec<String>,
+    pub expose: Vec<String>,
+    pub workdir: Option<String>,
+}
+
+impl Dockerfile {
+    // the process the container runs: ENTRYPOINT, with CMD as its arguments
+    pub fn run_args(&self, service: &Service) -> Vec<String> {
+        let mut args = service
+            .entrypoint
+            .clone()
+            .unwrap_or(self.entrypoint.clone());
+        args.extend(service.command.clone().unwrap_or(self.cmd.clone()));
+        args
+    }
+}
+
+pub fn parse_dockerfile(code: &str) -> Dockerfile {
+    let mut res = Dockerfile::default();
+    let joined = code.replace("\\\r\n", " ").replace("\\\n", " ");
+    for line in joined.lines().map(|l| l.trim()) {
+        if line.starts_with('#') {
+            continue;
+        }
+        let (instruction, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let value = value.trim();
+        match instruction.to_uppercase().as_str() {
+            // only the final stage ends up in the image
+            "FROM" => res = Dockerfile::default(),
+            "ENTRYPOINT" => res.entrypoint = shell_or_exec(value),
+            "CMD" => res.cmd = shell_or_exec(value),
+            "EXPOSE" => res
+                .expose
+                .extend(value.split_whitespace().map(|p| p.to_string())),
+            "WORKDIR" => res.workdir = Some(value.to_string()),
+            _ => (),
+        }
+    }
+    res
+}
+
+// a file (and maybe a function in it) that a container command runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryTarget {
+    pub path: String,
+    pub function: Option<String>,
+}
+
+const ENTRY_EXTS: [&str; 11] = [
+    ".py", ".js", ".mjs", ".cjs", ".ts", ".rb", ".go", ".sh", ".php", ".java", ".kt",
+];
+
+pub fn entry_targets(args: &[String], workdir: Option<&str>) -> Vec<EntryTarget> {
+    let mut res = Vec::new();
+    let relative = |path: &str| -> String {
+        let path = match workdir {
+            Some(w) => path
+                .strip_prefix(&format!("{}/", w.trim_end_matches('/')))
+                .unwrap_or(path),
+            None => path,
+        };
+        path.trim_start_matches("./").trim_start_matches('/').to_string()
+    };
+    for (i, arg) in args.iter().enumerate() {
+        // python -m package.module
+        if i > 0 && args[i - 1] == "-m" {
+            res.push(EntryTarget {
+                path: format!("{}.py", arg.replace('.', "/")),
+                function: None,
+            });
+            continue;
+        }
+        // uvicorn / gunicorn app.main:app
+        if let Some((module, attr)) = arg.split_once(':') {
+            let attr = attr.trim_end_matches("()");
+            let is_ident = |s: &str| {
+                s.starts_with(|c: char| c.is_alphabetic() || c == '_')
+                    && s.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+            };
+            if is_ident(module) && is_ident(attr) {
+                res.push(EntryTarget {
+                    path: format!("{}.py", module.replace('.', "/")),
+                    function: Some(attr.to_string()),
+                });
+                continue;
+            }
+        }
+        if ENTRY_EXTS.iter().any(|ext| arg.ends_with(ext)) && !arg.starts_with('-') {
+            res.push(EntryTarget {
+                path: relative(arg),
+                function: None,
+            });
+        }
+    }
+    res
+}
+
+fn shell_or_exec(value: &str) -> Vec<String> {
+    if value.starts_with('[') {
+        if let Ok(Value::Array(items)) = serde_json::from_str(value) {
+            return items.iter().map(scalar).collect();
+        }
+    }
+    value
+        .split_whitespace()
+        .map(|a| a.trim_matches(|c| c == '"' || c == '\'').to_string())
+        .collect()
+}
+
+fn command_args(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(s) => shell_or_exec(s),
+        other => list(Some(other)).iter().map(scalar).collect(),
+    }
+}
+
+fn list(value: Option<&Value>) -> Vec<Value> {
+    match value {
+        Some(Value::Array(items)) => items.clone(),
+        _ => Vec::new(),
+    }
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+pub fn parent_dir(file: &str) -> String {
+    file.rsplit_once('/')
+        .map(|(dir, _)| dir.to_string())
+        .unwrap_or_default()
+}
+
+// base/rel, resolving . and ..
+pub fn join_path(base: &str, rel: &str) -> String {
+    let mut parts: Vec<&str> = base.split('/').filter(|p| !p.is_empty()).collect();
+    for part in rel.split('/') {
+        match part {
+            "" | "." => (),
+            ".." => {
+                parts.pop();
+            }
+            p => parts.push(p),
+        }
+    }
+    let joined = parts.join("/");
+    if base.starts_with('/') {
+        format!("/{}", joined)
+    } else {
+        joined
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPOSE: &str = r#"version: "3.8"
+services:
+  api:
+    build:
+      context: ./backend
+      dockerfile: Dockerfile.prod
+    ports:
+      - "8000:8000"
+      - target: 9090
+        published: 19090
+    environment:
+      - DATABASE_URL=postgres://db/app
+      - DEBUG
+    depends_on:
+      - db
+    command: ["uvicorn", "app.main:app", "--port", "8000"]
+  db:
+    image: postgres:16
+    environment:
+      POSTGRES_PASSWORD: secret
+    ports:
+      - 5432
+"#;
+
+    #[test]
+    fn test_parse_compose() {
+        let services = parse_compose(COMPOSE, "deploy/docker-compose.yml");
+        assert_eq!(services.len(), 2);
+        let api = &services[0];
+        assert_eq!(api.node.name, "api");
+        assert_eq!((api.node.start, api.node.end), (2, 15));
+        assert_eq!(api.node.meta.get("ports").unwrap(), "8000:8000,19090:9090");
+        assert_eq!(api.node.meta.get("environment").unwrap(), "DATABASE_URL,DEBUG");
+        assert_eq!(api.depends_on, vec!["db"]);
+        assert_eq!(api.build_context.as_deref(), Some("deploy/backend"));
+        assert_eq!(api.dockerfile.as_deref(), Some("deploy/backend/Dockerfile.prod"));
+        assert_eq!(
+            api.command.as_ref().unwrap().join(" "),
+            "uvicorn app.main:app --port 8000"
+        );
+        let db = &services[1];
+        assert_eq!(db.node.meta.get("image").unwrap(), "postgres:16");
+        assert_eq!(db.node.meta.get("environment").unwrap(), "POSTGRES_PASSWORD");
+        assert_eq!(db.node.meta.get("ports").unwrap(), "5432");
+        assert!(db.build_context.is_none());
+
+        assert!(parse_compose(COMPOSE, "deploy/values.yml").is_empty());
+    }
+
+    #[test]
+    fn test_dockerfile_service() {
+        let code = "FROM node:20\nCMD [\"node\", \"index.js\"]\n";
+        let web = dockerfile_service("apps/web/Dockerfile", code);
+        assert_eq!(web.node.name, "web");
+        assert_eq!((web.node.start, web.node.end), (0, 1));
+        assert_eq!(web.build_context.as_deref(), Some("apps/web"));
+        let worker = dockerfile_service("docker/worker.Dockerfile", code);
+        assert_eq!(worker.node.name, "worker");
+        assert_eq!(worker.build_context.as_deref(), Some("docker"));
+    }
+
+    #[test]
+    fn test_parse_dockerfile() {
+        let code = r#"FROM golang:1.22 AS build
+WORKDIR /src
+CMD ["go", "build"]
+EXPOSE 1234
+
+FROM python:3.12-slim
+WORKDIR /app
+COPY . .
+EXPOSE 8000 9090/tcp
+ENTRYPOINT ["python", \
+    "-m", "app.server"]
+"#;
+        let df = parse_dockerfile(code);
+        assert_eq!(df.entrypoint, vec!["python", "-m", "app.server"]);
+        assert!(df.cmd.is_empty());
+        assert_eq!(df.expose, vec!["8000", "9090/tcp"]);
+        assert_eq!(df.workdir.as_deref(), Some("/app"));
+        assert_eq!(
+            entry_targets(&df.entrypoint, df.workdir.as_deref()),
+            vec![EntryTarget {
+                path: "app/server.py".to_string(),
+                function: None
+            }]
+        );
+    }
+
+    #[test]
+    fn test_entry_targets() {
+        let args = |s: &str| shell_or_exec(s);
+        assert_eq!(
+            entry_targets(&args("node /app/dist/server.js --port 3000"), Some("/app")),
+            vec![EntryTarget {
+                path: "dist/server.js".to_string(),
+                function: None
+            }]
+        );
+        assert_eq!(
+            entry_targets(&args("gunicorn -b 0.0.0.0:8000 wsgi:create_app()"), None),
+            vec![EntryTarget {
+                path: "wsgi.py".to_string(),
+                function: Some("create_app".to_string())
+            }]
+        );
+        assert!(entry_targets(&args("./server"), Some("/app")).is_empty());
+        assert_eq!(join_path("a/b", "../c/./d"), "a/c/d");
+        assert_eq!(join_path("/tmp/repo", "."), "/tmp/repo");
+    }
+}
@@ -0,0 +1,441 @@
+use crate::lang::graphs::{Graph, NodeType};
+use crate::lang::proto::{call_text, line_of, matching_brace, to_pascal_case, to_snake_case};
+use crate::lang::{Edge, NodeData};
+use regex::Regex;
+use std::collections::HashMap;
+
+// graphql support. Schemas come from .graphql/.gql files and from gql`...`
+// tagged templates in source files, and are scanned like .proto files
+
+pub const GRAPHQL_VERB: &str = "GRAPHQL";
+
+const ROOT_TYPES: [&str; 3] = ["Query", "Mutation", "Subscription"];
+
+pub fn is_graphql_file(file: &str) -> bool {
+    file.ends_with(".graphql") || file.ends_with(".gql")
+}
+
+// source files only count when they have a gql`...` or graphql`...` template
+pub fn has_graphql_schema(file: &str, code: &str) -> bool {
+    is_graphql_file(file) || Regex::new(GQL_TEMPLATE).unwrap().is_match(code)
+}
+
+const GQL_TEMPLATE: &str = r"\b(?:gql|graphql)\s*`([^`]*)`";
+
+#[derive(Debug, Default)]
+pub struct GraphqlSchema {
+    // object, input, interface and enum types
+    pub data_models: Vec<NodeData>,
+    // one endpoint per field of the root types, named like Query.user
+    pub fields: Vec<NodeData>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GraphqlOperation {
+    // Query, Mutation or Subscription
+    pub root: String,
+    pub name: String,
+    // the top-level fields it selects
+    pub fields: Vec<String>,
+}
+
+pub fn parse_graphql_schema(code: &str, file: &str) -> GraphqlSchema {
+    let src = if is_graphql_file(file) {
+        strip_comments(code)
+    } else {
+        strip_comments(&gql_templates(code))
+    };
+    let mut res = GraphqlSchema::default();
+
+    let block_re =
+        Regex::new(r"\b(?:extend\s+)?(type|input|interface|enum)\s+(\w+)[^{}]*\{").unwrap();
+    let field_re = Regex::new(r"(\w+)\s*:\s*([\[\]\w!]+)").unwrap();
+
+    let mut last_close = 0;
+    for cap in block_re.captures_iter(&src) {
+        let whole = cap.get(0).unwrap();
+        // "type" inside a selection set or another block
+        if whole.start() < last_close {
+            continue;
+        }
+        let open = whole.end() - 1;
+        let Some(close) = matching_brace(&src, open) else {
+            continue;
+        };
+        last_close = close;
+        let name = &cap[2];
+        if &cap[1] != "type" || !ROOT_TYPES.contains(&name) {
+            let mut nd = NodeData::name_file(name, file);
+            nd.body = code[whole.start()..=close].to_string();
+            nd.start = line_of(code, whole.start());
+            nd.end = line_of(code, close);
+            res.data_models.push(nd);
+            continue;
+        }
+        // blank out the field arguments, so only "name: Type" is left to match
+        let body = blank_between(&src[open + 1..close], '(', ')');
+        for field in field_re.captures_iter(&body) {
+            let m = field.get(0).unwrap();
+            let start = open + 1 + m.start();
+            let end = open + 1 + m.end();
+            let mut nd = NodeData::name_file(&format!("{}.{}", name, &field[1]), file);
+            nd.body = code[start..end].to_string();
+            nd.start = line_of(code, start);
+            nd.end = line_of(code, end);
+            nd.add_verb(GRAPHQL_VERB);
+            nd.add_handler(&field[1]);
+            nd.meta.insert("type".to_string(), name.to_string());
+            nd.meta.insert("returns".to_string(), field[2].to_string());
+            res.fields.push(nd);
+        }
+    }
+    res
+}
+
+// the Handler edge goes to the resolver of the field:
+//   apollo:        const resolvers = { Query: { user: (_, { id }) => ... } }
+//   gqlgen:        func (r *queryResolver) User(ctx context.Context, id string) (*model.User, error)
+//   graphene:      class Query(graphene.ObjectType): def resolve_user(root, info, id)
+//   async-graphql: #[Object] impl QueryRoot { async fn user(&self, id: ID) }
+pub fn find_graphql_handler<G: Graph>(
+    field: &NodeData,
+    filez: &[(String, String)],
+    graph: &G,
+) -> Option<Edge> {
+    let root = field.meta.get("type")?;
+    let name = field.meta.get("handler")?;
+    let markers = [
+        (format!(r"\b{}\s*:\s*\{{", root), name.to_string()),
+        (
+            format!(
+                r"func\s*\(\s*\w+\s+\*?({}Resolver)\s*\)\s*{}\s*\(",
+                root.to_lowercase(),
+                to_pascal_case(name)
+            ),
+            to_pascal_case(name),
+        ),
+        (
+            format!(r"class\s+(\w*{}\w*)\s*\([^)]*ObjectType[^)]*\)", root),
+            format!("resolve_{}", to_snake_case(name)),
+        ),
+        (
+            format!(r"#\[Object[^\]]*\]\s*impl\s+(?:<[^>]*>\s*)?(\w*{}\w*)", root),
+            to_snake_case(name),
+        ),
+    ];
+    for (file, code) in filez {
+        if is_graphql_file(file) {
+            continue;
+        }
+        for (marker, resolver) in &markers {
+            let Ok(marker) = Regex::new(marker) else {
+                continue;
+            };
+            for cap in marker.captures_iter(code) {
+                let implementor = cap.get(1).map(|m| m.as_str());
+                let candidates = graph.find_nodes_by_name(NodeType::Function, resolver);
+                let handler = candidates
+                    .iter()
+                    .find(|f| {
+                        implementor.is_some()
+                            && f.meta.get("operand").map(|o| o.as_str()) == implementor
+                    })
+                    .or_else(|| candidates.iter().find(|f| &f.file == file));
+                if let Some(handler) = handler {
+                    return Some(Edge::handler(field, handler));
+                }
+            }
+        }
+    }
+    None
+}
+
+// operations declared in gql`...` templates, by the variable they are assigned to:
+//   const GET_USER = gql`query GetUser($id: ID!) { user(id: $id) { id name } }`
+pub fn collect_graphql_operations(filez: &[(String, String)]) -> HashMap<String, GraphqlOperation> {
+    let re = Regex::new(r"(\w+)\s*=\s*(?:gql|graphql)\s*`([^`]*)`").unwrap();
+    let mut res = HashMap::new();
+    for (file, code) in filez {
+        if is_graphql_file(file) {
+            continue;
+        }
+        for cap in re.captures_iter(code) {
+            if let Some(op) = parse_operation(&cap[2]) {
+                res.insert(cap[1].to_string(), op);
+            }
+        }
+    }
+    res
+}
+
+// client operations run with apollo/urql hooks or the client itself become
+// Requests, one per selected field, named like the field endpoint they hit:
+//   useQuery(GET_USER), useMutation<Data, Vars>(CREATE_USER)
+//   client.query({ query: GET_USER }), client.mutate({ mutation: CREATE_USER })
+pub fn find_graphql_requests(
+    file: &str,
+    functions: &[NodeData],
+    operations: &HashMap<String, GraphqlOperation>,
+) -> Vec<(NodeData, NodeData)> {
+    if operations.is_empty() {
+        return Vec::new();
+    }
+    let calls = [
+        r"\b(?:useQuery|useLazyQuery|useSuspenseQuery|useMutation|useSubscription)\s*(?:<[^>]*>)?\s*\(\s*(\w+)",
+        r"\.(?:query|mutate|subscribe)\s*\(\s*\{\s*(?:query|mutation)\s*:\s*(\w+)",
+    ];
+    let calls: Vec<Regex> = calls.iter().map(|c| Regex::new(c).unwrap()).collect();
+    let mut res = Vec::new();
+    for func in functions.iter().filter(|f| f.file == file) {
+        for call_re in &calls {
+            for cap in call_re.captures_iter(&func.body) {
+                let Some(op) = operations.get(&cap[1]) else {
+                    continue;
+                };
+                let m = cap.get(0).unwrap();
+                let line = func.start + func.body[..m.start()].matches('\n').count();
+                for field in &op.fields {
+                    let mut req = NodeData::name_file(&format!("{}.{}", op.root, field), file);
+                    req.body = call_text(&func.body, m.start());
+                    req.start = line;
+                    req.end = line;
+                    req.add_verb(GRAPHQL_VERB);
+                    req.meta.insert("operation".to_string(), op.name.clone());
+                    res.push((req, func.clone()));
+                }
+            }
+        }
+    }
+    res
+}
+
+pub fn parse_operation(text: &str) -> Option<GraphqlOperation> {
+    let src = strip_comments(text);
+    let head = Regex::new(r"^\s*(?:(query|mutation|subscription)\b\s*(\w+)?[^{]*)?\{").unwrap();
+    let cap = head.captures(&src)?;
+    let root = match cap.get(1).map(|m| m.as_str()) {
+        Some("mutation") => "Mutation",
+        Some("subscription") => "Subscription",
+        _ => "Query",
+    };
+    let name = cap.get(2).map(|m| m.as_str()).unwrap_or_default();
+    let open = cap.get(0).unwrap().end() - 1;
+    let close = matching_brace(&src, open)?;
+    // only the top level of the selection set: no arguments, sub-selections or spreads
+    let selection = blank_between(&src[open + 1..close], '(', ')');
+    let selection = blank_between(&selection, '{', '}');
+    let selection = Regex::new(r"\.\.\.\s*(?:on\s+)?\w+")
+        .unwrap()
+        .replace_all(&selection, "")
+        .to_string();
+    let field_re = Regex::new(r"(\w+)(?:\s*:\s*(\w+))?").unwrap();
+    let fields = field_re
+        .captures_iter(&selection)
+        .map(|f| f.get(2).unwrap_or_else(|| f.get(1).unwrap()).as_str().to_string())
+        .filter(|f| f != "__typename")
+        .collect();
+    Some(GraphqlOperation {
+        root: root.to_string(),
+        name: name.to_string(),
+        fields,
+    })
+}
+
+// keeps only the contents of gql`...` templates, with offsets and line numbers intact
+fn gql_templates(code: &str) -> String {
+    let mut out: Vec<u8> = code
+        .bytes()
+        .map(|b| if b == b'\n' { b'\n' } else { b' ' })
+        .collect();
+    let re = Regex::new(GQL_TEMPLATE).unwrap();
+    for cap in re.captures_iter(code) {
+        let m = cap.get(1).unwrap();
+        out[m.start()..m.end()].copy_from_slice(m.as_str().as_bytes());
+    }
+    let templated = String::from_utf8(out).unwrap_or_default();
+    // ${FRAGMENT} interpolations
+    Regex::new(r"\$\{[^}]*\}")
+        .unwrap()
+        .replace_all(&templated, |c: &regex::Captures| " ".repeat(c[0].len()))
+        .to_string()
+}
+
+// blanks out # comments and string descriptions, keeping byte offsets intact
+fn strip_comments(code: &str) -> String {
+    let bytes = code.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    out[i] = b' ';
+                    i += 1;
+                }
+            }
+            b'"' => {
+                let block = code[i..].starts_with("\"\"\"");
+                let quote = if block { "\"\"\"" } else { "\"" };
+                let end = code[i + quote.len()..]
+                    .find(quote)
+                    .map(|e| i + quote.len() + e + quote.len())
+                    .unwrap_or(bytes.len());
+                for (j, b) in out.iter_mut().enumerate().take(end).skip(i) {
+                    if bytes[j] != b'\n' {
+                        *b = b' ';
+                    }
+                }
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    String::from_utf8(out).unwrap_or_else(|_| code.to_string())
+}
+
+// blanks out everything between (nested) open and close characters
+fn blank_between(src: &str, open: char, close: char) -> String {
+    let mut depth = 0;
+    src.chars()
+        .map(|c| {
+            if c == open {
+                depth += 1;
+                return ' ';
+            }
+            if c == close && depth > 0 {
+                depth -= 1;
+                return ' ';
+            }
+            if depth > 0 && c != '\n' {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"# users and posts
+type User {
+  id: ID!
+  name: String
+  "the posts { written } by this user"
+  posts(first: Int = 10): [Post!]!
+}
+
+input NewUser {
+  name: String!
+}
+
+type Query {
+  user(id: ID!): User
+  """
+  all users, paginated
+  """
+  users(
+    first: Int
+    after: String
+  ): [User!]!
+}
+
+type Mutation {
+  createUser(input: NewUser!): User!
+}
+"#;
+
+    #[test]
+    fn test_parse_graphql_schema() {
+        let schema = parse_graphql_schema(SCHEMA, "schema.graphql");
+        let models: Vec<&str> = schema.data_models.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(models, vec!["User", "NewUser"]);
+        assert_eq!(schema.data_models[0].start, 1);
+        assert_eq!(schema.data_models[0].end, 6);
+
+        let fields: Vec<&str> = schema.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(fields, vec!["Query.user", "Query.users", "Mutation.createUser"]);
+        let users = &schema.fields[1];
+        assert_eq!(users.start, 17);
+        assert_eq!(users.end, 20);
+        assert_eq!(users.meta.get("verb"), Some(&"GRAPHQL".to_string()));
+        assert_eq!(users.meta.get("handler"), Some(&"users".to_string()));
+        assert_eq!(users.meta.get("returns"), Some(&"[User!]!".to_string()));
+    }
+
+    #[test]
+    fn test_parse_graphql_templates() {
+        let code = r#"import { gql } from "graphql-tag";
+
+export const typeDefs = gql`
+  extend type Query {
+    posts: [Post!]!
+  }
+  type Post {
+    id: ID!
+  }
+`;
+"#;
+        let schema = parse_graphql_schema(code, "src/posts.ts");
+        assert_eq!(schema.fields.len(), 1);
+        assert_eq!(schema.fields[0].name, "Query.posts");
+        assert_eq!(schema.fields[0].start, 4);
+        assert_eq!(schema.data_models.len(), 1);
+        assert_eq!(schema.data_models[0].name, "Post");
+    }
+
+    #[test]
+    fn test_parse_operation() {
+        let op = parse_operation(
+            "query GetUser($id: ID!) {\n  me: user(id: $id) { id ...UserParts }\n  __typename\n  stats { count }\n}",
+        )
+        .unwrap();
+        assert_eq!(op.root, "Query");
+        assert_eq!(op.name, "GetUser");
+        assert_eq!(op.fields, vec!["user", "stats"]);
+
+        let op = parse_operation("mutation { createUser(input: $input) { id } }").unwrap();
+        assert_eq!(op.root, "Mutation");
+        assert_eq!(op.fields, vec!["createUser"]);
+
+        let op = parse_operation("{ users { id } }").unwrap();
+        assert_eq!(op.root, "Query");
+        assert_eq!(op.fields, vec!["users"]);
+
+        assert!(parse_operation("fragment UserParts on User { id }").is_none());
+    }
+
+    #[test]
+    fn test_find_graphql_requests() {
+        let queries = (
+            "src/queries.ts".to_string(),
+            "export const GET_USER = gql`query GetUser($id: ID!) { user(id: $id) { id } }`;\nexport const CREATE_USER = gql`mutation CreateUser($input: NewUser!) { createUser(input: $input) { id } }`;".to_string(),
+        );
+        let operations = collect_graphql_operations(&[queries]);
+        assert_eq!(operations.len(), 2);
+
+        let code = "import { GET_USER, CREATE_USER } from './queries';\n\nfunction useUserPage(id: string) {\n  const { data } = useQuery<GetUserData>(GET_USER, { variables: { id } });\n  const [create] = useMutation(CREATE_USER);\n  return { data, create };\n}\n";
+        let mut func = NodeData::name_file("useUserPage", "src/user.ts");
+        func.body = code.lines().skip(2).take(5).collect::<Vec<_>>().join("\n");
+        func.start = 2;
+        func.end = 6;
+        let requests = find_graphql_requests("src/user.ts", &[func], &operations);
+        let found: Vec<(&str, usize, &str)> = requests
+            .iter()
+            .map(|(r, f)| (r.name.as_str(), r.start, f.name.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Query.user", 3, "useUserPage"),
+                ("Mutation.createUser", 4, "useUserPage")
+            ]
+        );
+        assert_eq!(
+            requests[0].0.meta.get("operation"),
+            Some(&"GetUser".to_string())
+        );
+    }
+}
@@ -123,6 +123,19 @@ impl Graph for ArrayGraph {
         }
     }
 
+    fn set_node_meta(&mut self, node_type: NodeType, node_data: &NodeData, key: &str, value: &str) {
+        let key_of = create_node_key(&Node::new(node_type, node_data.clone()));
+        if let Some(node) = self
+            .nodes
+            .iter_mut()
+            .find(|n| create_node_key(n) == key_of)
+        {
+            node.node_data
+                .meta
+                .insert(key.to_string(), value.to_string());
+        }
+    }
+
     fn get_graph_keys(&self) -> (HashSet<String>, HashSet<String>) {
         let node_keys: HashSet<String> = self.node_keys.iter().map(|s| s.to_lowercase()).collect();
         let edge_keys: HashSet<String> = self.edge_keys.iter().map(|s| s.to_lowercase()).collect();
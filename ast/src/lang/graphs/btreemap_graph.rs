@@ -66,6 +66,15 @@ impl Graph for BTreeMapGraph {
     if std::env::var("STREAM_UPLOAD").is_ok() { streaming::record_node(&node_type, &node_data); }
     }
 
+    fn set_node_meta(&mut self, node_type: NodeType, node_data: &NodeData, key: &str, value: &str) {
+        let node_key = create_node_key(&Node::new(node_type, node_data.clone()));
+        if let Some(node) = self.nodes.get_mut(&node_key) {
+            node.node_data
+                .meta
+                .insert(key.to_string(), value.to_string());
+        }
+    }
+
     fn get_graph_keys(&self) -> (HashSet<String>, HashSet<String>) {
         let node_keys: HashSet<String> = self.nodes.keys().map(|s| s.to_lowercase()).collect();
 
@@ -255,8 +264,8 @@ impl Graph for BTreeMapGraph {
             {
                 if let Some(file_node_data) = self
                     .find_nodes_by_name(NodeType::File, &file_name)
-                    .first()
-                    .cloned()
+                    .into_iter()
+                    .find(|f| f.file == func_clone.file)
                 {
                     let contains_edge = Edge::contains(
                         NodeType::File,
@@ -755,11 +764,7 @@ impl Graph for BTreeMapGraph {
     fn has_edge(&self, source: &Node, target: &Node, edge_type: EdgeType) -> bool {
         let source_key = create_node_key(source);
         let target_key = create_node_key(target);
-        if let Some(_edge) = self.find_edge_by_keys(&source_key, &target_key, &edge_type) {
-            return true;
-        } else {
-            return false;
-        }
+        self.edges.contains(&(source_key, target_key, edge_type))
     }
 }
 
@@ -27,6 +27,7 @@ pub trait Graph: Default + Debug {
             || file.ends_with(".ts")
             || file.ends_with(".jsx")
             || file.ends_with(".js")
+            || file.ends_with(".vue")
     }
     fn create_filtered_graph(self, final_filter: &[String], lang_kind: Language) -> Self
     where
@@ -48,6 +49,7 @@ pub trait Graph: Default + Debug {
     );
     fn add_edge(&mut self, edge: Edge);
     fn add_node(&mut self, node_type: NodeType, node_data: NodeData);
+    fn set_node_meta(&mut self, node_type: NodeType, node_data: &NodeData, key: &str, value: &str);
     fn get_graph_keys(&self) -> (HashSet<String>, HashSet<String>);
 
     fn find_source_edge_by_name_and_file(
@@ -152,3 +154,71 @@ pub trait Graph: Default + Debug {
     }
     fn has_edge(&self, source: &Node, target: &Node, edge_type: EdgeType) -> bool;
 }
+
+// Graph is not object safe, so the passes a Stack runs over the built graph get this view of it
+pub trait GraphEdit {
+    fn find_nodes_by_name(&self, node_type: NodeType, name: &str) -> Vec<NodeData>;
+    fn find_nodes_by_type(&self, node_type: NodeType) -> Vec<NodeData>;
+    fn find_nodes_by_file_ends_with(&self, node_type: NodeType, file: &str) -> Vec<NodeData>;
+    fn find_node_by_name_in_file(
+        &self,
+        node_type: NodeType,
+        name: &str,
+        file: &str,
+    ) -> Option<NodeData>;
+    fn has_edge(&self, source: &Node, target: &Node, edge_type: EdgeType) -> bool;
+    fn add_node_with_parent(
+        &mut self,
+        node_type: NodeType,
+        node_data: NodeData,
+        parent_type: NodeType,
+        parent_file: &str,
+    );
+    fn add_edge(&mut self, edge: Edge);
+    fn add_page(&mut self, page: (NodeData, Option<Edge>));
+    fn add_endpoints(&mut self, endpoints: Vec<(NodeData, Option<Edge>)>);
+}
+
+pub struct GraphEditor<'a, G: Graph>(pub &'a mut G);
+
+impl<G: Graph> GraphEdit for GraphEditor<'_, G> {
+    fn find_nodes_by_name(&self, node_type: NodeType, name: &str) -> Vec<NodeData> {
+        self.0.find_nodes_by_name(node_type, name)
+    }
+    fn find_nodes_by_type(&self, node_type: NodeType) -> Vec<NodeData> {
+        self.0.find_nodes_by_type(node_type)
+    }
+    fn find_nodes_by_file_ends_with(&self, node_type: NodeType, file: &str) -> Vec<NodeData> {
+        self.0.find_nodes_by_file_ends_with(node_type, file)
+    }
+    fn find_node_by_name_in_file(
+        &self,
+        node_type: NodeType,
+        name: &str,
+        file: &str,
+    ) -> Option<NodeData> {
+        self.0.find_node_by_name_in_file(node_type, name, file)
+    }
+    fn has_edge(&self, source: &Node, target: &Node, edge_type: EdgeType) -> bool {
+        self.0.has_edge(source, target, edge_type)
+    }
+    fn add_node_with_parent(
+        &mut self,
+        node_type: NodeType,
+        node_data: NodeData,
+        parent_type: NodeType,
+        parent_file: &str,
+    ) {
+        self.0
+            .add_node_with_parent(node_type, node_data, parent_type, parent_file)
+    }
+    fn add_edge(&mut self, edge: Edge) {
+        self.0.add_edge(edge)
+    }
+    fn add_page(&mut self, page: (NodeData, Option<Edge>)) {
+        self.0.add_page(page)
+    }
+    fn add_endpoints(&mut self, endpoints: Vec<(NodeData, Option<Edge>)>) {
+        self.0.add_endpoints(endpoints)
+    }
+}
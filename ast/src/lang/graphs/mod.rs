@@ -47,6 +47,9 @@ pub enum NodeType {
     Feature,
     Page,
     Var,
+    Service,
+    Job,
+    Queue,
 }
 
 // pub enum TestType {
@@ -79,7 +82,7 @@ pub enum EdgeType {
     Contains, // Module -> Function/Class/Module OR File -> Function/Class/Module
     Imports,  // File -> Module
     Of,       // Instance -> Class
-    Handler,  // Endpoint -> Function
+    Handler,  // Endpoint/Job -> Function
     Includes, // Feature -> Function/Class/Module/Endpoint/Request/DataModel/Test
     Renders,  // Page -> Component
     #[serde(rename = "PARENT_OF")]
@@ -87,6 +90,13 @@ pub enum EdgeType {
     Implements, // Class -> Trait
     #[serde(rename = "NESTED_IN")]
     NestedIn, // Function -> Function (child to parent)
+    References, // DataModel -> DataModel (foreign key, or ORM model to its table)
+    #[serde(rename = "DEPENDS_ON")]
+    DependsOn, // Service -> Service, Library -> Library
+    Builds,    // Service -> Directory/Repository (build context)
+    Runs,      // Service -> File/Function (container entrypoint)
+    #[serde(rename = "ON_QUEUE")]
+    OnQueue, // Job -> Queue
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
@@ -181,6 +191,20 @@ impl Edge {
             NodeRef::from(f.into(), NodeType::Function),
         )
     }
+    pub fn job_handler(j: &NodeData, f: &NodeData) -> Edge {
+        Edge::new(
+            EdgeType::Handler,
+            NodeRef::from(j.into(), NodeType::Job),
+            NodeRef::from(f.into(), NodeType::Function),
+        )
+    }
+    pub fn on_queue(j: &NodeData, q: &NodeData) -> Edge {
+        Edge::new(
+            EdgeType::OnQueue,
+            NodeRef::from(j.into(), NodeType::Job),
+            NodeRef::from(q.into(), NodeType::Queue),
+        )
+    }
     pub fn renders(e: &NodeData, f: &NodeData) -> Edge {
         Edge::new(
             EdgeType::Renders,
@@ -231,6 +255,41 @@ impl Edge {
             NodeRef::from(tr.into(), NodeType::Trait),
         )
     }
+    pub fn references(source: &NodeData, target: &NodeData) -> Edge {
+        Edge::new(
+            EdgeType::References,
+            NodeRef::from(source.into(), NodeType::DataModel),
+            NodeRef::from(target.into(), NodeType::DataModel),
+        )
+    }
+    pub fn depends_on(service: &NodeData, dependency: &NodeData) -> Edge {
+        Edge::new(
+            EdgeType::DependsOn,
+            NodeRef::from(service.into(), NodeType::Service),
+            NodeRef::from(dependency.into(), NodeType::Service),
+        )
+    }
+    pub fn library_depends_on(library: &NodeData, dependency: &NodeData) -> Edge {
+        Edge::new(
+            EdgeType::DependsOn,
+            NodeRef::from(library.into(), NodeType::Library),
+            NodeRef::from(dependency.into(), NodeType::Library),
+        )
+    }
+    pub fn builds(service: &NodeData, context_type: NodeType, context: &NodeData) -> Edge {
+        Edge::new(
+            EdgeType::Builds,
+            NodeRef::from(service.into(), NodeType::Service),
+            NodeRef::from(context.into(), context_type),
+        )
+    }
+    pub fn runs(service: &NodeData, target_type: NodeType, target: &NodeData) -> Edge {
+        Edge::new(
+            EdgeType::Runs,
+            NodeRef::from(service.into(), NodeType::Service),
+            NodeRef::from(target.into(), target_type),
+        )
+    }
 }
 
 impl From<Operand> for Edge {
@@ -291,6 +350,11 @@ impl ToString for EdgeType {
             EdgeType::Calls => "CALLS".to_string(),
             EdgeType::Implements => "IMPLEMENTS".to_string(),
             EdgeType::NestedIn => "NESTED_IN".to_string(),
+            EdgeType::References => "REFERENCES".to_string(),
+            EdgeType::DependsOn => "DEPENDS_ON".to_string(),
+            EdgeType::Builds => "BUILDS".to_string(),
+            EdgeType::Runs => "RUNS".to_string(),
+            EdgeType::OnQueue => "ON_QUEUE".to_string(),
         }
     }
 }
@@ -312,6 +376,11 @@ impl FromStr for EdgeType {
             "PARENT_OF" => Ok(EdgeType::ParentOf),
             "IMPLEMENTS" => Ok(EdgeType::Implements),
             "NESTED_IN" => Ok(EdgeType::NestedIn),
+            "REFERENCES" => Ok(EdgeType::References),
+            "DEPENDS_ON" => Ok(EdgeType::DependsOn),
+            "BUILDS" => Ok(EdgeType::Builds),
+            "RUNS" => Ok(EdgeType::Runs),
+            "ON_QUEUE" => Ok(EdgeType::OnQueue),
             _ => Err(Error::Custom(format!("Invalid EdgeType: {}", s))),
         }
     }
@@ -1252,6 +1252,12 @@ impl Graph for Neo4jGraph {
                 .unwrap_or_default()
         })
     }
+    fn set_node_meta(&mut self, node_type: NodeType, node_data: &NodeData, key: &str, value: &str) {
+        // add_node merges on the node key, so this only updates the properties
+        let mut node_data = node_data.clone();
+        node_data.meta.insert(key.to_string(), value.to_string());
+        self.add_node(node_type, node_data);
+    }
     fn get_graph_keys(&self) -> (HashSet<String>, HashSet<String>) {
         sync_fn(|| async { self.get_graph_keys_async().await })
     }
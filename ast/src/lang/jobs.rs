@@ -0,0 +1,729 @@
+use crate::lang::queries::python::{
+    assignments, call_name, keyword_arg, last_name, positional_args, string_value,
+};
+use crate::lang::graphs::{Edge, EdgeType, GraphEdit, Node, NodeType};
+use crate::lang::NodeData;
+use std::collections::HashMap;
+use tracing::info;
+use tree_sitter::{Node as TreeNode, Parser};
+
+// background jobs, as Job nodes. A job runs its handler function (the perform
+// method of an ActiveJob or Sidekiq class, or a Celery, RQ or Dramatiq task
+// function), and the call sites that enqueue the job call that handler.
+// Celery beat entries are Jobs too, that run the handler of the task they fire.
+// Each job is on a Queue node, one per queue name in the repo
+
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub node: NodeData,
+    // the function that runs the job
+    pub handler: String,
+}
+
+// a call that enqueues a job, like HardJob.perform_later(user)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enqueue {
+    pub job: String,
+    pub line: usize,
+    // the framework the job must be from, for enqueue methods as common as send
+    pub framework: Option<&'static str>,
+}
+
+// a celery beat entry, and the task it fires
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    pub node: NodeData,
+    pub task: String,
+}
+
+const DEFAULT_QUEUE: &str = "default";
+const CELERY_QUEUE: &str = "celery";
+const BEAT_FRAMEWORK: &str = "celery_beat";
+const RUBY_HANDLER: &str = "perform";
+const SIDEKIQ_MODULES: [&str; 2] = ["Sidekiq::Worker", "Sidekiq::Job"];
+const RUBY_ENQUEUE_METHODS: [&str; 5] = [
+    "perform_later",
+    "perform_async",
+    "perform_in",
+    "perform_at",
+    "perform_bulk",
+];
+const PYTHON_ENQUEUE_METHODS: [&str; 2] = ["delay", "apply_async"];
+const DRAMATIQ_ENQUEUE_METHODS: [&str; 2] = ["send", "send_with_options"];
+const DRAMATIQ_FRAMEWORK: &str = "dramatiq";
+const RQ_ENQUEUE_METHODS: [&str; 4] = ["enqueue", "enqueue_call", "enqueue_in", "enqueue_at"];
+
+// the job of an enqueue call, by its full name or task name, or by its name without a module
+pub fn find_job<'a>(jobs: &'a [Job], name: &str) -> Option<&'a Job> {
+    let task = |j: &Job| j.node.meta.get("task").cloned().unwrap_or_default();
+    jobs.iter()
+        .find(|j| j.node.name == name || task(j) == name)
+        .or_else(|| jobs.iter().find(|j| task(j).ends_with(&format!(".{}", name))))
+        .or_else(|| jobs.iter().find(|j| short_name(&j.node.name) == short_name(name)))
+}
+
+// ReportJob for Admin::ReportJob, add for proj.tasks.add
+fn short_name(name: &str) -> &str {
+    name.rsplit(['.', ':']).next().unwrap_or(name)
+}
+
+// adds the jobs and schedules with their queues, and links them to their
+// handlers and the enqueues (found by the enqueues fn) to the handler they run
+pub fn add_jobs(
+    graph: &mut dyn GraphEdit,
+    files: &[&(String, String)],
+    root: &str,
+    jobs: Vec<Job>,
+    schedules: Vec<Schedule>,
+    enqueues: fn(&str) -> Vec<Enqueue>,
+) {
+    let mut handlers = HashMap::new();
+    let mut queues: HashMap<String, NodeData> = HashMap::new();
+    for job in jobs.iter().map(|j| &j.node).chain(schedules.iter().map(|s| &s.node)) {
+        let file = job.file.clone();
+        graph.add_node_with_parent(NodeType::Job, job.clone(), NodeType::File, &file);
+        let Some(name) = job.meta.get("queue") else {
+            continue;
+        };
+        let queue = queues.entry(name.clone()).or_insert_with(|| {
+            let queue = NodeData::name_file(name, root);
+            graph.add_node_with_parent(NodeType::Queue, queue.clone(), NodeType::Repository, root);
+            queue
+        });
+        graph.add_edge(Edge::on_queue(job, queue));
+    }
+    for job in &jobs {
+        let file = job.node.file.clone();
+        let handler = graph
+            .find_nodes_by_file_ends_with(NodeType::Function, &file)
+            .into_iter()
+            .find(|f| {
+                f.file == file
+                    && f.name == job.handler
+                    && f.start >= job.node.start
+                    && f.end <= job.node.end
+            });
+        if let Some(handler) = handler {
+            graph.add_edge(Edge::job_handler(&job.node, &handler));
+            handlers.insert((job.node.name.clone(), file), handler);
+        }
+    }
+    let handler_of = |name: &str, framework: Option<&str>| {
+        find_job(&jobs, name)
+            .filter(|job| {
+                framework.is_none_or(|f| job.node.meta.get("framework").is_some_and(|jf| jf == f))
+            })
+            .and_then(|job| handlers.get(&(job.node.name.clone(), job.node.file.clone())))
+    };
+
+    // beat entries run the handler of the task they fire
+    for schedule in &schedules {
+        if let Some(handler) = handler_of(&schedule.task, None) {
+            graph.add_edge(Edge::job_handler(&schedule.node, handler));
+        }
+    }
+    info!("=> got {} jobs on {} queues", jobs.len() + schedules.len(), queues.len());
+
+    // the function that enqueues a job calls its handler
+    for (filename, code) in files {
+        let enqueues = enqueues(code);
+        if enqueues.is_empty() {
+            continue;
+        }
+        let functions: Vec<NodeData> = graph
+            .find_nodes_by_file_ends_with(NodeType::Function, filename)
+            .into_iter()
+            .filter(|f| &f.file == filename)
+            .collect();
+        for enqueue in enqueues {
+            let Some(handler) = handler_of(&enqueue.job, enqueue.framework) else {
+                continue;
+            };
+            let caller = functions
+                .iter()
+                .filter(|f| f.start <= enqueue.line && enqueue.line <= f.end)
+                .min_by_key(|f| f.end - f.start);
+            let Some(caller) = caller else {
+                continue;
+            };
+            let (source, target) = (
+                Node::new(NodeType::Function, caller.clone()),
+                Node::new(NodeType::Function, handler.clone()),
+            );
+            if !graph.has_edge(&source, &target, EdgeType::Calls) {
+                graph.add_edge(Edge::calls(NodeType::Function, caller, NodeType::Function, handler));
+            }
+        }
+    }
+}
+
+// ActiveJob subclasses and classes that include Sidekiq::Worker (or Sidekiq::Job)
+pub fn ruby_jobs(code: &str, file: &str) -> Vec<Job> {
+    let mut res = Vec::new();
+    let Some(tree) = parse_ruby(code) else {
+        return res;
+    };
+    for class in descendants(tree.root_node(), "class") {
+        let Some(name) = class.child_by_field_name("name").map(|n| text(n, code)) else {
+            continue;
+        };
+        let superclass = class
+            .child_by_field_name("superclass")
+            .and_then(|s| s.named_child(0))
+            .map(|s| text(s, code))
+            .unwrap_or_default();
+        let calls = class
+            .child_by_field_name("body")
+            .map(|b| body_calls(b, code))
+            .unwrap_or_default();
+        let sidekiq = calls
+            .iter()
+            .any(|(method, arg, _)| method == "include" && SIDEKIQ_MODULES.contains(&arg.as_str()));
+        let framework = if sidekiq {
+            "sidekiq"
+        } else if superclass.ends_with("Job") {
+            "activejob"
+        } else {
+            continue;
+        };
+        // ApplicationJob and other abstract bases have nothing to perform
+        let performs = descendants(class, "method").into_iter().any(|m| {
+            m.child_by_field_name("name")
+                .is_some_and(|n| text(n, code) == RUBY_HANDLER)
+        });
+        if !performs {
+            continue;
+        }
+        let queue = calls
+            .iter()
+            .find_map(|(method, _, queue)| match method.as_str() {
+                "queue_as" | "sidekiq_options" => queue.clone(),
+                _ => None,
+            })
+            .unwrap_or_else(|| DEFAULT_QUEUE.to_string());
+
+        let mut node = NodeData::name_file(&name, file);
+        node.body = text(class, code);
+        node.start = class.start_position().row;
+        node.end = class.end_position().row;
+        node.meta.insert("framework".to_string(), framework.to_string());
+        node.meta.insert("queue".to_string(), queue);
+        res.push(Job {
+            node,
+            handler: RUBY_HANDLER.to_string(),
+        });
+    }
+    res
+}
+
+// ReportJob.perform_later(...) and Admin::CleanupJob.set(...).perform_later: the
+// receiver is a job constant that ruby_enqueues resolves
+pub fn is_ruby_enqueue(method: &str, receiver: &str) -> bool {
+    RUBY_ENQUEUE_METHODS.contains(&method)
+        && receiver.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+// HardJob.perform_later, HardJob.set(wait: 1.hour).perform_later and HardWorker.perform_async
+pub fn ruby_enqueues(code: &str) -> Vec<Enqueue> {
+    let mut res = Vec::new();
+    let Some(tree) = parse_ruby(code) else {
+        return res;
+    };
+    for call in descendants(tree.root_node(), "call") {
+        let is_enqueue = call
+            .child_by_field_name("method")
+            .is_some_and(|m| RUBY_ENQUEUE_METHODS.contains(&text(m, code).as_str()));
+        if !is_enqueue {
+            continue;
+        }
+        let mut receiver = call.child_by_field_name("receiver");
+        // configured jobs: HardJob.set(queue: :low).perform_later
+        if let Some(r) = receiver.filter(|r| r.kind() == "call") {
+            let is_set = r
+                .child_by_field_name("method")
+                .is_some_and(|m| text(m, code) == "set");
+            receiver = if is_set { r.child_by_field_name("receiver") } else { None };
+        }
+        let Some(receiver) = receiver.filter(|r| matches!(r.kind(), "constant" | "scope_resolution"))
+        else {
+            continue;
+        };
+        res.push(Enqueue {
+            job: text(receiver, code),
+            line: call.start_position().row,
+            framework: None,
+        });
+    }
+    res
+}
+
+// @shared_task, @app.task(queue="emails"), @dramatiq.actor and rq's @job("low")
+pub fn python_jobs(code: &str, file: &str, root: &str) -> Vec<Job> {
+    let mut res = Vec::new();
+    let Some(tree) = parse_python(code) else {
+        return res;
+    };
+    let rq = code.contains("from rq") || code.contains("import rq");
+    let module = python_module(file, root);
+    for def in descendants(tree.root_node(), "decorated_definition") {
+        let Some(func) = def
+            .child_by_field_name("definition")
+            .filter(|d| d.kind() == "function_definition")
+        else {
+            continue;
+        };
+        let Some(name) = func.child_by_field_name("name").map(|n| text(n, code)) else {
+            continue;
+        };
+        let mut cursor = def.walk();
+        let decorators = def
+            .named_children(&mut cursor)
+            .filter(|d| d.kind() == "decorator")
+            .collect::<Vec<_>>();
+        let Some((framework, options)) = decorators
+            .iter()
+            .find_map(|d| task_decorator(*d, code, rq))
+        else {
+            continue;
+        };
+        let option = |names: &[&str]| {
+            options
+                .and_then(|c| keyword_arg(c, names, code))
+                .and_then(|v| string_value(v, code))
+        };
+        let (task, queue) = match framework {
+            "celery" => (
+                option(&["name"]).unwrap_or_else(|| join_module(&module, &name)),
+                option(&["queue"]).unwrap_or_else(|| CELERY_QUEUE.to_string()),
+            ),
+            "dramatiq" => (
+                option(&["actor_name"]).unwrap_or_else(|| name.clone()),
+                option(&["queue_name"]).unwrap_or_else(|| DEFAULT_QUEUE.to_string()),
+            ),
+            // @job("low", connection=redis)
+            _ => (
+                join_module(&module, &name),
+                options
+                    .and_then(|c| positional_args(c).first().and_then(|a| string_value(*a, code)))
+                    .or_else(|| option(&["queue"]))
+                    .unwrap_or_else(|| DEFAULT_QUEUE.to_string()),
+            ),
+        };
+
+        let mut node = NodeData::name_file(&name, file);
+        node.body = text(def, code);
+        node.start = def.start_position().row;
+        node.end = def.end_position().row;
+        node.meta.insert("framework".to_string(), framework.to_string());
+        node.meta.insert("task".to_string(), task);
+        node.meta.insert("queue".to_string(), queue);
+        res.push(Job {
+            node,
+            handler: name,
+        });
+    }
+    res
+}
+
+// add.delay(), add.apply_async(), send_email.send() on a dramatiq actor, app.send_task("proj.tasks.add")
+// and rq's queue.enqueue(count_words)
+pub fn python_enqueues(code: &str) -> Vec<Enqueue> {
+    let mut res = Vec::new();
+    let Some(tree) = parse_python(code) else {
+        return res;
+    };
+    for call in descendants(tree.root_node(), "call") {
+        let Some(function) = call
+            .child_by_field_name("function")
+            .filter(|f| f.kind() == "attribute")
+        else {
+            continue;
+        };
+        let Some(method) = function.child_by_field_name("attribute").map(|a| text(a, code)) else {
+            continue;
+        };
+        let receiver = || {
+            function
+                .child_by_field_name("object")
+                .and_then(|o| last_name(o, code))
+        };
+        let mut framework = None;
+        let job = match method.as_str() {
+            m if PYTHON_ENQUEUE_METHODS.contains(&m) => receiver(),
+            // only a dramatiq actor's send enqueues it, not a socket's or a signal's
+            m if DRAMATIQ_ENQUEUE_METHODS.contains(&m) => {
+                framework = Some(DRAMATIQ_FRAMEWORK);
+                receiver()
+            }
+            "send_task" => positional_args(call)
+                .first()
+                .and_then(|a| string_value(*a, code)),
+            m if RQ_ENQUEUE_METHODS.contains(&m) => keyword_arg(call, &["func"], code)
+                .or_else(|| {
+                    positional_args(call)
+                        .into_iter()
+                        .find(|a| matches!(a.kind(), "identifier" | "attribute" | "string"))
+                })
+                .and_then(|a| string_value(a, code).or_else(|| last_name(a, code))),
+            _ => None,
+        };
+        if let Some(job) = job {
+            res.push(Enqueue {
+                job,
+                line: call.start_position().row,
+                framework,
+            });
+        }
+    }
+    res
+}
+
+// beat_schedule = {"cleanup": {"task": "proj.tasks.cleanup", "schedule": crontab(hour=3)}},
+// CELERY_BEAT_SCHEDULE in django settings, and sender.add_periodic_task(30.0, ping.s())
+pub fn python_schedules(code: &str, file: &str) -> Vec<Schedule> {
+    let mut res = Vec::new();
+    let Some(tree) = parse_python(code) else {
+        return res;
+    };
+    let schedule = |name: &str, node: TreeNode, task: String, every: String| {
+        let mut nd = NodeData::name_file(name, file);
+        nd.body = text(node, code);
+        nd.start = node.start_position().row;
+        nd.end = node.end_position().row;
+        nd.meta.insert("framework".to_string(), BEAT_FRAMEWORK.to_string());
+        nd.meta.insert("task".to_string(), task.clone());
+        nd.meta.insert("schedule".to_string(), every);
+        Schedule { node: nd, task }
+    };
+    for assignment in assignments(tree.root_node()) {
+        let is_beat = assignment
+            .child_by_field_name("left")
+            .is_some_and(|l| text(l, code).to_lowercase().ends_with("beat_schedule"));
+        let Some(entries) = assignment
+            .child_by_field_name("right")
+            .filter(|r| is_beat && r.kind() == "dictionary")
+        else {
+            continue;
+        };
+        let mut cursor = entries.walk();
+        for entry in entries.named_children(&mut cursor).filter(|e| e.kind() == "pair") {
+            let Some(name) = entry
+                .child_by_field_name("key")
+                .and_then(|k| string_value(k, code))
+            else {
+                continue;
+            };
+            let Some(options) = entry
+                .child_by_field_name("value")
+                .filter(|v| v.kind() == "dictionary")
+            else {
+                continue;
+            };
+            let option = |key: &str| {
+                let mut cursor = options.walk();
+                let value = options
+                    .named_children(&mut cursor)
+                    .filter(|o| o.kind() == "pair")
+                    .find(|o| {
+                        o.child_by_field_name("key")
+                            .and_then(|k| string_value(k, code))
+                            .is_some_and(|k| k == key)
+                    })
+                    .and_then(|o| o.child_by_field_name("value"));
+                value
+            };
+            let Some(task) = option("task").and_then(|t| string_value(t, code)) else {
+                continue;
+            };
+            let every = option("schedule").map(|s| text(s, code)).unwrap_or_default();
+            res.push(schedule(&name, entry, task, every));
+        }
+    }
+    for call in descendants(tree.root_node(), "call") {
+        if call_name(call, code).as_deref() != Some("add_periodic_task") {
+            continue;
+        }
+        let args = positional_args(call);
+        let every = args
+            .first()
+            .copied()
+            .or_else(|| keyword_arg(call, &["schedule"], code))
+            .map(|s| text(s, code))
+            .unwrap_or_default();
+        // ping.s("hello"), or a bare task
+        let task = args
+            .get(1)
+            .copied()
+            .or_else(|| keyword_arg(call, &["sig"], code))
+            .and_then(|sig| match sig.kind() {
+                "call" => sig
+                    .child_by_field_name("function")
+                    .and_then(|f| f.child_by_field_name("object"))
+                    .and_then(|o| last_name(o, code)),
+                _ => last_name(sig, code),
+            });
+        let Some(task) = task else {
+            continue;
+        };
+        let name = keyword_arg(call, &["name"], code)
+            .and_then(|n| string_value(n, code))
+            .unwrap_or_else(|| task.clone());
+        res.push(schedule(&name, call, task, every));
+    }
+    res
+}
+
+// the framework of a task decorator, and its call with options
+fn task_decorator<'a>(
+    decorator: TreeNode<'a>,
+    code: &str,
+    rq: bool,
+) -> Option<(&'static str, Option<TreeNode<'a>>)> {
+    let expr = decorator.named_child(0)?;
+    let (target, options) = match expr.kind() {
+        "call" => (expr.child_by_field_name("function")?, Some(expr)),
+        _ => (expr, None),
+    };
+    let framework = match text(target, code).as_str() {
+        "shared_task" | "celery.shared_task" => "celery",
+        "actor" | "dramatiq.actor" => "dramatiq",
+        "job" if rq => "rq",
+        t if t.ends_with(".task") => "celery",
+        _ => return None,
+    };
+    Some((framework, options))
+}
+
+// proj/tasks.py => proj.tasks, relative to the repo root
+fn python_module(file: &str, root: &str) -> String {
+    let path = file.strip_prefix(root).unwrap_or(file).trim_start_matches('/');
+    let path = path.trim_end_matches(".py");
+    let path = path.strip_suffix("/__init__").unwrap_or(path);
+    path.replace('/', ".")
+}
+
+fn join_module(module: &str, name: &str) -> String {
+    if module.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", module, name)
+    }
+}
+
+fn parse_ruby(code: &str) -> Option<tree_sitter::Tree> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_ruby::LANGUAGE.into())
+        .ok()?;
+    parser.parse(code, None)
+}
+
+fn parse_python(code: &str) -> Option<tree_sitter::Tree> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_python::LANGUAGE.into())
+        .ok()?;
+    parser.parse(code, None)
+}
+
+fn text(node: TreeNode, code: &str) -> String {
+    node.utf8_text(code.as_bytes()).unwrap_or_default().to_string()
+}
+
+fn descendants<'a>(node: TreeNode<'a>, kind: &str) -> Vec<TreeNode<'a>> {
+    let mut res = Vec::new();
+    let mut stack = vec![node];
+    while let Some(n) = stack.pop() {
+        if n.kind() == kind {
+            res.push(n);
+        }
+        let mut cursor = n.walk();
+        let children = n.named_children(&mut cursor).collect::<Vec<_>>();
+        stack.extend(children.into_iter().rev());
+    }
+    res
+}
+
+// the calls made directly in a class body: (method, first argument, queue option)
+fn body_calls(body: TreeNode, code: &str) -> Vec<(String, String, Option<String>)> {
+    let mut res = Vec::new();
+    let mut cursor = body.walk();
+    for call in body.named_children(&mut cursor) {
+        if call.kind() != "call" || call.child_by_field_name("receiver").is_some() {
+            continue;
+        }
+        let Some(method) = call.child_by_field_name("method").map(|m| text(m, code)) else {
+            continue;
+        };
+        let args = call
+            .child_by_field_name("arguments")
+            .map(|a| {
+                let mut cursor = a.walk();
+                a.named_children(&mut cursor).collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let first = args.first().map(|a| text(*a, code)).unwrap_or_default();
+        // queue_as :mailers, or sidekiq_options queue: "critical"
+        let queue = args.iter().find_map(|a| match a.kind() {
+            "simple_symbol" | "string" => Some(symbol_value(&text(*a, code))),
+            "pair" => {
+                let key = a.child_by_field_name("key").map(|k| text(k, code))?;
+                let value = a.child_by_field_name("value").map(|v| text(v, code))?;
+                (symbol_value(&key) == "queue").then(|| symbol_value(&value))
+            }
+            _ => None,
+        });
+        res.push((method, first, queue));
+    }
+    res
+}
+
+// :mailers, "mailers" or queue: => mailers, queue
+fn symbol_value(s: &str) -> String {
+    s.trim_end_matches(':')
+        .trim_start_matches(':')
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ruby_jobs() {
+        let code = r#"class ApplicationJob < ActiveJob::Base
+end
+
+class ReportJob < ApplicationJob
+  queue_as :reports
+
+  def perform(report)
+    report.generate
+  end
+end
+
+class HardWorker
+  include Sidekiq::Worker
+  sidekiq_options queue: "critical", retry: 3
+
+  def perform(id)
+  end
+end
+
+class PlainWorker
+  def perform
+  end
+end
+"#;
+        let jobs = ruby_jobs(code, "app/jobs/report_job.rb");
+        assert_eq!(jobs.len(), 2);
+        assert_eq!(jobs[0].node.name, "ReportJob");
+        assert_eq!(jobs[0].node.meta.get("framework"), Some(&"activejob".to_string()));
+        assert_eq!(jobs[0].node.meta.get("queue"), Some(&"reports".to_string()));
+        assert_eq!(jobs[1].node.name, "HardWorker");
+        assert_eq!(jobs[1].node.meta.get("framework"), Some(&"sidekiq".to_string()));
+        assert_eq!(jobs[1].node.meta.get("queue"), Some(&"critical".to_string()));
+    }
+
+    #[test]
+    fn test_ruby_enqueues() {
+        let code = r#"def create
+  ReportJob.perform_later(@report)
+  Admin::CleanupJob.set(wait: 1.hour).perform_later
+  HardWorker.perform_async(1)
+  ReportJob.new.perform(@report)
+end
+"#;
+        assert_eq!(
+            ruby_enqueues(code),
+            vec![
+                Enqueue { job: "ReportJob".to_string(), line: 1, framework: None },
+                Enqueue { job: "Admin::CleanupJob".to_string(), line: 2, framework: None },
+                Enqueue { job: "HardWorker".to_string(), line: 3, framework: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_python_jobs() {
+        let code = r#"from celery import shared_task
+import dramatiq
+from rq.decorators import job
+
+@shared_task
+def add(x, y):
+    return x + y
+
+@app.task(name="reports.build", queue="reports")
+def build_report(report_id):
+    pass
+
+@dramatiq.actor(queue_name="emails")
+def send_welcome(user_id):
+    pass
+
+@job("low", connection=redis)
+def count_words(url):
+    pass
+
+@login_required
+def view(request):
+    add.delay(1, 2)
+    build_report.apply_async(args=[1], countdown=10)
+    send_welcome.send(request.user.id)
+    app.send_task("proj.tasks.add", args=[1, 2])
+    queue.enqueue(count_words, "http://example.com")
+    sock.send(b"ping")
+"#;
+        let jobs = python_jobs(code, "repo/proj/tasks.py", "repo");
+        let task = |j: &Job, key: &str| j.node.meta.get(key).cloned().unwrap_or_default();
+        assert_eq!(jobs.len(), 4);
+        assert_eq!(task(&jobs[0], "task"), "proj.tasks.add");
+        assert_eq!(task(&jobs[0], "queue"), "celery");
+        assert_eq!(task(&jobs[1], "task"), "reports.build");
+        assert_eq!(task(&jobs[1], "queue"), "reports");
+        assert_eq!(task(&jobs[2], "framework"), "dramatiq");
+        assert_eq!(task(&jobs[2], "queue"), "emails");
+        assert_eq!(task(&jobs[3], "framework"), "rq");
+        assert_eq!(task(&jobs[3], "queue"), "low");
+
+        let enqueues = python_enqueues(code);
+        let names = enqueues.iter().map(|e| e.job.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["add", "build_report", "send_welcome", "proj.tasks.add", "count_words", "sock"]
+        );
+        assert_eq!(enqueues[2].framework, Some("dramatiq"));
+        assert_eq!(enqueues[5].framework, Some("dramatiq"));
+        assert_eq!(enqueues[0].framework, None);
+        assert_eq!(find_job(&jobs, "proj.tasks.add").map(|j| j.node.name.as_str()), Some("add"));
+        assert_eq!(find_job(&jobs, "tasks.add").map(|j| j.node.name.as_str()), Some("add"));
+    }
+
+    #[test]
+    fn test_python_schedules() {
+        let code = r#"app.conf.beat_schedule = {
+    "nightly-report": {
+        "task": "reports.build",
+        "schedule": crontab(hour=3, minute=0),
+    },
+}
+
+@app.on_after_configure.connect
+def setup_periodic_tasks(sender, **kwargs):
+    sender.add_periodic_task(30.0, add.s(1, 2), name="add every 30")
+"#;
+        let schedules = python_schedules(code, "proj/celery.py");
+        assert_eq!(schedules.len(), 2);
+        assert_eq!(schedules[0].node.name, "nightly-report");
+        assert_eq!(schedules[0].task, "reports.build");
+        assert_eq!(
+            schedules[0].node.meta.get("schedule"),
+            Some(&"crontab(hour=3, minute=0)".to_string())
+        );
+        assert_eq!(schedules[1].node.name, "add every 30");
+        assert_eq!(schedules[1].task, "add");
+        assert_eq!(schedules[1].node.meta.get("schedule"), Some(&"30.0".to_string()));
+    }
+}
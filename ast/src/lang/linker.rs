@@ -1,8 +1,12 @@
 use crate::lang::graphs::{Graph, NodeType};
+use crate::lang::graphql::GRAPHQL_VERB;
+use crate::lang::openapi::OPENAPI_SOURCE;
+use crate::lang::proto::{grpc_paths_match, GRPC_VERB};
 use crate::lang::{Edge, Language, NodeData};
 use lsp::language::PROGRAMMING_LANGUAGES;
 use regex::Regex;
 use shared::{Context, Error, Result};
+use std::collections::HashSet;
 use std::path::PathBuf;
 use tracing::info;
 
@@ -141,6 +145,10 @@ pub fn link_api_nodes<G: Graph>(graph: &mut G) -> Result<()> {
     }
 
     for node_data in endpoint_nodes {
+        // spec endpoints are linked to the code endpoints instead
+        if is_spec_endpoint(&node_data) {
+            continue;
+        }
         if let Some(normalized_path) = normalize_backend_path(&node_data.name) {
             backend_endpoints.push((node_data, normalized_path));
         }
@@ -150,7 +158,13 @@ pub fn link_api_nodes<G: Graph>(graph: &mut G) -> Result<()> {
     let mut i = 0;
     for (req, req_path) in frontend_requests {
         for (endpoint, _) in &backend_endpoints {
-            if paths_match(&req_path, &endpoint.name) && verbs_match(&req, endpoint) {
+            let path_matches = match req.meta.get("verb").map(|v| v.as_str()) {
+                Some(GRPC_VERB) => grpc_paths_match(&req.name, &endpoint.name),
+                // graphql requests are named after the root field they select
+                Some(GRAPHQL_VERB) => req.name == endpoint.name,
+                _ => paths_match(&req_path, &endpoint.name),
+            };
+            if path_matches && verbs_match(&req, endpoint) {
                 let edge = Edge::calls(NodeType::Request, &req, NodeType::Endpoint, endpoint);
                 graph.add_edge(edge);
                 i += 1;
@@ -162,6 +176,71 @@ pub fn link_api_nodes<G: Graph>(graph: &mut G) -> Result<()> {
     Ok(())
 }
 
+// endpoints from openapi specs are matched to the endpoints found in code by verb
+// and normalized path, with or without the spec's base path. The ones without a
+// counterpart on the other side are flagged: spec_only (documented, not
+// implemented) and code_only (not documented)
+pub fn link_openapi_endpoints<G: Graph>(graph: &mut G) -> Result<()> {
+    let (spec_endpoints, code_endpoints): (Vec<NodeData>, Vec<NodeData>) = graph
+        .find_nodes_by_type(NodeType::Endpoint)
+        .into_iter()
+        .partition(is_spec_endpoint);
+    if spec_endpoints.is_empty() {
+        return Ok(());
+    }
+    let code_endpoints: Vec<NodeData> = code_endpoints
+        .into_iter()
+        .filter(|e| {
+            !matches!(
+                e.meta.get("verb").map(|v| v.as_str()),
+                Some(GRPC_VERB) | Some(GRAPHQL_VERB)
+            )
+        })
+        .collect();
+
+    let key = |path: &str, e: &NodeData| {
+        (
+            normalize_backend_path(path),
+            e.meta.get("verb").map(|v| v.to_uppercase()),
+        )
+    };
+    // the code may mount the spec paths under the server's base path, or not
+    let spec_keys_of = |e: &NodeData| {
+        let mut keys = vec![key(&e.name, e)];
+        if let Some(base) = e.meta.get("base_path") {
+            keys.push(key(&format!("{}{}", base, e.name), e));
+        }
+        keys
+    };
+    let spec_keys: HashSet<_> = spec_endpoints.iter().flat_map(spec_keys_of).collect();
+    let code_keys: HashSet<_> = code_endpoints.iter().map(|e| key(&e.name, e)).collect();
+
+    let mut spec_only = 0;
+    for endpoint in &spec_endpoints {
+        if !spec_keys_of(endpoint).iter().any(|k| code_keys.contains(k)) {
+            graph.set_node_meta(NodeType::Endpoint, endpoint, "spec_only", "true");
+            spec_only += 1;
+        }
+    }
+    let mut code_only = 0;
+    for endpoint in &code_endpoints {
+        if !spec_keys.contains(&key(&endpoint.name, endpoint)) {
+            graph.set_node_meta(NodeType::Endpoint, endpoint, "code_only", "true");
+            code_only += 1;
+        }
+    }
+    info!(
+        "openapi: {} endpoints only in spec, {} only in code",
+        spec_only, code_only
+    );
+
+    Ok(())
+}
+
+fn is_spec_endpoint(endpoint: &NodeData) -> bool {
+    endpoint.meta.get("source").map(|s| s.as_str()) == Some(OPENAPI_SOURCE)
+}
+
 pub fn normalize_frontend_path(path: &str) -> Option<String> {
     // Skip paths that are entirely template literals
     if path.starts_with("${") && path.ends_with("}") && !path[2..].contains("${") {
@@ -344,16 +423,124 @@ mod tests {
         let mut endpoint2 = NodeData::name_file("/api/posts/:id", "src/routes/posts.ts");
         endpoint2.meta.insert("verb".to_string(), "GET".to_string());
 
+        // gRPC client stub call against the rpc declared in the proto package
+        let mut req3 = NodeData::name_file("/Users/GetUser", "client/main.go");
+        req3.meta.insert("verb".to_string(), "GRPC".to_string());
+
+        let mut endpoint3 = NodeData::name_file("/users.v1.Users/GetUser", "proto/users.proto");
+        endpoint3.meta.insert("verb".to_string(), "GRPC".to_string());
+
         // Add nodes to graph
         graph.nodes.push(Node::new(NodeType::Request, req1));
         graph.nodes.push(Node::new(NodeType::Request, req2));
+        graph.nodes.push(Node::new(NodeType::Request, req3));
         graph.nodes.push(Node::new(NodeType::Endpoint, endpoint1));
         graph.nodes.push(Node::new(NodeType::Endpoint, endpoint2));
+        graph.nodes.push(Node::new(NodeType::Endpoint, endpoint3));
 
         link_api_nodes(&mut graph)?;
 
-        // Should only create one edge for the matching pair
-        assert_eq!(graph.edges.len(), 1);
+        // Should only create edges for the matching http and grpc pairs
+        assert_eq!(graph.edges.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_link_openapi_endpoints() -> Result<()> {
+        use crate::lang::graphs::ArrayGraph;
+        let mut graph = ArrayGraph::new(String::new(), Language::Typescript);
+
+        let endpoint = |name: &str, file: &str, verb: &str, spec: bool| {
+            let mut nd = NodeData::name_file(name, file);
+            nd.meta.insert("verb".to_string(), verb.to_string());
+            if spec {
+                nd.meta
+                    .insert("source".to_string(), OPENAPI_SOURCE.to_string());
+            }
+            Node::new(NodeType::Endpoint, nd)
+        };
+        graph.nodes.push(endpoint("/items/{id}", "openapi.yaml", "GET", true));
+        graph.nodes.push(endpoint("/orders", "openapi.yaml", "GET", true));
+        graph.nodes.push(endpoint("/items/:id", "src/routes.ts", "GET", false));
+        graph.nodes.push(endpoint("/items/:id", "src/routes.ts", "DELETE", false));
+
+        link_openapi_endpoints(&mut graph)?;
+
+        let flags: Vec<(String, Option<&String>, Option<&String>)> = graph
+            .nodes
+            .iter()
+            .map(|n| {
+                (
+                    n.node_data.name.clone(),
+                    n.node_data.meta.get("spec_only"),
+                    n.node_data.meta.get("code_only"),
+                )
+            })
+            .collect();
+        let yes = "true".to_string();
+        assert_eq!(
+            flags,
+            vec![
+                ("/items/{id}".to_string(), None, None),
+                ("/orders".to_string(), Some(&yes), None),
+                ("/items/:id".to_string(), None, None),
+                ("/items/:id".to_string(), None, Some(&yes)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_link_openapi_endpoints_with_servers() -> Result<()> {
+        use crate::lang::graphs::ArrayGraph;
+        use crate::lang::openapi::parse_openapi;
+        let mut graph = ArrayGraph::new(String::new(), Language::Typescript);
+
+        let spec = r#"
+openapi: 3.0.3
+servers:
+  - url: https://api.example.com/v1
+paths:
+  /users/{id}:
+    get:
+      operationId: getUser
+  /health:
+    get:
+      operationId: health
+  /reports:
+    get:
+      operationId: listReports
+"#;
+        let spec = parse_openapi(spec, "openapi.yaml").unwrap();
+        let users = spec.endpoints.iter().find(|e| e.name == "/users/{id}").unwrap();
+        assert_eq!(users.meta.get("base_path"), Some(&"/v1".to_string()));
+        for nd in spec.endpoints {
+            graph.nodes.push(Node::new(NodeType::Endpoint, nd));
+        }
+
+        // mounted under the base path, or not
+        let endpoint = |name: &str| {
+            let mut nd = NodeData::name_file(name, "src/routes.ts");
+            nd.meta.insert("verb".to_string(), "GET".to_string());
+            Node::new(NodeType::Endpoint, nd)
+        };
+        graph.nodes.push(endpoint("/v1/users/:id"));
+        graph.nodes.push(endpoint("/health"));
+
+        link_openapi_endpoints(&mut graph)?;
+
+        let flagged = |flag: &str| -> Vec<String> {
+            graph
+                .nodes
+                .iter()
+                .filter(|n| n.node_data.meta.contains_key(flag))
+                .map(|n| n.node_data.name.clone())
+                .collect()
+        };
+        assert_eq!(flagged("spec_only"), vec!["/reports"]);
+        assert!(flagged("code_only").is_empty());
 
         Ok(())
     }
@@ -0,0 +1,820 @@
+use crate::lang::openapi::{key_line, to_json};
+use lsp::Language;
+use serde_json::Value;
+use std::collections::HashSet;
+
+// lockfiles, for the exact resolved version of every dependency, direct or
+// transitive, and the dependencies between them. go.sum has no dependency
+// graph, so go packages only get their versions
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dependency {
+    pub name: String,
+    // when the lockfile pins which of several versions is meant
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    pub start: usize,
+    pub dependencies: Vec<Dependency>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Lockfile {
+    pub packages: Vec<LockedPackage>,
+    // the direct dependencies, when the lockfile (or its manifest) says which they are
+    pub direct: Option<Vec<Dependency>>,
+}
+
+fn file_name(file: &str) -> &str {
+    file.rsplit('/').next().unwrap_or_default()
+}
+
+pub fn is_lockfile(file: &str) -> bool {
+    manifest_name(file).is_some()
+}
+
+// the manifest next to the lockfile
+pub fn manifest_name(file: &str) -> Option<&'static str> {
+    match file_name(file) {
+        "Cargo.lock" => Some("Cargo.toml"),
+        "package-lock.json" | "yarn.lock" | "pnpm-lock.yaml" => Some("package.json"),
+        "go.sum" => Some("go.mod"),
+        "Gemfile.lock" => Some("Gemfile"),
+        "poetry.lock" => Some("pyproject.toml"),
+        _ => None,
+    }
+}
+
+pub fn is_lockfile_for(lang: &Language, file: &str) -> bool {
+    match file_name(file) {
+        "Cargo.lock" => lang == &Language::Rust,
+        "package-lock.json" | "yarn.lock" | "pnpm-lock.yaml" => {
+            lang.pkg_files().contains(&"package.json")
+        }
+        "go.sum" => lang == &Language::Go,
+        "Gemfile.lock" => lang == &Language::Ruby,
+        "poetry.lock" => lang == &Language::Python,
+        _ => false,
+    }
+}
+
+pub fn parse_lockfile(file: &str, code: &str, manifest: Option<&str>) -> Option<Lockfile> {
+    let lock = match file_name(file) {
+        "Cargo.lock" => parse_cargo_lock(code),
+        "package-lock.json" => parse_package_lock(code)?,
+        "yarn.lock" => parse_yarn_lock(code),
+        "pnpm-lock.yaml" => parse_pnpm_lock(code)?,
+        "go.sum" => Lockfile {
+            packages: parse_go_sum(code),
+            direct: manifest.map(go_mod_direct),
+        },
+        "Gemfile.lock" => parse_gemfile_lock(code),
+        "poetry.lock" => Lockfile {
+            packages: parse_toml_packages(code).into_iter().map(|(p, _)| p).collect(),
+            direct: manifest.map(pyproject_direct),
+        },
+        _ => return None,
+    };
+    Some(lock)
+}
+
+impl Lockfile {
+    // the direct dependencies: from the lockfile, or else the manifest libraries,
+    // or else the packages that nothing else depends on
+    pub fn direct_dependencies(&self, manifest_libs: &[String]) -> Vec<Dependency> {
+        if let Some(direct) = &self.direct {
+            return direct.clone();
+        }
+        if !manifest_libs.is_empty() {
+            return manifest_libs.iter().map(|name| dependency(name, None)).collect();
+        }
+        let required: HashSet<String> = self
+            .packages
+            .iter()
+            .flat_map(|p| p.dependencies.iter().map(|d| normalize(&d.name)))
+            .collect();
+        self.packages
+            .iter()
+            .filter(|p| !required.contains(&normalize(&p.name)))
+            .map(|p| dependency(&p.name, Some(&p.version)))
+            .collect()
+    }
+
+    // the index of the package a dependency resolves to
+    pub fn resolve(&self, dep: &Dependency) -> Option<usize> {
+        let same_name = |p: &&LockedPackage| normalize(&p.name) == normalize(&dep.name);
+        let exact = dep.version.as_ref().and_then(|v| {
+            self.packages
+                .iter()
+                .position(|p| same_name(&p) && &p.version == v)
+        });
+        exact.or_else(|| self.packages.iter().position(|p| same_name(&p)))
+    }
+}
+
+pub fn is_direct(package: &LockedPackage, direct: &[Dependency]) -> bool {
+    direct.iter().any(|d| {
+        let same_version = match &d.version {
+            Some(version) => version == &package.version,
+            None => true,
+        };
+        normalize(&d.name) == normalize(&package.name) && same_version
+    })
+}
+
+// python treats - _ and case as the same, which is harmless elsewhere
+pub fn normalize(name: &str) -> String {
+    name.to_lowercase().replace('_', "-")
+}
+
+fn dependency(name: &str, version: Option<&str>) -> Dependency {
+    Dependency {
+        name: name.to_string(),
+        version: version.map(|v| v.to_string()),
+    }
+}
+
+fn unquote(s: &str) -> &str {
+    s.trim().trim_matches(|c| c == '"' || c == '\'')
+}
+
+fn parse_cargo_lock(code: &str) -> Lockfile {
+    let mut packages = Vec::new();
+    let mut direct = Vec::new();
+    // workspace members have no source, and are not libraries
+    for (package, local) in parse_toml_packages(code) {
+        if local {
+            direct.extend(package.dependencies);
+        } else {
+            packages.push(package);
+        }
+    }
+    Lockfile {
+        packages,
+        direct: Some(direct),
+    }
+}
+
+// the [[package]] tables of Cargo.lock and poetry.lock, and whether each has no source
+fn parse_toml_packages(code: &str) -> Vec<(LockedPackage, bool)> {
+    let mut res = Vec::new();
+    let mut current: Option<(LockedPackage, bool)> = None;
+    // in the [package.dependencies] table of poetry
+    let mut in_deps_table = false;
+    // in a multiline cargo dependencies array
+    let mut in_deps_array = false;
+    for (i, line) in code.lines().enumerate() {
+        let line = line.trim();
+        if line == "[[package]]" {
+            res.extend(current.take());
+            current = Some((
+                LockedPackage {
+                    name: String::new(),
+                    version: String::new(),
+                    start: i,
+                    dependencies: Vec::new(),
+                },
+                true,
+            ));
+            in_deps_table = false;
+            continue;
+        }
+        if line.starts_with('[') && !in_deps_array {
+            in_deps_table = line == "[package.dependencies]";
+            if !line.starts_with("[package.") {
+                res.extend(current.take());
+            }
+            continue;
+        }
+        let Some((package, local)) = current.as_mut() else {
+            continue;
+        };
+        if in_deps_array {
+            in_deps_array = !line.starts_with(']');
+            package.dependencies.extend(cargo_deps(line));
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (unquote(key), value.trim());
+        if in_deps_table {
+            package.dependencies.push(dependency(key, None));
+            continue;
+        }
+        match key {
+            "name" => package.name = unquote(value).to_string(),
+            "version" => package.version = unquote(value).to_string(),
+            "source" => *local = false,
+            "dependencies" => {
+                in_deps_array = value.starts_with('[') && !value.ends_with(']');
+                package.dependencies.extend(cargo_deps(value));
+            }
+            _ => (),
+        }
+    }
+    res.extend(current);
+    res.retain(|(p, _)| !p.name.is_empty());
+    res
+}
+
+// "name", "name version" or "name version (source)"
+fn cargo_deps(text: &str) -> Vec<Dependency> {
+    text.split(',')
+        .map(|d| unquote(d.trim().trim_matches(|c| c == '[' || c == ']')))
+        .filter(|d| !d.is_empty())
+        .map(|d| {
+            let mut parts = d.split_whitespace();
+            let name = parts.next().unwrap_or_default();
+            dependency(name, parts.next())
+        })
+        .collect()
+}
+
+fn parse_package_lock(code: &str) -> Option<Lockfile> {
+    let doc: Value = serde_json::from_str(code).ok()?;
+    let lines: Vec<&str> = code.lines().collect();
+    let mut lock = Lockfile::default();
+    // lockfile v2 and v3 list every installed path
+    if let Some(packages) = doc.get("packages").and_then(|p| p.as_object()) {
+        for (path, package) in packages {
+            if path.is_empty() {
+                lock.direct = Some(npm_deps(package, true));
+                continue;
+            }
+            let Some((_, name)) = path.rsplit_once("node_modules/") else {
+                continue;
+            };
+            let Some(version) = package.get("version").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            lock.packages.push(LockedPackage {
+                name: name.to_string(),
+                version: version.to_string(),
+                start: object_line(&lines, path).unwrap_or_default(),
+                dependencies: npm_deps(package, false),
+            });
+        }
+    } else if let Some(deps) = doc.get("dependencies").and_then(|d| d.as_object()) {
+        collect_package_lock_v1(deps, &lines, &mut lock.packages);
+    }
+    Some(lock)
+}
+
+fn collect_package_lock_v1(
+    deps: &serde_json::Map<String, Value>,
+    lines: &[&str],
+    packages: &mut Vec<LockedPackage>,
+) {
+    for (name, package) in deps {
+        let Some(version) = package.get("version").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let requires = package.get("requires").and_then(|r| r.as_object());
+        packages.push(LockedPackage {
+            name: name.clone(),
+            version: version.to_string(),
+            start: object_line(lines, name).unwrap_or_default(),
+            dependencies: requires
+                .map(|r| r.keys().map(|k| dependency(k, None)).collect())
+                .unwrap_or_default(),
+        });
+        if let Some(nested) = package.get("dependencies").and_then(|d| d.as_object()) {
+            collect_package_lock_v1(nested, lines, packages);
+        }
+    }
+}
+
+fn npm_deps(package: &Value, with_dev: bool) -> Vec<Dependency> {
+    let mut keys = vec!["dependencies", "optionalDependencies"];
+    if with_dev {
+        keys.push("devDependencies");
+    }
+    keys.iter()
+        .filter_map(|k| package.get(*k).and_then(|d| d.as_object()))
+        .flat_map(|d| d.keys().map(|name| dependency(name, None)))
+        .collect()
+}
+
+// the line that opens the json object at key
+fn object_line(lines: &[&str], key: &str) -> Option<usize> {
+    let prefix = format!("\"{}\":", key);
+    lines.iter().position(|l| {
+        let l = l.trim_start();
+        l.starts_with(&prefix) && l.trim_end().ends_with('{')
+    })
+}
+
+// yarn v1 and berry (v2+) lockfiles
+fn parse_yarn_lock(code: &str) -> Lockfile {
+    let mut packages: Vec<LockedPackage> = Vec::new();
+    let mut in_package = false;
+    let mut in_deps = false;
+    for (i, line) in code.lines().enumerate() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        if indent == 0 {
+            in_deps = false;
+            let spec = line.trim_end_matches(':').split(", ").next().unwrap_or_default();
+            let spec = unquote(spec);
+            // the berry metadata and the workspace projects themselves
+            let name = yarn_spec_name(spec).filter(|_| !spec.contains("@workspace:"));
+            in_package = name.is_some();
+            if let Some(name) = name {
+                packages.push(LockedPackage {
+                    name: name.to_string(),
+                    version: String::new(),
+                    start: i,
+                    dependencies: Vec::new(),
+                });
+            }
+            continue;
+        }
+        let Some(package) = packages.last_mut().filter(|_| in_package) else {
+            continue;
+        };
+        let line = line.trim();
+        if indent <= 2 {
+            in_deps = line == "dependencies:" || line == "optionalDependencies:";
+            if let Some(version) = line
+                .strip_prefix("version ")
+                .or_else(|| line.strip_prefix("version:"))
+            {
+                package.version = unquote(version).to_string();
+            }
+        } else if in_deps {
+            let name = line.split_whitespace().next().unwrap_or_default();
+            let name = unquote(name.trim_end_matches(':'));
+            package.dependencies.push(dependency(name, None));
+        }
+    }
+    Lockfile {
+        packages,
+        direct: None,
+    }
+}
+
+// "@babel/core@^7.0.0" and "lodash@npm:^4.17.21" -> the package name
+fn yarn_spec_name(spec: &str) -> Option<&str> {
+    let at = spec.get(1..)?.find('@')? + 1;
+    Some(&spec[..at])
+}
+
+fn parse_pnpm_lock(code: &str) -> Option<Lockfile> {
+    let doc = serde_norway::from_str(code).map(to_json).ok()?;
+    let lines: Vec<&str> = code.lines().collect();
+    let v5 = doc
+        .get("lockfileVersion")
+        .map(|v| v.to_string().trim_matches('"').starts_with('5'))
+        .unwrap_or(false);
+
+    let dep_keys = ["dependencies", "devDependencies", "optionalDependencies"];
+    let names = |v: &Value, keys: &[&str]| -> Vec<Dependency> {
+        keys.iter()
+            .filter_map(|k| v.get(*k).and_then(|d| d.as_object()))
+            .flat_map(|d| d.keys().map(|name| dependency(name, None)))
+            .collect()
+    };
+    // every workspace project, or the top level of older single project lockfiles
+    let direct = match doc.get("importers").and_then(|i| i.as_object()) {
+        Some(importers) => importers.values().flat_map(|i| names(i, &dep_keys)).collect(),
+        None => names(&doc, &dep_keys),
+    };
+
+    // pnpm 9 keeps the dependencies of each package in snapshots
+    let snapshots = doc.get("snapshots").and_then(|s| s.as_object());
+    let mut packages = Vec::new();
+    let Some(entries) = doc.get("packages").and_then(|p| p.as_object()) else {
+        return Some(Lockfile {
+            packages,
+            direct: Some(direct),
+        });
+    };
+    for (key, package) in entries {
+        let Some((name, version)) = pnpm_key(key, v5) else {
+            continue;
+        };
+        let mut dependencies = names(package, &dep_keys[..1]);
+        dependencies.extend(names(package, &dep_keys[2..]));
+        if let Some(snapshots) = snapshots {
+            for (snap_key, snapshot) in snapshots {
+                if snap_key.split('(').next() == Some(key.as_str()) {
+                    dependencies.extend(names(snapshot, &dep_keys[..1]));
+                    dependencies.extend(names(snapshot, &dep_keys[2..]));
+                }
+            }
+        }
+        dependencies.dedup();
+        packages.push(LockedPackage {
+            name,
+            version,
+            start: key_line(&lines, key, 0).unwrap_or_default(),
+            dependencies,
+        });
+    }
+    Some(Lockfile {
+        packages,
+        direct: Some(direct),
+    })
+}
+
+// "/@scope/name@1.0.0(peer@2)", "name@1.0.0" or (pnpm 5) "/name/1.0.0_peer@2"
+fn pnpm_key(key: &str, v5: bool) -> Option<(String, String)> {
+    let key = key.trim_start_matches('/').split('(').next()?;
+    if v5 {
+        let (name, version) = key.rsplit_once('/')?;
+        let version = version.split('_').next()?;
+        return Some((name.to_string(), version.to_string()));
+    }
+    let at = key.get(1..)?.find('@')? + 1;
+    Some((key[..at].to_string(), key[at + 1..].to_string()))
+}
+
+// the version of each module that was downloaded (not only its go.mod)
+fn parse_go_sum(code: &str) -> Vec<LockedPackage> {
+    let mut res: Vec<LockedPackage> = Vec::new();
+    for (i, line) in code.lines().enumerate() {
+        let mut parts = line.split_whitespace();
+        let (Some(module), Some(version)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        if version.ends_with("/go.mod") {
+            continue;
+        }
+        let package = LockedPackage {
+            name: module.to_string(),
+            version: version.to_string(),
+            start: i,
+            dependencies: Vec::new(),
+        };
+        // go.sum is sorted, so a later version of the same module is the newer one
+        match res.last_mut().filter(|p| p.name == module) {
+            Some(last) => *last = package,
+            None => res.push(package),
+        }
+    }
+    res
+}
+
+// the requires of a go.mod that are not marked "// indirect"
+fn go_mod_direct(code: &str) -> Vec<Dependency> {
+    let mut res = Vec::new();
+    let mut in_block = false;
+    for line in code.lines() {
+        let line = line.trim();
+        let spec = if in_block {
+            if line.starts_with(')') {
+                in_block = false;
+                continue;
+            }
+            line
+        } else if let Some(rest) = line.strip_prefix("require") {
+            let rest = rest.trim();
+            if rest.starts_with('(') {
+                in_block = true;
+                continue;
+            }
+            rest
+        } else {
+            continue;
+        };
+        if spec.is_empty() || spec.starts_with("//") || spec.contains("// indirect") {
+            continue;
+        }
+        let mut parts = spec.split_whitespace();
+        if let (Some(name), Some(version)) = (parts.next(), parts.next()) {
+            res.push(dependency(name, Some(version)));
+        }
+    }
+    res
+}
+
+// the dependencies of a pyproject.toml, from poetry tables or the [project] list
+fn pyproject_direct(code: &str) -> Vec<Dependency> {
+    let mut res = Vec::new();
+    let mut section = "";
+    let mut in_list = false;
+    for line in code.lines() {
+        let line = line.trim();
+        if line.starts_with('[') && !in_list {
+            section = line;
+            continue;
+        }
+        let poetry_deps = section.starts_with("[tool.poetry") && section.ends_with("dependencies]");
+        if poetry_deps {
+            if let Some((key, _)) = line.split_once('=') {
+                let key = unquote(key);
+                if key != "python" {
+                    res.push(dependency(key, None));
+                }
+            }
+            continue;
+        }
+        if section != "[project]" {
+            continue;
+        }
+        let items = if in_list {
+            in_list = !line.starts_with(']');
+            line
+        } else if let Some(value) = line.strip_prefix("dependencies") {
+            let value = value.trim_start().trim_start_matches('=').trim();
+            in_list = value.starts_with('[') && !value.ends_with(']');
+            value
+        } else {
+            continue;
+        };
+        for item in items.split(',') {
+            let item = unquote(item.trim().trim_matches(|c| c == '[' || c == ']'));
+            let name: String = item
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '-' || *c == '_' || *c == '.')
+                .collect();
+            if !name.is_empty() {
+                res.push(dependency(&name, None));
+            }
+        }
+    }
+    res
+}
+
+fn parse_gemfile_lock(code: &str) -> Lockfile {
+    let mut packages: Vec<LockedPackage> = Vec::new();
+    let mut direct = Vec::new();
+    let mut section = "";
+    for (i, line) in code.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        if indent == 0 {
+            section = line.trim();
+            continue;
+        }
+        let text = line.trim();
+        let name = text.split_whitespace().next().unwrap_or_default();
+        match (section, indent) {
+            // "  rails (~> 8.0.2)", "  my_gem!" for gems from a git source or path
+            ("DEPENDENCIES", 2) => direct.push(dependency(name.trim_end_matches('!'), None)),
+            ("GEM" | "GIT" | "PATH", 4) => {
+                // platform builds, like "nokogiri (1.18.8-x86_64-linux-gnu)", are one package
+                if packages.last().is_some_and(|p| p.name == name) {
+                    continue;
+                }
+                let version = text
+                    .split_once('(')
+                    .map(|(_, v)| v.trim_end_matches(')'))
+                    .unwrap_or_default();
+                let version = version.split('-').next().unwrap_or_default();
+                packages.push(LockedPackage {
+                    name: name.to_string(),
+                    version: version.to_string(),
+                    start: i,
+                    dependencies: Vec::new(),
+                });
+            }
+            ("GEM" | "GIT" | "PATH", 6) => {
+                if let Some(package) = packages.last_mut() {
+                    package.dependencies.push(dependency(name, None));
+                }
+            }
+            _ => (),
+        }
+    }
+    Lockfile {
+        packages,
+        direct: Some(direct),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cargo_and_poetry_lock() {
+        let cargo = r#"version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "hyper 0.14.27",
+ "serde",
+]
+
+[[package]]
+name = "hyper"
+version = "0.14.27"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = ["itoa"]
+
+[[package]]
+name = "hyper"
+version = "1.4.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "itoa"
+version = "1.0.11"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "serde"
+version = "1.0.219"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+        let lock = parse_lockfile("Cargo.lock", cargo, None).unwrap();
+        let names: Vec<String> = lock
+            .packages
+            .iter()
+            .map(|p| format!("{} {} {}", p.name, p.version, p.start))
+            .collect();
+        assert_eq!(
+            names,
+            vec!["hyper 0.14.27 10", "hyper 1.4.1 16", "itoa 1.0.11 21", "serde 1.0.219 26"]
+        );
+        let direct = lock.direct_dependencies(&[]);
+        assert!(is_direct(&lock.packages[0], &direct));
+        assert!(!is_direct(&lock.packages[1], &direct));
+        assert!(!is_direct(&lock.packages[2], &direct));
+        assert!(is_direct(&lock.packages[3], &direct));
+        assert_eq!(lock.resolve(&lock.packages[0].dependencies[0]), Some(2));
+
+        let poetry = r#"[[package]]
+name = "requests"
+version = "2.31.0"
+optional = false
+
+[package.dependencies]
+certifi = ">=2017.4.17"
+urllib3 = {version = ">=1.21.1,<3"}
+
+[package.extras]
+socks = ["PySocks (>=1.5.6,!=1.5.7)"]
+
+[[package]]
+name = "urllib3"
+version = "2.2.1"
+
+[[package]]
+name = "certifi"
+version = "2024.2.2"
+
+[metadata]
+lock-version = "2.0"
+"#;
+        let pyproject = "[tool.poetry.dependencies]\npython = \"^3.11\"\nRequests = \"^2.31\"\n";
+        let lock = parse_lockfile("poetry.lock", poetry, Some(pyproject)).unwrap();
+        assert_eq!(lock.packages.len(), 3);
+        assert_eq!(
+            lock.packages[0].dependencies,
+            vec![dependency("certifi", None), dependency("urllib3", None)]
+        );
+        let direct = lock.direct_dependencies(&[]);
+        assert!(is_direct(&lock.packages[0], &direct));
+        assert!(!is_direct(&lock.packages[1], &direct));
+    }
+
+    #[test]
+    fn test_npm_locks() {
+        let package_lock = r#"{
+  "name": "web",
+  "lockfileVersion": 3,
+  "packages": {
+    "": {
+      "name": "web",
+      "dependencies": {
+        "express": "^4.18.2"
+      }
+    },
+    "node_modules/express": {
+      "version": "4.18.2",
+      "dependencies": {
+        "debug": "2.6.9"
+      }
+    },
+    "node_modules/express/node_modules/debug": {
+      "version": "2.6.9"
+    }
+  }
+}"#;
+        let lock = parse_lockfile("web/package-lock.json", package_lock, None).unwrap();
+        assert_eq!(lock.packages.len(), 2);
+        assert_eq!(lock.packages[1].name, "debug");
+        assert_eq!(lock.packages[1].start, 16);
+        assert_eq!(lock.direct, Some(vec![dependency("express", None)]));
+
+        let yarn = r#"# yarn lockfile v1
+
+
+"@babel/code-frame@^7.0.0", "@babel/code-frame@^7.10.4":
+  version "7.12.13"
+  resolved "https://registry.yarnpkg.com/@babel/code-frame/-/code-frame-7.12.13.tgz"
+  dependencies:
+    "@babel/highlight" "^7.12.13"
+
+"@babel/highlight@^7.12.13":
+  version "7.13.10"
+"#;
+        let lock = parse_lockfile("yarn.lock", yarn, None).unwrap();
+        assert_eq!(lock.packages.len(), 2);
+        assert_eq!(lock.packages[0].name, "@babel/code-frame");
+        assert_eq!(lock.packages[0].version, "7.12.13");
+        assert_eq!(lock.packages[0].dependencies, vec![dependency("@babel/highlight", None)]);
+        let direct = lock.direct_dependencies(&[]);
+        assert_eq!(direct, vec![dependency("@babel/code-frame", Some("7.12.13"))]);
+
+        let pnpm = r#"lockfileVersion: '9.0'
+
+importers:
+
+  .:
+    dependencies:
+      react-dom:
+        specifier: ^18.2.0
+        version: 18.2.0(react@18.2.0)
+
+packages:
+
+  react-dom@18.2.0:
+    resolution: {integrity: sha512-x}
+
+  react@18.2.0:
+    resolution: {integrity: sha512-y}
+
+snapshots:
+
+  react-dom@18.2.0(react@18.2.0):
+    dependencies:
+      react: 18.2.0
+
+  react@18.2.0: {}
+"#;
+        let lock = parse_lockfile("pnpm-lock.yaml", pnpm, None).unwrap();
+        let dom = lock.packages.iter().find(|p| p.name == "react-dom").unwrap();
+        assert_eq!(dom.version, "18.2.0");
+        assert_eq!(dom.start, 12);
+        assert_eq!(dom.dependencies, vec![dependency("react", None)]);
+        assert_eq!(lock.direct, Some(vec![dependency("react-dom", None)]));
+        assert_eq!(
+            pnpm_key("/@types/node/18.0.0_typescript@5.0.0", true),
+            Some(("@types/node".to_string(), "18.0.0".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_go_and_ruby_locks() {
+        let go_sum = "github.com/go-chi/chi v1.5.4/go.mod h1:a=
+github.com/go-chi/chi v1.5.5 h1:b=
+github.com/go-chi/chi v1.5.5/go.mod h1:c=
+github.com/kr/pretty v0.1.0/go.mod h1:d=
+golang.org/x/text v0.13.0 h1:e=
+golang.org/x/text v0.14.0 h1:f=
+";
+        let go_mod = "module app\n\nrequire github.com/go-chi/chi v1.5.5\n\nrequire (\n\tgolang.org/x/text v0.14.0 // indirect\n)\n";
+        let lock = parse_lockfile("go.sum", go_sum, Some(go_mod)).unwrap();
+        let names: Vec<String> = lock
+            .packages
+            .iter()
+            .map(|p| format!("{} {}", p.name, p.version))
+            .collect();
+        assert_eq!(names, vec!["github.com/go-chi/chi v1.5.5", "golang.org/x/text v0.14.0"]);
+        let direct = lock.direct_dependencies(&[]);
+        assert!(is_direct(&lock.packages[0], &direct));
+        assert!(!is_direct(&lock.packages[1], &direct));
+
+        let gemfile_lock = "GEM
+  remote: https://rubygems.org/
+  specs:
+    actioncable (8.0.2)
+      actionpack (= 8.0.2)
+      nio4r (~> 2.0)
+    nio4r (2.7.4)
+    nokogiri (1.18.8-arm64-darwin)
+    nokogiri (1.18.8-x86_64-linux-gnu)
+
+PLATFORMS
+  x86_64-linux
+
+DEPENDENCIES
+  actioncable (~> 8.0)
+  nokogiri
+
+BUNDLED WITH
+   2.6.2
+";
+        let lock = parse_lockfile("Gemfile.lock", gemfile_lock, None).unwrap();
+        assert_eq!(lock.packages.len(), 3);
+        assert_eq!(lock.packages[0].dependencies.len(), 2);
+        assert_eq!(lock.packages[2].version, "1.18.8");
+        let direct = lock.direct_dependencies(&[]);
+        assert!(is_direct(&lock.packages[0], &direct));
+        assert!(!is_direct(&lock.packages[1], &direct));
+    }
+}
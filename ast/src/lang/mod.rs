@@ -1,9 +1,17 @@
 pub mod asg;
 pub mod call_finder;
+pub mod docker;
+pub mod db_schema;
 pub mod embedding;
+pub mod graphql;
 pub mod graphs;
+pub mod jobs;
 pub mod linker;
+pub mod lockfile;
+pub mod notebook;
+pub mod openapi;
 pub mod parse;
+pub mod proto;
 pub mod queries;
 
 use asg::*;
@@ -167,6 +175,36 @@ impl Lang {
             lang: Box::new(cpp::Cpp::new()),
         }
     }
+    pub fn new_c() -> Self {
+        Self {
+            kind: Language::C,
+            lang: Box::new(c::C::new()),
+        }
+    }
+    pub fn new_csharp() -> Self {
+        Self {
+            kind: Language::CSharp,
+            lang: Box::new(csharp::CSharp::new()),
+        }
+    }
+    pub fn new_php() -> Self {
+        Self {
+            kind: Language::Php,
+            lang: Box::new(php::Php::new()),
+        }
+    }
+    pub fn new_vue() -> Self {
+        Self {
+            kind: Language::Vue,
+            lang: Box::new(vue::Vue::new()),
+        }
+    }
+    pub fn new_bash() -> Self {
+        Self {
+            kind: Language::Bash,
+            lang: Box::new(bash::Bash::new()),
+        }
+    }
     pub fn lang(&self) -> &dyn Stack {
         self.lang.as_ref()
     }
@@ -467,6 +505,17 @@ impl Lang {
                 if o == FUNCTION_NAME {
                     caller_name = body;
                 } else if o == FUNCTION_DEFINITION {
+                    if self.lang.is_test_definition(&node, code) {
+                        // calls from tests are collected with the test query below
+                        return Ok(());
+                    }
+                    if caller_name.is_empty() {
+                        if let Some(name) = self.lang.component_name_from_file(file) {
+                            caller_name = name;
+                        } else if self.lang.skip_unnamed_functions() {
+                            return Ok(());
+                        }
+                    }
                     let caller_start = node.start_position().row as usize;
                     // NOTE this should always be the last one
                     let q2 = self.q(&self.lang.function_call_query(), &NodeType::Function);
@@ -522,7 +571,8 @@ impl Lang {
                 let mut caller_name = String::new();
                 Self::loop_captures(&q_tests, &tm, code, |body, node, o| {
                     if o == FUNCTION_NAME {
-                        caller_name = body;
+                        // describe("items api") is named like format_test names it
+                        caller_name = parse::trim_quotes(&body).to_string();
                     } else if o == FUNCTION_DEFINITION {
                         let caller_start = node.start_position().row as usize;
                         let q2 = self.q(&self.lang.function_call_query(), &NodeType::Function);
@@ -638,7 +688,7 @@ impl Lang {
             Language::Typescript => Lang::new_typescript(),
             Language::React => Lang::new_react(),
             Language::Ruby => Lang::new_ruby(),
-            Language::Bash => unimplemented!(),
+            Language::Bash => Lang::new_bash(),
             Language::Toml => unimplemented!(),
             Language::Kotlin => Lang::new_kotlin(),
             Language::Swift => Lang::new_swift(),
@@ -646,6 +696,10 @@ impl Lang {
             Language::Svelte => Lang::new_svelte(),
             Language::Angular => Lang::new_angular(),
             Language::Cpp => Lang::new_cpp(),
+            Language::C => Lang::new_c(),
+            Language::CSharp => Lang::new_csharp(),
+            Language::Php => Lang::new_php(),
+            Language::Vue => Lang::new_vue(),
         }
     }
 }
@@ -678,3 +732,50 @@ pub fn query_to_ident(query: Query, node: TreeNode, code: &str) -> Result<Option
     let name = name_node.node.utf8_text(code.as_bytes())?;
     Ok(Some(name.to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lsp::language::PROGRAMMING_LANGUAGES;
+
+    // queries are only compiled once a repo of that language is parsed, so an invalid
+    // node name would otherwise surface as a panic in the middle of a build
+    #[test]
+    fn test_queries_compile() {
+        for language in PROGRAMMING_LANGUAGES {
+            let lang = Lang::from_language(language);
+            let stack = lang.lang();
+            let mut queries = vec![
+                (Some(stack.identifier_query()), NodeType::Var),
+                (Some(stack.class_definition_query()), NodeType::Class),
+                (Some(stack.function_definition_query()), NodeType::Function),
+                (Some(stack.function_call_query()), NodeType::Function),
+                (stack.lib_query(), NodeType::Library),
+                (stack.imports_query(), NodeType::Import),
+                (stack.variables_query(), NodeType::Var),
+                (stack.trait_query(), NodeType::Trait),
+                (stack.implements_query(), NodeType::Class),
+                (stack.instance_definition_query(), NodeType::Instance),
+                (stack.comment_query(), NodeType::Function),
+                (stack.test_query(), NodeType::UnitTest),
+                (stack.e2e_test_query(), NodeType::E2eTest),
+                (stack.request_finder(), NodeType::Request),
+                (stack.data_model_query(), NodeType::DataModel),
+                (stack.data_model_within_query(), NodeType::DataModel),
+                (stack.page_query(), NodeType::Page),
+                (stack.component_template_query(), NodeType::Class),
+            ];
+            for finder in stack.endpoint_finders() {
+                queries.push((Some(finder), NodeType::Endpoint));
+            }
+            for extra in stack.extra_calls_queries() {
+                queries.push((Some(extra), NodeType::Function));
+            }
+            for (query, node_type) in queries {
+                if let Some(query) = query {
+                    lang.q(&query, &node_type);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::Value;
+
+// jupyter notebooks. The code cells are joined into one python source (in the
+// "# %%" percent format), which is what gets parsed and stored as the file body.
+// Each cell starts with a "# %% [cell N]" marker line, so lines in that source
+// can be mapped back to the cell index and the offset within the cell
+
+const CELL_MARKER: &str = "# %% [cell ";
+
+// cell magics that still run their body as python
+const PYTHON_CELL_MAGICS: [&str; 4] = ["%%time", "%%timeit", "%%capture", "%%prun"];
+
+lazy_static! {
+    // %magic, !shell, ?help, and help after a bare (dotted) name: pd.read_csv??
+    static ref MAGIC_RE: Regex = Regex::new(r"^\s*(%|!|\?)|^\s*[A-Za-z_][\w.]*\?\??\s*$").unwrap();
+}
+
+pub fn is_notebook(file: &str) -> bool {
+    file.ends_with(".ipynb")
+}
+
+pub fn notebook_source(code: &str) -> Option<String> {
+    let doc: Value = serde_json::from_str(code).ok()?;
+    let cells = doc.get("cells")?.as_array()?;
+    let mut source = String::new();
+    for (index, cell) in cells.iter().enumerate() {
+        if cell.get("cell_type").and_then(|t| t.as_str()) != Some("code") {
+            continue;
+        }
+        // a list of lines (with their newlines), or a single string
+        let text = match cell.get("source") {
+            Some(Value::Array(lines)) => lines.iter().filter_map(|l| l.as_str()).collect(),
+            Some(Value::String(s)) => s.clone(),
+            _ => String::new(),
+        };
+        source.push_str(&format!("{}{}]\n", CELL_MARKER, index));
+        source.push_str(&comment_magics(&text));
+        if !source.ends_with('\n') {
+            source.push('\n');
+        }
+    }
+    Some(source)
+}
+
+// ipython magics and shell escapes are not python, so they are commented out
+// (keeping the line count) instead of breaking the parse of the whole cell
+fn comment_magics(text: &str) -> String {
+    let first = text.lines().next().unwrap_or_default().trim();
+    let whole_cell = first.starts_with("%%")
+        && !PYTHON_CELL_MAGICS.contains(&first.split_whitespace().next().unwrap_or_default());
+    text.split_inclusive('\n')
+        .map(|line| {
+            if whole_cell || MAGIC_RE.is_match(line) {
+                format!("#{}", line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect()
+}
+
+// (cell index, line within the cell) of a line in the notebook source
+pub fn cell_position(source: &str, line: usize) -> Option<(usize, usize)> {
+    source
+        .lines()
+        .take(line + 1)
+        .enumerate()
+        .filter_map(|(i, l)| {
+            let index = l.strip_prefix(CELL_MARKER)?.strip_suffix(']')?;
+            Some((index.parse().ok()?, i))
+        })
+        .last()
+        .filter(|(_, marker)| *marker < line)
+        .map(|(index, marker)| (index, line - marker - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTEBOOK: &str = r##"{
+ "cells": [
+  {
+   "cell_type": "markdown",
+   "metadata": {},
+   "source": ["# Churn model\n", "Loads the data and scores it"]
+  },
+  {
+   "cell_type": "code",
+   "execution_count": 1,
+   "metadata": {},
+   "outputs": [],
+   "source": [
+    "%matplotlib inline\n",
+    "!pip install pandas\n",
+    "import pandas as pd\n",
+    "from features import build_features"
+   ]
+  },
+  {
+   "cell_type": "code",
+   "execution_count": 2,
+   "metadata": {},
+   "outputs": [],
+   "source": "def score(df):\n    return build_features(df).sum()\n\npd.read_csv?"
+  },
+  {
+   "cell_type": "code",
+   "execution_count": 3,
+   "metadata": {},
+   "outputs": [],
+   "source": ["%%bash\n", "ls data\n"]
+  }
+ ],
+ "metadata": {},
+ "nbformat": 4,
+ "nbformat_minor": 5
+}"##;
+
+    #[test]
+    fn test_notebook_source() {
+        let source = notebook_source(NOTEBOOK).unwrap();
+        assert_eq!(
+            source,
+            "# %% [cell 1]
+#%matplotlib inline
+#!pip install pandas
+import pandas as pd
+from features import build_features
+# %% [cell 2]
+def score(df):
+    return build_features(df).sum()
+
+#pd.read_csv?
+# %% [cell 3]
+#%%bash
+#ls data
+"
+        );
+        assert!(notebook_source("not json").is_none());
+    }
+
+    #[test]
+    fn test_comment_magics() {
+        let cell = "df.describe??\n?len\nq = \"\"\"ready?\n\"\"\"\nx = 1  # why?\nprint('ok?')\n";
+        assert_eq!(
+            comment_magics(cell),
+            "#df.describe??\n#?len\nq = \"\"\"ready?\n\"\"\"\nx = 1  # why?\nprint('ok?')\n"
+        );
+    }
+
+    #[test]
+    fn test_cell_position() {
+        let source = notebook_source(NOTEBOOK).unwrap();
+        assert_eq!(cell_position(&source, 0), None);
+        assert_eq!(cell_position(&source, 3), Some((1, 2)));
+        assert_eq!(cell_position(&source, 6), Some((2, 0)));
+        assert_eq!(cell_position(&source, 7), Some((2, 1)));
+        assert_eq!(cell_position(&source, 12), Some((3, 1)));
+    }
+}
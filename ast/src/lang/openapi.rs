@@ -0,0 +1,403 @@
+use crate::lang::NodeData;
+use serde_json::Value;
+
+// openapi 3 / swagger 2 documents, in yaml or json. Every operation becomes an
+// Endpoint with meta source=openapi (and base_path, from the servers or basePath),
+// and every schema a DataModel. They are reconciled with the endpoints found in
+// code by link_openapi_endpoints
+
+pub const OPENAPI_SOURCE: &str = "openapi";
+
+const METHODS: [&str; 7] = ["get", "put", "post", "delete", "patch", "options", "head"];
+const SUCCESS_RESPONSES: [&str; 6] = ["200", "201", "202", "204", "2XX", "default"];
+
+// the usual names of spec files, so they are never skipped when collecting files
+pub fn is_openapi_path(file: &str) -> bool {
+    let lower = file.to_lowercase();
+    let name = lower.rsplit('/').next().unwrap_or_default();
+    (name.contains("openapi") || name.contains("swagger")) && is_spec_ext(&lower)
+}
+
+fn is_spec_ext(file: &str) -> bool {
+    file.ends_with(".yaml") || file.ends_with(".yml") || file.ends_with(".json")
+}
+
+#[derive(Debug, Default)]
+pub struct OpenApiSpec {
+    pub endpoints: Vec<NodeData>,
+    pub schemas: Vec<NodeData>,
+}
+
+pub fn parse_openapi(code: &str, file: &str) -> Option<OpenApiSpec> {
+    if !is_spec_ext(&file.to_lowercase()) {
+        return None;
+    }
+    if !code.contains("openapi") && !code.contains("swagger") {
+        return None;
+    }
+    // yaml is a superset of json, so one parser does both
+    let doc = to_json(serde_norway::from_str(code).ok()?);
+    if doc.get("openapi").is_none() && doc.get("swagger").is_none() {
+        return None;
+    }
+    let paths = doc.get("paths")?.as_object()?;
+    let lines: Vec<&str> = code.lines().collect();
+    let mut res = OpenApiSpec::default();
+
+    let base = base_path(&doc);
+    let paths_line = key_line(&lines, "paths", 0).unwrap_or(0);
+    for (path, item) in paths {
+        let path_line = key_line(&lines, path, paths_line);
+        let shared_params = item.get("parameters");
+        for method in METHODS {
+            let Some(op) = item.get(method) else {
+                continue;
+            };
+            let mut nd = NodeData::name_file(path, file);
+            nd.add_verb(method);
+            nd.meta
+                .insert("source".to_string(), OPENAPI_SOURCE.to_string());
+            if !base.is_empty() {
+                nd.meta.insert("base_path".to_string(), base.clone());
+            }
+            if let Some(start) = path_line.and_then(|l| key_line(&lines, method, l)) {
+                let end = block_end(&lines, start);
+                nd.start = start;
+                nd.end = end;
+                nd.body = lines[start..=end].join("\n");
+            }
+            if let Some(id) = op.get("operationId").and_then(|i| i.as_str()) {
+                nd.meta.insert("operation_id".to_string(), id.to_string());
+            }
+            let params: Vec<String> = [shared_params, op.get("parameters")]
+                .into_iter()
+                .flatten()
+                .filter_map(|p| p.as_array())
+                .flatten()
+                .filter_map(|p| parameter(&doc, p))
+                .collect();
+            if !params.is_empty() {
+                nd.meta.insert("parameters".to_string(), params.join(","));
+            }
+            if let Some(request) = request_schema(&doc, op) {
+                nd.meta.insert("request".to_string(), request);
+            }
+            if let Some(response) = response_schema(op) {
+                nd.meta.insert("response".to_string(), response);
+            }
+            res.endpoints.push(nd);
+        }
+    }
+
+    // openapi 3 keeps them under components.schemas, swagger 2 under definitions
+    let (schemas, parent) = match doc.pointer("/components/schemas") {
+        Some(s) => (s, "schemas"),
+        None => (doc.get("definitions").unwrap_or(&Value::Null), "definitions"),
+    };
+    let schemas_line = key_line(&lines, parent, 0);
+    for name in schemas.as_object().into_iter().flat_map(|s| s.keys()) {
+        let mut nd = NodeData::name_file(name, file);
+        if let Some(start) = schemas_line.and_then(|l| key_line(&lines, name, l)) {
+            let end = block_end(&lines, start);
+            nd.start = start;
+            nd.end = end;
+            nd.body = lines[start..=end].join("\n");
+        }
+        res.schemas.push(nd);
+    }
+    Some(res)
+}
+
+// swagger 2 basePath, or the path of the first openapi 3 server url
+fn base_path(doc: &Value) -> String {
+    let base = match doc.get("basePath").and_then(|b| b.as_str()) {
+        Some(b) => b.to_string(),
+        None => {
+            let url = doc
+                .pointer("/servers/0/url")
+                .and_then(|u| u.as_str())
+                .unwrap_or_default();
+            match url.split_once("://") {
+                Some((_, rest)) => rest.find('/').map(|i| rest[i..].to_string()).unwrap_or_default(),
+                None => url.to_string(),
+            }
+        }
+    };
+    base.trim_end_matches('/').to_string()
+}
+
+// name:in, like id:path or limit:query
+fn parameter(doc: &Value, param: &Value) -> Option<String> {
+    let param = resolve(doc, param)?;
+    let name = param.get("name")?.as_str()?;
+    let location = param.get("in").and_then(|i| i.as_str()).unwrap_or("query");
+    Some(format!("{}:{}", name, location))
+}
+
+fn request_schema(doc: &Value, op: &Value) -> Option<String> {
+    // openapi 3 requestBody, or a swagger 2 body parameter
+    if let Some(body) = op.get("requestBody") {
+        let body = resolve(doc, body)?;
+        return content_schema(body);
+    }
+    op.get("parameters")?
+        .as_array()?
+        .iter()
+        .filter_map(|p| resolve(doc, p))
+        .find(|p| p.get("in").and_then(|i| i.as_str()) == Some("body"))
+        .and_then(|p| schema_name(p.get("schema")?))
+}
+
+fn response_schema(op: &Value) -> Option<String> {
+    let responses = op.get("responses")?;
+    SUCCESS_RESPONSES.iter().find_map(|code| {
+        let response = responses.get(*code)?;
+        content_schema(response).or_else(|| schema_name(response.get("schema")?))
+    })
+}
+
+fn content_schema(body: &Value) -> Option<String> {
+    body.get("content")?
+        .as_object()?
+        .values()
+        .find_map(|media| schema_name(media.get("schema")?))
+}
+
+// the schema a $ref points to, or the items of an array of them
+fn schema_name(schema: &Value) -> Option<String> {
+    if let Some(r) = schema.get("$ref").and_then(|r| r.as_str()) {
+        return r.rsplit('/').next().map(|n| n.to_string());
+    }
+    if let Some(items) = schema.get("items") {
+        return schema_name(items).map(|n| format!("{}[]", n));
+    }
+    ["allOf", "oneOf", "anyOf"]
+        .iter()
+        .find_map(|k| schema.get(*k)?.as_array()?.iter().find_map(schema_name))
+}
+
+// yaml allows non-string keys, like unquoted response codes
+pub(crate) fn to_json(value: serde_norway::Value) -> Value {
+    match value {
+        serde_norway::Value::Null => Value::Null,
+        serde_norway::Value::Bool(b) => Value::Bool(b),
+        serde_norway::Value::Number(n) => serde_json::to_value(n).unwrap_or_default(),
+        serde_norway::Value::String(s) => Value::String(s),
+        serde_norway::Value::Sequence(seq) => Value::Array(seq.into_iter().map(to_json).collect()),
+        serde_norway::Value::Mapping(map) => Value::Object(
+            map.into_iter()
+                .map(|(k, v)| {
+                    let key = match to_json(k) {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    (key, to_json(v))
+                })
+                .collect(),
+        ),
+        serde_norway::Value::Tagged(tagged) => to_json(tagged.value),
+    }
+}
+
+fn resolve<'a>(doc: &'a Value, value: &'a Value) -> Option<&'a Value> {
+    match value.get("$ref").and_then(|r| r.as_str()) {
+        Some(r) => doc.pointer(r.trim_start_matches('#')),
+        None => Some(value),
+    }
+}
+
+// the line of a yaml or json key, at or after from
+pub(crate) fn key_line(lines: &[&str], key: &str, from: usize) -> Option<usize> {
+    let candidates = [
+        format!("{}:", key),
+        format!("\"{}\":", key),
+        format!("\"{}\" :", key),
+        format!("'{}':", key),
+    ];
+    (from..lines.len()).find(|&i| {
+        let line = lines[i].trim_start();
+        candidates.iter().any(|c| line.starts_with(c.as_str()))
+    })
+}
+
+// the last line of the block that starts at start, by indentation
+pub(crate) fn block_end(lines: &[&str], start: usize) -> usize {
+    let indent = |l: &str| l.len() - l.trim_start().len();
+    let base = indent(lines[start]);
+    let mut end = start;
+    for (i, line) in lines.iter().enumerate().skip(start + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if indent(line) <= base {
+            // the closing brace of a json object
+            if line.trim_start().starts_with('}') && indent(line) == base {
+                end = i;
+            }
+            break;
+        }
+        end = i;
+    }
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPENAPI_YAML: &str = r##"openapi: 3.0.3
+info:
+  title: Items
+  version: 1.0.0
+servers:
+  - url: https://api.example.com/v1
+paths:
+  /items:
+    get:
+      operationId: listItems
+      parameters:
+        - name: limit
+          in: query
+          schema:
+            type: integer
+      responses:
+        "200":
+          description: the items
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: "#/components/schemas/Item"
+    post:
+      operationId: createItem
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/NewItem"
+      responses:
+        "201":
+          description: created
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/Item"
+  /items/{id}:
+    parameters:
+      - $ref: "#/components/parameters/ItemId"
+    delete:
+      operationId: deleteItem
+      responses:
+        "204":
+          description: deleted
+components:
+  parameters:
+    ItemId:
+      name: id
+      in: path
+      required: true
+      schema:
+        type: string
+  schemas:
+    Item:
+      type: object
+      properties:
+        id:
+          type: string
+    NewItem:
+      type: object
+      properties:
+        name:
+          type: string
+"##;
+
+    const SWAGGER_JSON: &str = r##"{
+  "swagger": "2.0",
+  "basePath": "/api",
+  "paths": {
+    "/users/{id}": {
+      "put": {
+        "operationId": "updateUser",
+        "parameters": [
+          { "name": "id", "in": "path", "type": "string" },
+          { "name": "body", "in": "body", "schema": { "$ref": "#/definitions/User" } }
+        ],
+        "responses": {
+          "200": { "description": "ok", "schema": { "$ref": "#/definitions/User" } }
+        }
+      }
+    }
+  },
+  "definitions": {
+    "User": {
+      "type": "object"
+    }
+  }
+}
+"##;
+
+    #[test]
+    fn test_parse_openapi_yaml() {
+        let spec = parse_openapi(OPENAPI_YAML, "openapi.yaml").unwrap();
+        let endpoints: Vec<(&str, &str)> = spec
+            .endpoints
+            .iter()
+            .map(|e| (e.meta.get("verb").unwrap().as_str(), e.name.as_str()))
+            .collect();
+        assert_eq!(
+            endpoints,
+            vec![
+                ("GET", "/items"),
+                ("POST", "/items"),
+                ("DELETE", "/items/{id}")
+            ]
+        );
+        let list = &spec.endpoints[0];
+        assert_eq!(list.meta.get("base_path"), Some(&"/v1".to_string()));
+        assert_eq!(list.start, 8);
+        assert_eq!(list.end, 23);
+        assert_eq!(list.meta.get("operation_id"), Some(&"listItems".to_string()));
+        assert_eq!(list.meta.get("parameters"), Some(&"limit:query".to_string()));
+        assert_eq!(list.meta.get("response"), Some(&"Item[]".to_string()));
+        assert_eq!(list.meta.get("source"), Some(&"openapi".to_string()));
+        let create = &spec.endpoints[1];
+        assert_eq!(create.meta.get("request"), Some(&"NewItem".to_string()));
+        assert_eq!(create.meta.get("response"), Some(&"Item".to_string()));
+        let delete = &spec.endpoints[2];
+        assert_eq!(delete.meta.get("parameters"), Some(&"id:path".to_string()));
+        assert_eq!(delete.meta.get("response"), None);
+
+        let schemas: Vec<(&str, usize, usize)> = spec
+            .schemas
+            .iter()
+            .map(|s| (s.name.as_str(), s.start, s.end))
+            .collect();
+        assert_eq!(schemas, vec![("Item", 55, 59), ("NewItem", 60, 64)]);
+    }
+
+    #[test]
+    fn test_parse_swagger_json() {
+        let spec = parse_openapi(SWAGGER_JSON, "api/swagger.json").unwrap();
+        assert_eq!(spec.endpoints.len(), 1);
+        let update = &spec.endpoints[0];
+        assert_eq!(update.name, "/users/{id}");
+        assert_eq!(update.meta.get("base_path"), Some(&"/api".to_string()));
+        assert_eq!(update.meta.get("verb"), Some(&"PUT".to_string()));
+        assert_eq!(update.meta.get("parameters"), Some(&"id:path,body:body".to_string()));
+        assert_eq!(update.meta.get("request"), Some(&"User".to_string()));
+        assert_eq!(update.meta.get("response"), Some(&"User".to_string()));
+        assert_eq!((update.start, update.end), (5, 14));
+        assert_eq!(spec.schemas.len(), 1);
+        assert_eq!((spec.schemas[0].start, spec.schemas[0].end), (18, 20));
+    }
+
+    #[test]
+    fn test_not_openapi() {
+        assert!(parse_openapi("name: ci\non: push\n", ".github/workflows/ci.yml").is_none());
+        assert!(parse_openapi("{\"swagger\": \"2.0\"}", "src/swagger.ts").is_none());
+        assert!(is_openapi_path("docs/openapi.yaml"));
+        assert!(is_openapi_path("api/Swagger.json"));
+        assert!(!is_openapi_path("src/openapi.ts"));
+    }
+}
@@ -145,7 +145,7 @@ impl Lang {
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(q, tree.root_node(), code.as_bytes());
         let mut res = Vec::new();
-        if self.lang.is_test_file(file){
+        if self.lang.is_test_file(file) || self.lang.has_inline_tests(code) {
         while let Some(m) = matches.next() {
             let ff = self.format_test(&m, code, file, &q)?;
             res.push((ff, None, vec![], vec![], None, vec![]));
@@ -1,13 +1,13 @@
 use std::collections::HashSet;
 
-use crate::lang::call_finder::node_data_finder;
+use crate::lang::call_finder::{func_target_file_finder, node_data_finder};
 use crate::lang::{graphs::Graph, *};
 use lsp::{Cmd as LspCmd, Position, Res as LspRes};
 use shared::Result;
 use streaming_iterator::StreamingIterator;
 use tree_sitter::QueryMatch;
 
-use super::utils::{find_def, is_capitalized, log_cmd, trim_quotes};
+use super::utils::{find_def, is_capitalized, join_route, log_cmd, trim_quotes};
 use super::super::queries::consts::FUNCTION_COMMENT;
 
 impl Lang {
@@ -190,6 +190,11 @@ impl Lang {
             Ok(())
         })?;
         for (pos, comp_name) in components_positions_names {
+            // lazily imported components are referenced by their file
+            let comp_name = self
+                .lang
+                .component_name_from_file(&comp_name)
+                .unwrap_or(comp_name);
             if let Some(lsp) = lsp {
                 // use lsp to find the component
                 log_cmd(format!("=> looking for component {:?}", comp_name));
@@ -309,6 +314,8 @@ impl Lang {
         let mut call = None;
         let mut params = HandlerParams::default();
         let mut handler_position = None;
+        let mut prefix = None;
+        let mut is_route = true;
         Self::loop_captures(q, &m, code, |body, node, o| {
             if o == ENDPOINT {
                 let namey = trim_quotes(&body);
@@ -325,6 +332,10 @@ impl Lang {
                 endp.body = body;
                 endp.start = node.start_position().row;
                 endp.end = node.end_position().row;
+                is_route = self.lang.is_route(node, code);
+                if let Some(p) = self.lang.find_endpoint_prefix(node, code) {
+                    prefix = Some(p);
+                }
             } else if o == HANDLER {
                 // tracing::info!("found HANDLER {:?} {:?}", body, endp.name);
                 let handler_name = trim_quotes(&body);
@@ -350,6 +361,10 @@ impl Lang {
                 call = Some(body);
             } else if o == ENDPOINT_GROUP {
                 endp.add_group(&body);
+            } else if o == ENDPOINT_PREFIX {
+                prefix = Some(trim_quotes(&body).to_string());
+            } else if o == PARENT_NAME {
+                endp.add_parent(&body);
             } else if o == COLLECTION_ITEM {
                 params.item = Some(HandlerItem::new_collection(trim_quotes(&body)));
             } else if o == MEMBER_ITEM {
@@ -359,6 +374,12 @@ impl Lang {
             }
             Ok(())
         })?;
+        if !is_route {
+            return Ok(Vec::new());
+        }
+        if let Some(prefix) = prefix {
+            endp.name = join_route(&prefix, &endp.name);
+        }
         if endp.meta.get("verb").is_none() {
             self.lang.add_endpoint_verb(&mut endp, &call);
         }
@@ -446,6 +467,9 @@ impl Lang {
             }
             Ok(())
         })?;
+        if !inst.body.is_empty() {
+            self.lang.update_data_model(&mut inst);
+        }
         Ok(inst)
     }
     pub fn format_function<G: Graph>(
@@ -481,6 +505,16 @@ impl Lang {
                 let pos = Position::new(file, p.row as u32, p.column as u32)?;
                 name_pos = Some(pos);
             } else if o == FUNCTION_DEFINITION {
+                if self.lang.is_test_definition(&node, code) {
+                    // collected by the test query
+                    return Ok(());
+                }
+                if func.name.is_empty() {
+                    if let Some(name) = self.lang.component_name_from_file(file) {
+                        func.name = name;
+                        func.add_component();
+                    }
+                }
                 func.body = body;
                 func.start = node.start_position().row;
                 func.end = node.end_position().row;
@@ -651,6 +685,10 @@ impl Lang {
             log_cmd(format!("found function but empty body {:?}", func.name));
             return Ok(None);
         }
+        if func.name.is_empty() && self.lang.skip_unnamed_functions() {
+            log_cmd(format!("found function but empty name in {}", file));
+            return Ok(None);
+        }
 
         if !comments.is_empty() {
             func.docs = Some(self.clean_and_combine_comments(&comments));
@@ -714,6 +752,8 @@ impl Lang {
         q: &Query,
     ) -> Result<NodeData> {
         let mut test = NodeData::in_file(file);
+        // code around the test (like the annotations on its class) that helps classify it
+        let mut context = None;
         Self::loop_captures(q, &m, code, |body, node, o| {
             if o == FUNCTION_NAME {
                 test.name = trim_quotes(&body).to_string();
@@ -721,10 +761,18 @@ impl Lang {
                 test.body = body;
                 test.start = node.start_position().row;
                 test.end = node.end_position().row;
+            } else if o == TEST_CONTEXT {
+                context = Some(body);
             }
             Ok(())
         })?;
-        let tt = self.lang.classify_test(&test.name, file, &test.body);
+        let tt = match context {
+            Some(context) => {
+                self.lang
+                    .classify_test(&test.name, file, &format!("{}\n{}", context, test.body))
+            }
+            None => self.lang.classify_test(&test.name, file, &test.body),
+        };
         match tt {
             NodeType::E2eTest => test.add_test_kind("e2e"),
             NodeType::IntegrationTest => test.add_test_kind("integration"),
@@ -769,6 +817,9 @@ impl Lang {
             return Ok(None);
         }
         let (called, call_point) = call_name_and_point.unwrap();
+        if self.lang.skip_call(&called, fc.operand.as_deref()) {
+            return Ok(None);
+        }
 
         if let Some(lsp) = lsp_tx {
             log_cmd(format!("=> {} looking for {:?}", caller_name, called));
@@ -847,13 +898,21 @@ impl Lang {
                     }
                 }
             }
-        // } else if let Some(tf) = func_target_file_finder(&body, &fc.operand, graph) {
-        // fc.target = NodeKeys::new(&body, &tf);
         } else {
             // FALLBACK to find?
-            if let Some(tf) =
-                node_data_finder(&called, graph, file, fc.source.start, NodeType::Function)
-            {
+            let operand = if self.lang.use_operand_finder() {
+                &fc.operand
+            } else {
+                &None
+            };
+            if let Some(tf) = func_target_file_finder(
+                &called,
+                operand,
+                graph,
+                file,
+                fc.source.start,
+                NodeType::Function,
+            ) {
                 log_cmd(format!(
                     "==> ? (no lsp) ONE target for {:?} {}",
                     called, &tf.file
@@ -21,6 +21,19 @@ pub fn trim_quotes(value: &str) -> &str {
     value
 }
 
+// join a route prefix (controller, router group) with a route path
+pub fn join_route(prefix: &str, path: &str) -> String {
+    let prefix = prefix.trim_end_matches('/');
+    let path = path.trim_start_matches('/');
+    if path.is_empty() {
+        if prefix.is_empty() {
+            return "/".to_string();
+        }
+        return prefix.to_string();
+    }
+    format!("{}/{}", prefix, path)
+}
+
 pub fn log_cmd(cmd: String) {
     debug!("{}", cmd);
 }
@@ -0,0 +1,363 @@
+use crate::lang::graphs::{Graph, NodeType};
+use crate::lang::{Edge, NodeData};
+use regex::Regex;
+
+// protobuf / grpc support. There is no tree-sitter grammar for .proto files,
+// but the parts we need (package, service, rpc, message) are simple enough to scan
+
+pub const GRPC_VERB: &str = "GRPC";
+
+pub fn is_proto_file(file: &str) -> bool {
+    file.ends_with(".proto")
+}
+
+#[derive(Debug, Default)]
+pub struct ProtoFile {
+    pub messages: Vec<NodeData>,
+    // one endpoint per rpc, named like the grpc path: /package.Service/Method
+    pub rpcs: Vec<NodeData>,
+}
+
+pub fn parse_proto(code: &str, file: &str) -> ProtoFile {
+    let src = strip_comments(code);
+    let mut res = ProtoFile::default();
+
+    let package = Regex::new(r"\bpackage\s+([\w.]+)\s*;")
+        .unwrap()
+        .captures(&src)
+        .map(|c| c[1].to_string());
+
+    let block_re = Regex::new(r"\b(message|service)\s+(\w+)\s*\{").unwrap();
+    let rpc_re = Regex::new(
+        r"\brpc\s+(\w+)\s*\(\s*(stream\s+)?([\w.]+)\s*\)\s*returns\s*\(\s*(stream\s+)?([\w.]+)\s*\)",
+    )
+    .unwrap();
+
+    // (name, end) of the messages we are inside of, for nested message names
+    let mut parents: Vec<(String, usize)> = Vec::new();
+    for cap in block_re.captures_iter(&src) {
+        let whole = cap.get(0).unwrap();
+        let open = whole.end() - 1;
+        let Some(close) = matching_brace(&src, open) else {
+            continue;
+        };
+        parents.retain(|(_, end)| *end > whole.start());
+        let kind = &cap[1];
+        let name = &cap[2];
+        if kind == "message" {
+            let full_name = match parents.last() {
+                Some((parent, _)) => format!("{}.{}", parent, name),
+                None => name.to_string(),
+            };
+            let mut nd = NodeData::name_file(&full_name, file);
+            nd.body = code[whole.start()..=close].to_string();
+            nd.start = line_of(code, whole.start());
+            nd.end = line_of(code, close);
+            res.messages.push(nd);
+            parents.push((full_name, close));
+            continue;
+        }
+        let service_path = match &package {
+            Some(p) => format!("{}.{}", p, name),
+            None => name.to_string(),
+        };
+        let body = &src[open..close];
+        for rpc in rpc_re.captures_iter(body) {
+            let m = rpc.get(0).unwrap();
+            let start = open + m.start();
+            // rpcs end with ; or carry an { option ... } block
+            let rest = &src[open + m.end()..close];
+            let end = if rest.trim_start().starts_with('{') {
+                let brace = open + m.end() + rest.find('{').unwrap();
+                matching_brace(&src, brace).unwrap_or(close)
+            } else {
+                open + m.end() + rest.find(';').unwrap_or(0)
+            };
+            let method = &rpc[1];
+            let mut nd = NodeData::name_file(&format!("/{}/{}", service_path, method), file);
+            nd.body = code[start..=end].to_string();
+            nd.start = line_of(code, start);
+            nd.end = line_of(code, end);
+            nd.add_verb(GRPC_VERB);
+            nd.add_handler(method);
+            nd.meta.insert("service".to_string(), name.to_string());
+            nd.meta.insert("request".to_string(), stream_type(&rpc, 2, 3));
+            nd.meta.insert("response".to_string(), stream_type(&rpc, 4, 5));
+            res.rpcs.push(nd);
+        }
+    }
+    res
+}
+
+// the Handler edge goes to the method of the type that implements the service:
+//   go:     type server struct { pb.UnimplementedUsersServer }
+//   rust:   #[tonic::async_trait] impl Users for MyUsers
+//   python: class UsersServicer(users_pb2_grpc.UsersServicer)
+pub fn find_grpc_handler<G: Graph>(
+    rpc: &NodeData,
+    filez: &[(String, String)],
+    graph: &G,
+) -> Option<Edge> {
+    let service = rpc.meta.get("service")?;
+    let method = rpc.meta.get("handler")?;
+    let markers = [
+        format!(r"type\s+(\w+)\s+struct\s*\{{[^}}]*\bUnimplemented{}Server\b", service),
+        format!(r"impl\s+(?:\w+::)*{}\s+for\s+(\w+)", service),
+        format!(r"class\s+(\w+)\s*\([^)]*\b{}Servicer\s*\)", service),
+    ];
+    let markers: Vec<Regex> = markers.iter().filter_map(|m| Regex::new(m).ok()).collect();
+    let names = [method.to_string(), to_snake_case(method)];
+    for (file, code) in filez {
+        if is_proto_file(file) {
+            continue;
+        }
+        for marker in &markers {
+            for cap in marker.captures_iter(code) {
+                let implementor = &cap[1];
+                let candidates: Vec<NodeData> = names
+                    .iter()
+                    .flat_map(|n| graph.find_nodes_by_name(NodeType::Function, n))
+                    .collect();
+                let handler = candidates
+                    .iter()
+                    .find(|f| f.meta.get("operand").map(|o| o.as_str()) == Some(implementor))
+                    .or_else(|| candidates.iter().find(|f| &f.file == file));
+                if let Some(handler) = handler {
+                    return Some(Edge::handler(rpc, handler));
+                }
+            }
+        }
+    }
+    None
+}
+
+// calls on generated client stubs become Requests, linked to the rpc endpoint
+// later on by link_api_nodes. The proto package is not known on the client side,
+// so requests are named /Service/Method:
+//   go:     c := pb.NewUsersClient(conn); c.GetUser(ctx, req)
+//   rust:   let mut client = UsersClient::connect(addr).await?; client.get_user(req)
+//   python: stub = users_pb2_grpc.UsersStub(channel); stub.GetUser(req)
+pub fn find_grpc_requests(code: &str, file: &str, functions: &[NodeData]) -> Vec<(NodeData, NodeData)> {
+    let stubs = [
+        r"(\w+)\s*:?=\s*(?:\w+\.)?New(\w+)Client\(",
+        r"let\s+(?:mut\s+)?(\w+)\s*=\s*(?:\w+::)*(\w+)Client::(?:connect|new)\(",
+        r"(\w+)\s*=\s*(?:[\w.]+\.)?(\w+)Stub\(",
+    ];
+    let mut clients = Vec::new();
+    for stub in stubs {
+        let re = Regex::new(stub).unwrap();
+        for cap in re.captures_iter(code) {
+            clients.push((cap[1].to_string(), cap[2].to_string()));
+        }
+    }
+    let mut res = Vec::new();
+    for (var, service) in clients {
+        let call_re = Regex::new(&format!(r"\b{}\.(\w+)\(", regex::escape(&var))).unwrap();
+        for func in functions.iter().filter(|f| f.file == file) {
+            for cap in call_re.captures_iter(&func.body) {
+                let m = cap.get(0).unwrap();
+                let method = to_pascal_case(&cap[1]);
+                let line = func.start + func.body[..m.start()].matches('\n').count();
+                let mut req = NodeData::name_file(&format!("/{}/{}", service, method), file);
+                req.body = call_text(&func.body, m.start());
+                req.start = line;
+                req.end = line;
+                req.add_verb(GRPC_VERB);
+                req.meta.insert("service".to_string(), service.clone());
+                res.push((req, func.clone()));
+            }
+        }
+    }
+    res
+}
+
+// /Service/Method on the client matches /package.Service/Method on the server
+pub fn grpc_paths_match(request_path: &str, endpoint_path: &str) -> bool {
+    let split = |p: &str| -> Option<(String, String)> {
+        let (service, method) = p.trim_start_matches('/').split_once('/')?;
+        let service = service.rsplit('.').next()?;
+        Some((service.to_string(), method.to_lowercase()))
+    };
+    match (split(request_path), split(endpoint_path)) {
+        (Some(req), Some(end)) => req == end,
+        _ => false,
+    }
+}
+
+fn stream_type(rpc: &regex::Captures, stream: usize, ty: usize) -> String {
+    match rpc.get(stream) {
+        Some(_) => format!("stream {}", &rpc[ty]),
+        None => rpc[ty].to_string(),
+    }
+}
+
+pub(crate) fn call_text(body: &str, start: usize) -> String {
+    let rest = &body[start..];
+    let end = rest.find('\n').unwrap_or(rest.len());
+    rest[..end].trim().to_string()
+}
+
+// blanks out comments, keeping byte offsets and line numbers intact
+fn strip_comments(code: &str) -> String {
+    let bytes = code.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut i = 0;
+    let mut in_string = false;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if in_string {
+            if c == b'\\' {
+                i += 1;
+            } else if c == b'"' {
+                in_string = false;
+            }
+        } else if c == b'"' {
+            in_string = true;
+        } else if c == b'/' && bytes.get(i + 1) == Some(&b'/') {
+            while i < bytes.len() && bytes[i] != b'\n' {
+                out[i] = b' ';
+                i += 1;
+            }
+            continue;
+        } else if c == b'/' && bytes.get(i + 1) == Some(&b'*') {
+            while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                if bytes[i] != b'\n' {
+                    out[i] = b' ';
+                }
+                i += 1;
+            }
+            for b in out.iter_mut().skip(i).take(2) {
+                *b = b' ';
+            }
+            i += 2;
+            continue;
+        }
+        i += 1;
+    }
+    String::from_utf8(out).unwrap_or_else(|_| code.to_string())
+}
+
+pub(crate) fn matching_brace(src: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in src[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+pub(crate) fn line_of(code: &str, offset: usize) -> usize {
+    code[..offset].matches('\n').count()
+}
+
+pub(crate) fn to_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+pub(crate) fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USERS_PROTO: &str = r#"syntax = "proto3";
+
+package users.v1;
+
+// the users service
+service Users {
+  rpc GetUser (GetUserRequest) returns (User);
+  /* rpc Disabled (A) returns (B); */
+  rpc WatchUsers (WatchRequest) returns (stream User) {
+    option deprecated = true;
+  }
+}
+
+message User {
+  string id = 1;
+  message Address {
+    string city = 1;
+  }
+  Address address = 2;
+}
+
+message GetUserRequest {
+  string id = 1; // "quoted // not a comment"
+}
+"#;
+
+    #[test]
+    fn test_parse_proto() {
+        let proto = parse_proto(USERS_PROTO, "proto/users.proto");
+        let rpcs: Vec<&str> = proto.rpcs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(rpcs, vec!["/users.v1.Users/GetUser", "/users.v1.Users/WatchUsers"]);
+        let watch = &proto.rpcs[1];
+        assert_eq!(watch.meta.get("verb"), Some(&GRPC_VERB.to_string()));
+        assert_eq!(watch.meta.get("handler"), Some(&"WatchUsers".to_string()));
+        assert_eq!(watch.meta.get("response"), Some(&"stream User".to_string()));
+        assert_eq!(watch.start, 8);
+        assert_eq!(watch.end, 10);
+
+        let messages: Vec<&str> = proto.messages.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(messages, vec!["User", "User.Address", "GetUserRequest"]);
+        assert!(proto.messages[0].body.contains("Address address = 2;"));
+    }
+
+    #[test]
+    fn test_find_grpc_requests() {
+        let go = "func fetch(conn *grpc.ClientConn) {\n\tc := pb.NewUsersClient(conn)\n\tc.GetUser(ctx, req)\n}";
+        let rust = "async fn fetch() {\n    let mut client = UsersClient::connect(addr).await?;\n    client.get_user(req).await?;\n}";
+        let python = "def fetch(channel):\n    stub = users_pb2_grpc.UsersStub(channel)\n    return stub.GetUser(req)";
+        for (file, code) in [("client.go", go), ("client.rs", rust), ("client.py", python)] {
+            let func = NodeData {
+                name: "fetch".to_string(),
+                file: file.to_string(),
+                body: code.to_string(),
+                start: 10,
+                ..Default::default()
+            };
+            let reqs = find_grpc_requests(code, file, &[func]);
+            assert_eq!(reqs.len(), 1, "Expected 1 request in {}", file);
+            assert_eq!(reqs[0].0.name, "/Users/GetUser");
+            assert_eq!(reqs[0].0.start, 12);
+            assert_eq!(reqs[0].1.name, "fetch");
+        }
+    }
+
+    #[test]
+    fn test_grpc_paths_match() {
+        assert!(grpc_paths_match("/Users/GetUser", "/users.v1.Users/GetUser"));
+        assert!(grpc_paths_match("/Users/GetUser", "/Users/GetUser"));
+        assert!(!grpc_paths_match("/Users/GetUser", "/users.v1.Accounts/GetUser"));
+        assert!(!grpc_paths_match("/Users/GetUser", "/users.v1.Users/ListUsers"));
+    }
+}
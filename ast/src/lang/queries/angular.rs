@@ -1,7 +1,7 @@
 use super::super::*;
 use super::consts::*;
 use shared::{Context, Result};
-use tree_sitter::{Language, Parser, Query, Tree};
+use tree_sitter::{Language, Node as TreeNode, Parser, Query, QueryCursor, Tree};
 
 pub struct Angular(Language);
 
@@ -389,4 +389,608 @@ impl Stack for Angular {
         }
         edges
     }
+
+    // a Page per route of the Routes arrays, mounted under the routes that load them
+    fn add_extra_pages(&self, graph: &mut dyn GraphEdit, files: &[(String, String)]) -> usize {
+        let arrays: Vec<RouteArray> = files
+            .iter()
+            .filter(|(f, c)| is_routes_file(f, c))
+            .flat_map(|(f, c)| route_arrays(c, f))
+            .collect();
+        let mounted = mount_routes(&arrays);
+        let paths = files.iter().map(|(f, _)| f.as_str()).collect::<Vec<_>>();
+
+        let mut pages = Vec::new();
+        for route in &mounted {
+            let mut page = NodeData::name_file(&route.path, &route.file);
+            page.start = route.route.start;
+            page.end = route.route.end;
+            page.body = route.route.body.clone();
+            if let Some(redirect) = &route.route.redirect_to {
+                page.meta.insert("redirect_to".to_string(), redirect.clone());
+            }
+            graph.add_page((page.clone(), None));
+            pages.push(page);
+        }
+
+        let renders = |page: &NodeData, nt: NodeType, target: &NodeData| {
+            Edge::new(
+                EdgeType::Renders,
+                NodeRef::from(page.into(), NodeType::Page),
+                NodeRef::from(target.into(), nt),
+            )
+        };
+        for (route, page) in mounted.iter().zip(&pages) {
+            // component classes, and the exported class (or the file's only class) of a lazy import
+            let mut components = Vec::new();
+            if let Some(name) = &route.route.component {
+                components.extend(graph.find_nodes_by_name(NodeType::Class, name).into_iter().next());
+            }
+            for lazy in [&route.route.load_component, &route.route.load_children]
+                .into_iter()
+                .flatten()
+            {
+                let class = match &lazy.export {
+                    Some(name) => graph.find_nodes_by_name(NodeType::Class, name).into_iter().next(),
+                    None => lazy_import_file(&route.file, lazy, &paths).and_then(|file| {
+                        graph
+                            .find_nodes_by_file_ends_with(NodeType::Class, file)
+                            .into_iter()
+                            .next()
+                    }),
+                };
+                components.extend(class);
+            }
+            for class in components {
+                graph.add_edge(renders(page, NodeType::Class, &class));
+            }
+            if let Some(parent) = route.loaded_by {
+                graph.add_edge(renders(&pages[parent], NodeType::Page, page));
+            }
+
+            for (key, name) in &route.route.guards {
+                let guard = graph
+                    .find_nodes_by_name(NodeType::Function, name)
+                    .into_iter()
+                    .next()
+                    .or_else(|| {
+                        let class = graph.find_nodes_by_name(NodeType::Class, name).into_iter().next()?;
+                        graph.find_node_by_name_in_file(
+                            NodeType::Function,
+                            guard_method(key),
+                            &class.file,
+                        )
+                    })
+                    .or_else(|| {
+                        // functional guards are exported consts, which are not Functions yet
+                        let func = files
+                            .iter()
+                            .filter(|(_, c)| c.contains(name.as_str()))
+                            .flat_map(|(f, c)| exported_functions(c, f))
+                            .find(|f| &f.name == name)?;
+                        graph.add_node_with_parent(NodeType::Function, func.clone(), NodeType::File, &func.file);
+                        Some(func)
+                    });
+                if let Some(guard) = guard {
+                    graph.add_edge(Edge::calls(NodeType::Page, page, NodeType::Function, &guard));
+                }
+            }
+        }
+        mounted.len()
+    }
+}
+
+const ROUTER_CALLS: [&str; 3] = ["forRoot", "forChild", "provideRouter"];
+const GUARD_KEYS: [&str; 5] = [
+    "canActivate",
+    "canActivateChild",
+    "canDeactivate",
+    "canMatch",
+    "canLoad",
+];
+
+// a route record from a Routes array, with its nested children
+#[derive(Debug, Clone, Default)]
+pub struct RouteDef {
+    pub path: String,
+    pub start: usize,
+    pub end: usize,
+    pub body: String,
+    pub component: Option<String>,
+    pub load_component: Option<LazyImport>,
+    pub load_children: Option<LazyImport>,
+    pub redirect_to: Option<String>,
+    // (route key, guard or resolver name)
+    pub guards: Vec<(String, String)>,
+    pub children: Vec<RouteDef>,
+}
+
+// `() => import('./path').then(m => m.Export)`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LazyImport {
+    pub path: String,
+    pub export: Option<String>,
+}
+
+// a Routes array, named when it is assigned to a variable
+#[derive(Debug, Clone, Default)]
+pub struct RouteArray {
+    pub file: String,
+    pub name: Option<String>,
+    pub routes: Vec<RouteDef>,
+}
+
+// a route record placed at its full URL path
+#[derive(Debug, Clone)]
+pub struct MountedRoute {
+    pub file: String,
+    pub path: String,
+    pub route: RouteDef,
+    // the route whose loadChildren loaded this one
+    pub loaded_by: Option<usize>,
+}
+
+pub fn is_routes_file(file: &str, code: &str) -> bool {
+    file.ends_with(".ts") && !file.ends_with(".spec.ts") && code.contains("@angular/router")
+}
+
+// the method a class based guard or resolver implements for a route key
+pub fn guard_method(key: &str) -> &str {
+    if key == "resolve" {
+        "resolve"
+    } else {
+        key
+    }
+}
+
+// Routes typed variables, and arrays passed straight to forRoot/forChild/provideRouter
+pub fn route_arrays(code: &str, file: &str) -> Vec<RouteArray> {
+    let language: Language = tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into();
+    let mut parser = Parser::new();
+    if parser.set_language(&language).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(code, None) else {
+        return Vec::new();
+    };
+    let mut res = Vec::new();
+    collect_route_arrays(tree.root_node(), code, file, &mut res);
+    res
+}
+
+fn collect_route_arrays(node: TreeNode, code: &str, file: &str, res: &mut Vec<RouteArray>) {
+    let text = |n: TreeNode| n.utf8_text(code.as_bytes()).unwrap_or_default().to_string();
+    match node.kind() {
+        "variable_declarator" => {
+            let is_routes = node
+                .child_by_field_name("type")
+                .map(|t| {
+                    let t = text(t);
+                    let t = t.trim_start_matches(':').trim();
+                    t == "Routes" || t == "Route[]"
+                })
+                .unwrap_or(false);
+            if let (true, Some(name), Some(value)) = (
+                is_routes,
+                node.child_by_field_name("name"),
+                node.child_by_field_name("value"),
+            ) {
+                if value.kind() == "array" {
+                    res.push(RouteArray {
+                        file: file.to_string(),
+                        name: Some(text(name)),
+                        routes: route_defs(value, code),
+                    });
+                }
+            }
+        }
+        "call_expression" => {
+            let is_router = node
+                .child_by_field_name("function")
+                .map(|f| {
+                    let f = text(f);
+                    ROUTER_CALLS.contains(&f.rsplit('.').next().unwrap_or_default())
+                })
+                .unwrap_or(false);
+            let first_arg = node
+                .child_by_field_name("arguments")
+                .and_then(|a| a.named_child(0));
+            if let (true, Some(arg)) = (is_router, first_arg) {
+                if arg.kind() == "array" {
+                    res.push(RouteArray {
+                        file: file.to_string(),
+                        name: None,
+                        routes: route_defs(arg, code),
+                    });
+                }
+            }
+        }
+        _ => {}
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_route_arrays(child, code, file, res);
+    }
+}
+
+fn route_defs(array: TreeNode, code: &str) -> Vec<RouteDef> {
+    let mut cursor = array.walk();
+    array
+        .named_children(&mut cursor)
+        .filter(|n| n.kind() == "object")
+        .map(|obj| route_def(obj, code))
+        .collect()
+}
+
+fn route_def(obj: TreeNode, code: &str) -> RouteDef {
+    let text = |n: TreeNode| n.utf8_text(code.as_bytes()).unwrap_or_default().to_string();
+    let mut route = RouteDef {
+        start: obj.start_position().row,
+        end: obj.end_position().row,
+        body: text(obj),
+        ..Default::default()
+    };
+    let mut cursor = obj.walk();
+    for pair in obj.named_children(&mut cursor) {
+        if pair.kind() != "pair" {
+            continue;
+        }
+        let (Some(key), Some(value)) = (
+            pair.child_by_field_name("key"),
+            pair.child_by_field_name("value"),
+        ) else {
+            continue;
+        };
+        let key = parse::trim_quotes(&text(key)).to_string();
+        match key.as_str() {
+            "path" => route.path = parse::trim_quotes(&text(value)).to_string(),
+            "redirectTo" => route.redirect_to = Some(parse::trim_quotes(&text(value)).to_string()),
+            "component" if value.kind() == "identifier" => route.component = Some(text(value)),
+            "loadComponent" => route.load_component = lazy_import(value, code),
+            "loadChildren" => route.load_children = lazy_import(value, code),
+            "children" if value.kind() == "array" => route.children = route_defs(value, code),
+            "resolve" if value.kind() == "object" => {
+                let mut c = value.walk();
+                for resolver in value.named_children(&mut c) {
+                    if let Some(v) = resolver.child_by_field_name("value") {
+                        if v.kind() == "identifier" {
+                            route.guards.push((key.clone(), text(v)));
+                        }
+                    }
+                }
+            }
+            k if GUARD_KEYS.contains(&k) && value.kind() == "array" => {
+                let mut c = value.walk();
+                for guard in value.named_children(&mut c) {
+                    let name = match guard.kind() {
+                        "identifier" => Some(text(guard)),
+                        // guard factories like roleGuard('admin')
+                        "call_expression" => guard
+                            .child_by_field_name("function")
+                            .filter(|f| f.kind() == "identifier")
+                            .map(text),
+                        _ => None,
+                    };
+                    if let Some(name) = name {
+                        route.guards.push((key.clone(), name));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    route
+}
+
+fn lazy_import(value: TreeNode, code: &str) -> Option<LazyImport> {
+    let text = |n: TreeNode| n.utf8_text(code.as_bytes()).unwrap_or_default().to_string();
+    // the legacy './admin/admin.module#AdminModule' string form
+    if value.kind() == "string" {
+        let s = parse::trim_quotes(&text(value)).to_string();
+        let (path, export) = match s.split_once('#') {
+            Some((p, e)) => (p.to_string(), Some(e.to_string())),
+            None => (s, None),
+        };
+        return Some(LazyImport { path, export });
+    }
+    let mut lazy = LazyImport::default();
+    let mut stack = vec![value];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call_expression"
+            && node.child_by_field_name("function").map(|f| f.kind()) == Some("import")
+        {
+            if let Some(arg) = node
+                .child_by_field_name("arguments")
+                .and_then(|a| a.named_child(0))
+            {
+                lazy.path = parse::trim_quotes(&text(arg)).to_string();
+            }
+        } else if node.kind() == "arrow_function" && node != value {
+            if let Some(prop) = node
+                .child_by_field_name("body")
+                .filter(|b| b.kind() == "member_expression")
+                .and_then(|b| b.child_by_field_name("property"))
+            {
+                lazy.export = Some(text(prop));
+            }
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+    (!lazy.path.is_empty()).then_some(lazy)
+}
+
+// joins route paths into a full URL path, like "/admin/users/:id"
+pub fn join_route(prefix: &str, path: &str) -> String {
+    let segments = prefix
+        .split('/')
+        .chain(path.split('/'))
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    format!("/{}", segments.join("/"))
+}
+
+// the file path (without extension) a lazy import points to
+pub fn resolve_lazy_path(from_file: &str, import_path: &str) -> String {
+    let mut parts: Vec<&str> = from_file.split('/').collect();
+    parts.pop();
+    for seg in import_path.split('/') {
+        match seg {
+            "." | "" => {}
+            ".." => {
+                parts.pop();
+            }
+            s => parts.push(s),
+        }
+    }
+    parts.join("/")
+}
+
+// the file a lazy import loads, from a list of file paths
+pub fn lazy_import_file<'a>(from_file: &str, lazy: &LazyImport, files: &[&'a str]) -> Option<&'a str> {
+    let target = resolve_lazy_path(from_file, &lazy.path);
+    files
+        .iter()
+        .find(|f| **f == format!("{}.ts", target) || **f == format!("{}/index.ts", target))
+        .copied()
+}
+
+// the Routes arrays that a loadChildren import brings in. A routing module
+// usually sits next to its NgModule, so the directory is searched as well.
+fn lazy_targets(from_file: &str, lazy: &LazyImport, arrays: &[RouteArray]) -> Vec<usize> {
+    let files = arrays.iter().map(|a| a.file.as_str()).collect::<Vec<_>>();
+    let target = resolve_lazy_path(from_file, &lazy.path);
+    let in_file = |file: &str| {
+        let named = arrays
+            .iter()
+            .position(|a| a.file == file && a.name.is_some() && a.name == lazy.export);
+        match named {
+            Some(i) => vec![i],
+            None => (0..arrays.len()).filter(|i| arrays[*i].file == file).collect(),
+        }
+    };
+    if let Some(file) = lazy_import_file(from_file, lazy, &files) {
+        return in_file(file);
+    }
+    let dir = target.rsplit_once('/').map(|(d, _)| d).unwrap_or_default();
+    (0..arrays.len())
+        .filter(|i| arrays[*i].file.rsplit_once('/').map(|(d, _)| d) == Some(dir))
+        .collect()
+}
+
+// places every route at its full URL path. Arrays loaded by a loadChildren
+// are mounted under that route, everything else is mounted at the root.
+pub fn mount_routes(arrays: &[RouteArray]) -> Vec<MountedRoute> {
+    let mut lazy = vec![false; arrays.len()];
+    for array in arrays {
+        let mut stack: Vec<&RouteDef> = array.routes.iter().collect();
+        while let Some(route) = stack.pop() {
+            if let Some(load) = &route.load_children {
+                for i in lazy_targets(&array.file, load, arrays) {
+                    lazy[i] = true;
+                }
+            }
+            stack.extend(route.children.iter());
+        }
+    }
+    let mut res = Vec::new();
+    for (i, is_lazy) in lazy.iter().enumerate() {
+        if !is_lazy {
+            mount_array(arrays, i, "/", None, &mut vec![i], &mut res);
+        }
+    }
+    res
+}
+
+fn mount_array(
+    arrays: &[RouteArray],
+    i: usize,
+    prefix: &str,
+    loaded_by: Option<usize>,
+    mounting: &mut Vec<usize>,
+    res: &mut Vec<MountedRoute>,
+) {
+    for route in &arrays[i].routes {
+        mount_route(arrays, i, route, prefix, loaded_by, mounting, res);
+    }
+}
+
+fn mount_route(
+    arrays: &[RouteArray],
+    i: usize,
+    route: &RouteDef,
+    prefix: &str,
+    loaded_by: Option<usize>,
+    mounting: &mut Vec<usize>,
+    res: &mut Vec<MountedRoute>,
+) {
+    let path = join_route(prefix, &route.path);
+    let idx = res.len();
+    res.push(MountedRoute {
+        file: arrays[i].file.clone(),
+        path: path.clone(),
+        route: RouteDef {
+            children: Vec::new(),
+            ..route.clone()
+        },
+        loaded_by,
+    });
+    for child in &route.children {
+        mount_route(arrays, i, child, &path, None, mounting, res);
+    }
+    if let Some(load) = &route.load_children {
+        for j in lazy_targets(&arrays[i].file, load, arrays) {
+            // guards against routing modules that load each other
+            if mounting.contains(&j) {
+                continue;
+            }
+            mounting.push(j);
+            mount_array(arrays, j, &path, Some(idx), mounting, res);
+            mounting.pop();
+        }
+    }
+}
+
+// exported functions and arrow function consts, like functional guards and resolvers
+pub fn exported_functions(code: &str, file: &str) -> Vec<NodeData> {
+    let language: Language = tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into();
+    let query_str = r#"
+    (export_statement
+        declaration: [
+            (function_declaration
+                name: (identifier) @name
+            )
+            (lexical_declaration
+                (variable_declarator
+                    name: (identifier) @name
+                    value: [(arrow_function) (function_expression)]
+                )
+            )
+        ]
+    ) @export
+    "#;
+    let mut res = Vec::new();
+    let Ok(query) = Query::new(&language, query_str) else {
+        return res;
+    };
+    let mut parser = Parser::new();
+    if parser.set_language(&language).is_err() {
+        return res;
+    }
+    let Some(tree) = parser.parse(code, None) else {
+        return res;
+    };
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(&query, tree.root_node(), code.as_bytes());
+    while let Some(m) = matches.next() {
+        let mut func = NodeData::in_file(file);
+        for cap in m.captures.iter() {
+            let text = cap.node.utf8_text(code.as_bytes()).unwrap_or_default();
+            if query.capture_names()[cap.index as usize] == "name" {
+                func.name = text.to_string();
+            } else {
+                func.body = text.to_string();
+                func.start = cap.node.start_position().row;
+                func.end = cap.node.end_position().row;
+            }
+        }
+        res.push(func);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_arrays() {
+        let code = r#"import { Routes } from '@angular/router';
+import { authGuard } from './auth.guard';
+
+export const routes: Routes = [
+  { path: '', redirectTo: '/dashboard', pathMatch: 'full' },
+  {
+    path: 'dashboard',
+    component: DashboardComponent,
+    canActivate: [authGuard, roleGuard('admin')],
+    resolve: { user: userResolver },
+    children: [
+      { path: 'stats/:id', loadComponent: () => import('./stats/stats.component').then(m => m.StatsComponent) }
+    ]
+  },
+  { path: 'admin', loadChildren: () => import('./admin/admin.routes').then((m) => m.ADMIN_ROUTES) }
+];
+"#;
+        let arrays = route_arrays(code, "app/src/app/app.routes.ts");
+        assert_eq!(arrays.len(), 1);
+        assert_eq!(arrays[0].name, Some("routes".to_string()));
+        let routes = &arrays[0].routes;
+        assert_eq!(routes.len(), 3);
+        assert_eq!(routes[0].redirect_to, Some("/dashboard".to_string()));
+        assert_eq!(routes[1].component, Some("DashboardComponent".to_string()));
+        assert_eq!(routes[1].start, 5);
+        assert_eq!(
+            routes[1].guards,
+            vec![
+                ("canActivate".to_string(), "authGuard".to_string()),
+                ("canActivate".to_string(), "roleGuard".to_string()),
+                ("resolve".to_string(), "userResolver".to_string()),
+            ]
+        );
+        assert_eq!(
+            routes[1].children[0].load_component,
+            Some(LazyImport {
+                path: "./stats/stats.component".to_string(),
+                export: Some("StatsComponent".to_string()),
+            })
+        );
+        assert_eq!(
+            routes[2].load_children,
+            Some(LazyImport {
+                path: "./admin/admin.routes".to_string(),
+                export: Some("ADMIN_ROUTES".to_string()),
+            })
+        );
+
+        let module = "@NgModule({ imports: [RouterModule.forChild([{ path: 'x', component: X }])] })\nexport class XModule {}";
+        let arrays = route_arrays(module, "app/src/app/x/x.module.ts");
+        assert_eq!(arrays.len(), 1);
+        assert_eq!(arrays[0].name, None);
+        assert_eq!(arrays[0].routes[0].path, "x");
+    }
+
+    #[test]
+    fn test_mount_routes() {
+        let app = r#"export const routes: Routes = [
+  { path: 'home', component: HomeComponent, children: [{ path: 'news', component: NewsComponent }] },
+  { path: 'admin', loadChildren: () => import('./admin/admin.routes').then(m => m.ADMIN_ROUTES) },
+  { path: 'legacy', loadChildren: './legacy/legacy.module#LegacyModule' }
+];"#;
+        let admin = r#"export const ADMIN_ROUTES: Routes = [
+  { path: '', component: AdminComponent },
+  { path: 'users/:id', component: UserComponent }
+];"#;
+        let legacy = "RouterModule.forChild([{ path: 'old', component: OldComponent }])";
+        let mut arrays = route_arrays(app, "web/src/app/app.routes.ts");
+        arrays.extend(route_arrays(admin, "web/src/app/admin/admin.routes.ts"));
+        arrays.extend(route_arrays(legacy, "web/src/app/legacy/legacy-routing.module.ts"));
+
+        let mounted = mount_routes(&arrays);
+        let paths = mounted.iter().map(|m| m.path.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec!["/home", "/home/news", "/admin", "/admin", "/admin/users/:id", "/legacy", "/legacy/old"]
+        );
+        assert_eq!(mounted[3].loaded_by, Some(2));
+        assert_eq!(mounted[3].file, "web/src/app/admin/admin.routes.ts");
+        assert_eq!(mounted[4].loaded_by, Some(2));
+        assert_eq!(mounted[6].loaded_by, Some(5));
+        assert_eq!(mounted[1].loaded_by, None);
+        assert_eq!(join_route("/", ""), "/");
+        assert_eq!(
+            resolve_lazy_path("web/src/app/admin/admin.routes.ts", "../shared/x.component"),
+            "web/src/app/shared/x.component"
+        );
+    }
 }
@@ -1,6 +1,10 @@
-use tree_sitter::Language;
+use super::super::*;
+use super::consts::*;
+use crate::lang::docker::{join_path, parent_dir};
+use shared::error::{Context, Result};
+use tracing::info;
+use tree_sitter::{Language, Node as TreeNode, Parser, Query, Tree};
 
-#[allow(dead_code)]
 pub struct Bash(Language);
 
 impl Bash {
@@ -8,3 +12,425 @@ impl Bash {
         Bash(tree_sitter_bash::LANGUAGE.into())
     }
 }
+
+impl Stack for Bash {
+    fn q(&self, q: &str, _nt: &NodeType) -> Query {
+        Query::new(&self.0, q).unwrap()
+    }
+
+    fn parse(&self, code: &str, _nt: &NodeType) -> Result<Tree> {
+        let mut parser = Parser::new();
+        parser.set_language(&self.0)?;
+        parser.parse(code, None).context("Failed to parse code")
+    }
+
+    fn identifier_query(&self) -> String {
+        // bash has no (identifier), variables are read through $NAME expansions
+        format!("(variable_name) @identifier")
+    }
+
+    fn variables_query(&self) -> Option<String> {
+        // top level assignments, including export / readonly / declare
+        Some(format!(
+            r#"
+            (program
+                [
+                    (variable_assignment
+                        name: (variable_name) @{VARIABLE_NAME}
+                        value: (_)? @{VARIABLE_VALUE}
+                    )
+                    (declaration_command
+                        (variable_assignment
+                            name: (variable_name) @{VARIABLE_NAME}
+                            value: (_)? @{VARIABLE_VALUE}
+                        )
+                    )
+                ] @{VARIABLE_DECLARATION}
+            )
+            "#
+        ))
+    }
+
+    fn class_definition_query(&self) -> String {
+        // shell scripts have no classes
+        format!(
+            r#"
+            ((function_definition
+                name: (word) @{CLASS_NAME}
+            ) @{CLASS_DEFINITION}
+            (#eq? @{CLASS_NAME} "__no_classes__"))
+            "#
+        )
+    }
+
+    fn function_definition_query(&self) -> String {
+        format!(
+            r#"
+            (function_definition
+                name: (word) @{FUNCTION_NAME}
+                body: (_)
+            ) @{FUNCTION_DEFINITION}
+            "#
+        )
+    }
+
+    fn comment_query(&self) -> Option<String> {
+        // skip the shebang line
+        Some(format!(
+            r#"((comment) @{FUNCTION_COMMENT} (#not-match? @{FUNCTION_COMMENT} "^#!"))"#
+        ))
+    }
+
+    fn function_call_query(&self) -> String {
+        format!(
+            r#"
+            (command
+                name: (command_name (word) @{FUNCTION_NAME})
+            ) @{FUNCTION_CALL}
+            "#
+        )
+    }
+
+    fn add_script_links(&self, graph: &mut dyn GraphEdit, files: &[(String, String)]) {
+        info!("=> get_shell_scripts...");
+        let scripts: Vec<NodeData> = graph
+            .find_nodes_by_type(NodeType::File)
+            .into_iter()
+            .filter(|f| is_script(&f.file))
+            .collect();
+        let mut link_count = 0;
+        for (filename, code) in files {
+            if !is_script(filename) {
+                continue;
+            }
+            let Some(file) = scripts.iter().find(|f| &f.file == filename) else {
+                continue;
+            };
+            for link in script_links(code) {
+                let Some(target) = resolve_script(&link.path, filename, &scripts) else {
+                    continue;
+                };
+                let edge = match link.kind {
+                    ScriptLinkKind::Source => Edge::file_imports(file, NodeType::File, target),
+                    ScriptLinkKind::Run => {
+                        let caller = link.caller.and_then(|(name, _)| {
+                            graph.find_node_by_name_in_file(NodeType::Function, &name, filename)
+                        });
+                        match caller {
+                            Some(caller) => {
+                                Edge::calls(NodeType::Function, &caller, NodeType::File, target)
+                            }
+                            None => Edge::calls(NodeType::File, file, NodeType::File, target),
+                        }
+                    }
+                };
+                graph.add_edge(edge);
+                link_count += 1;
+            }
+        }
+
+        // npm scripts and make targets that run the scripts
+        let mut entry_count = 0;
+        for (filename, code) in files {
+            if !is_script_runner_file(filename) {
+                continue;
+            }
+            let (runner, entries) = if filename.ends_with("package.json") {
+                let entries = package_scripts(code)
+                    .into_iter()
+                    .map(|(name, command)| {
+                        let start = code
+                            .lines()
+                            .position(|l| l.contains(&format!("\"{}\"", name)))
+                            .unwrap_or_default();
+                        (name, command, start, start)
+                    })
+                    .collect::<Vec<_>>();
+                ("npm", entries)
+            } else {
+                let entries = make_targets(code)
+                    .into_iter()
+                    .map(|t| (t.name, t.recipe, t.start, t.end))
+                    .collect::<Vec<_>>();
+                ("make", entries)
+            };
+            for (name, command, start, end) in entries {
+                let targets: Vec<&NodeData> = command_scripts(&command)
+                    .iter()
+                    .filter_map(|path| resolve_script(path, filename, &scripts))
+                    .collect();
+                if targets.is_empty() {
+                    continue;
+                }
+                let mut entry = NodeData::name_file(&name, filename);
+                entry.body = command.trim().to_string();
+                entry.start = start;
+                entry.end = end;
+                entry
+                    .meta
+                    .insert("script_runner".to_string(), runner.to_string());
+                graph.add_node_with_parent(NodeType::Function, entry.clone(), NodeType::File, filename);
+                for target in targets {
+                    graph.add_edge(Edge::calls(NodeType::Function, &entry, NodeType::File, target));
+                }
+                entry_count += 1;
+            }
+        }
+        info!(
+            "=> got {} script links and {} script entries",
+            link_count, entry_count
+        );
+    }
+}
+
+const SCRIPT_EXTS: [&str; 2] = [".sh", ".bash"];
+// commands that run the script given as their first argument
+const SCRIPT_RUNNERS: [&str; 8] = ["bash", "sh", "zsh", "dash", "exec", "sudo", "nohup", "time"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptLinkKind {
+    // source / . (an Imports edge)
+    Source,
+    // running another script (a Calls edge)
+    Run,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptLink {
+    pub kind: ScriptLinkKind,
+    pub path: String,
+    // the name and start line of the function the command is in
+    pub caller: Option<(String, usize)>,
+}
+
+pub fn is_script(file: &str) -> bool {
+    SCRIPT_EXTS.iter().any(|ext| file.ends_with(ext))
+}
+
+// package.json and Makefiles run scripts from their entries
+pub fn is_script_runner_file(file: &str) -> bool {
+    let name = file.rsplit('/').next().unwrap_or_default();
+    name == "package.json" || name == "Makefile" || name == "makefile" || name == "GNUmakefile"
+}
+
+pub fn script_links(code: &str) -> Vec<ScriptLink> {
+    let mut parser = Parser::new();
+    let mut res = Vec::new();
+    if parser.set_language(&tree_sitter_bash::LANGUAGE.into()).is_err() {
+        return res;
+    }
+    let Some(tree) = parser.parse(code, None) else {
+        return res;
+    };
+    let mut stack = vec![tree.root_node()];
+    while let Some(node) = stack.pop() {
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+        if node.kind() != "command" {
+            continue;
+        }
+        let text = |n: TreeNode| unquote(n.utf8_text(code.as_bytes()).unwrap_or_default());
+        let Some(name) = node.child_by_field_name("name").map(text) else {
+            continue;
+        };
+        let mut cursor = node.walk();
+        let args: Vec<String> = node
+            .children_by_field_name("argument", &mut cursor)
+            .map(text)
+            .collect();
+        let link = if name == "source" || name == "." {
+            args.first().map(|a| (ScriptLinkKind::Source, a.clone()))
+        } else if is_script(&name) {
+            Some((ScriptLinkKind::Run, name))
+        } else if SCRIPT_RUNNERS.contains(&name.as_str()) {
+            args.iter()
+                .find(|a| !a.starts_with('-'))
+                .filter(|a| is_script(a))
+                .map(|a| (ScriptLinkKind::Run, a.clone()))
+        } else {
+            None
+        };
+        if let Some((kind, path)) = link {
+            res.push(ScriptLink {
+                kind,
+                path: script_path(&path),
+                caller: enclosing_function(node, code),
+            });
+        }
+    }
+    res.sort_by_key(|l| l.path.clone());
+    res
+}
+
+// the scripts run by a command line, like an npm script or a make recipe
+pub fn command_scripts(command: &str) -> Vec<String> {
+    command
+        .split(|c: char| c.is_whitespace() || c == ';' || c == '&' || c == '|')
+        .map(unquote)
+        .filter(|t| is_script(t))
+        .map(|t| script_path(&t))
+        .collect()
+}
+
+// (name, command) of the "scripts" in a package.json
+pub fn package_scripts(code: &str) -> Vec<(String, String)> {
+    let Ok(doc) = serde_json::from_str::<serde_json::Value>(code) else {
+        return Vec::new();
+    };
+    let Some(scripts) = doc.get("scripts").and_then(|s| s.as_object()) else {
+        return Vec::new();
+    };
+    scripts
+        .iter()
+        .filter_map(|(name, cmd)| Some((name.clone(), cmd.as_str()?.to_string())))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MakeTarget {
+    pub name: String,
+    pub start: usize,
+    pub end: usize,
+    pub recipe: String,
+}
+
+pub fn make_targets(code: &str) -> Vec<MakeTarget> {
+    let mut res: Vec<MakeTarget> = Vec::new();
+    for (i, line) in code.lines().enumerate() {
+        if let Some(recipe) = line.strip_prefix('\t') {
+            if let Some(target) = res.last_mut().filter(|t| t.end + 1 == i) {
+                target.end = i;
+                target.recipe.push_str(recipe.trim());
+                target.recipe.push('\n');
+            }
+            continue;
+        }
+        let Some((targets, rest)) = line.split_once(':') else {
+            continue;
+        };
+        // variable assignments, not rules
+        if rest.starts_with('=') || targets.contains('=') || targets.trim_start().starts_with('#') {
+            continue;
+        }
+        for name in targets.split_whitespace() {
+            if name.starts_with('.') || name.contains('%') || name.contains('$') {
+                continue;
+            }
+            res.push(MakeTarget {
+                name: name.to_string(),
+                start: i,
+                end: i,
+                recipe: String::new(),
+            });
+        }
+    }
+    res
+}
+
+// the script file a path refers to, from the file that runs it
+pub fn resolve_script<'a>(path: &str, from: &str, files: &'a [NodeData]) -> Option<&'a NodeData> {
+    let exact = join_path(&parent_dir(from), path);
+    let suffix = format!("/{}", path);
+    files
+        .iter()
+        .find(|f| f.file == exact)
+        .or_else(|| files.iter().find(|f| f.file.ends_with(&suffix)))
+}
+
+fn enclosing_function(node: TreeNode, code: &str) -> Option<(String, usize)> {
+    let mut parent = node.parent();
+    while let Some(p) = parent {
+        if p.kind() == "function_definition" {
+            let name = p.child_by_field_name("name")?;
+            let name = name.utf8_text(code.as_bytes()).ok()?.to_string();
+            return Some((name, p.start_position().row));
+        }
+        parent = p.parent();
+    }
+    None
+}
+
+fn unquote(text: &str) -> String {
+    text.trim_matches(|c| c == '"' || c == '\'').to_string()
+}
+
+// "$DIR/lib/common.sh" -> lib/common.sh, ./push.sh -> push.sh
+fn script_path(path: &str) -> String {
+    let mut path = path;
+    loop {
+        let trimmed = path.trim_start_matches("./");
+        let var_prefix = trimmed.strip_prefix('$').and_then(|rest| {
+            let end = match rest.chars().next()? {
+                '{' => rest.find('}')? + 1,
+                '(' => rest.rfind(')')? + 1,
+                _ => rest.find(|c: char| !c.is_alphanumeric() && c != '_')?,
+            };
+            rest[end..].strip_prefix('/')
+        });
+        match var_prefix {
+            Some(rest) => path = rest,
+            None => return trimmed.trim_start_matches('/').to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_links() {
+        let code = r#"#!/usr/bin/env bash
+DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+source "$DIR/lib/common.sh"
+. ./lib/env.sh
+
+push_image() {
+  "${DIR}/push.sh" "$1"
+  bash -x scripts/notify.sh done
+}
+"#;
+        let links = script_links(code);
+        assert_eq!(
+            links,
+            vec![
+                ScriptLink {
+                    kind: ScriptLinkKind::Source,
+                    path: "lib/common.sh".to_string(),
+                    caller: None
+                },
+                ScriptLink {
+                    kind: ScriptLinkKind::Source,
+                    path: "lib/env.sh".to_string(),
+                    caller: None
+                },
+                ScriptLink {
+                    kind: ScriptLinkKind::Run,
+                    path: "push.sh".to_string(),
+                    caller: Some(("push_image".to_string(), 5))
+                },
+                ScriptLink {
+                    kind: ScriptLinkKind::Run,
+                    path: "scripts/notify.sh".to_string(),
+                    caller: Some(("push_image".to_string(), 5))
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_runner_entries() {
+        let pkg = r#"{"scripts": {"deploy": "npm run build && ./scripts/deploy.sh prod", "lint": "eslint ."}}"#;
+        let scripts = package_scripts(pkg);
+        assert_eq!(scripts.len(), 2);
+        assert_eq!(command_scripts(&scripts[0].1), vec!["scripts/deploy.sh"]);
+        assert!(command_scripts(&scripts[1].1).is_empty());
+
+        let makefile = "IMAGE := app\n.PHONY: deploy release\n\ndeploy: build\n\tbash scripts/deploy.sh $(IMAGE)\n\nrelease: deploy\n\t@echo releasing\n\t./scripts/push.sh latest\n";
+        let targets = make_targets(makefile);
+        let names: Vec<&str> = targets.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["deploy", "release"]);
+        assert_eq!((targets[1].start, targets[1].end), (6, 8));
+        assert_eq!(command_scripts(&targets[1].recipe), vec!["scripts/push.sh"]);
+    }
+}
@@ -0,0 +1,162 @@
+use super::super::*;
+use super::consts::*;
+use shared::error::{Context, Result};
+use tree_sitter::{Language, Parser, Query, Tree};
+
+pub struct C(Language);
+
+impl C {
+    pub fn new() -> Self {
+        C(tree_sitter_c::LANGUAGE.into())
+    }
+}
+
+impl Stack for C {
+    fn q(&self, q: &str, _nt: &NodeType) -> Query {
+        Query::new(&self.0, q).unwrap()
+    }
+
+    fn parse(&self, code: &str, _nt: &NodeType) -> Result<Tree> {
+        let mut parser = Parser::new();
+        parser.set_language(&self.0)?;
+        parser.parse(code, None).context("Failed to parse code")
+    }
+
+    fn imports_query(&self) -> Option<String> {
+        // includes are often wrapped in header guards, so match them anywhere
+        Some(format!(
+            r#"
+            (preproc_include
+                path: (_) @{IMPORTS_FROM} @{IMPORTS_NAME}
+            ) @{IMPORTS}
+            "#
+        ))
+    }
+
+    fn variables_query(&self) -> Option<String> {
+        // only file scope declarations, function prototypes are skipped
+        Some(format!(
+            r#"
+            (translation_unit
+                (declaration
+                    type: (_) @{VARIABLE_TYPE}
+                    declarator: [
+                        (identifier) @{VARIABLE_NAME}
+                        (pointer_declarator
+                            declarator: (identifier) @{VARIABLE_NAME}
+                        )
+                        (array_declarator
+                            declarator: (identifier) @{VARIABLE_NAME}
+                        )
+                        (init_declarator
+                            declarator: [
+                                (identifier) @{VARIABLE_NAME}
+                                (pointer_declarator
+                                    declarator: (identifier) @{VARIABLE_NAME}
+                                )
+                                (array_declarator
+                                    declarator: (identifier) @{VARIABLE_NAME}
+                                )
+                            ]
+                            value: (_) @{VARIABLE_VALUE}
+                        )
+                    ]
+                ) @{VARIABLE_DECLARATION}
+            )
+            "#
+        ))
+    }
+
+    fn class_definition_query(&self) -> String {
+        format!(
+            r#"
+            [
+                (struct_specifier
+                    name: (type_identifier) @{CLASS_NAME}
+                    body: (field_declaration_list)
+                )
+                (union_specifier
+                    name: (type_identifier) @{CLASS_NAME}
+                    body: (field_declaration_list)
+                )
+            ] @{CLASS_DEFINITION}
+            "#
+        )
+    }
+
+    fn function_definition_query(&self) -> String {
+        format!(
+            r#"
+            (function_definition
+                type: (_) @{RETURN_TYPES}
+                declarator: [
+                    (function_declarator
+                        declarator: (identifier) @{FUNCTION_NAME}
+                        parameters: (parameter_list) @{ARGUMENTS}
+                    )
+                    (pointer_declarator
+                        declarator: (function_declarator
+                            declarator: (identifier) @{FUNCTION_NAME}
+                            parameters: (parameter_list) @{ARGUMENTS}
+                        )
+                    )
+                ]
+            ) @{FUNCTION_DEFINITION}
+            "#
+        )
+    }
+
+    fn comment_query(&self) -> Option<String> {
+        Some(format!(r#"(comment) @{FUNCTION_COMMENT}"#))
+    }
+
+    fn function_call_query(&self) -> String {
+        format!(
+            r#"
+            (call_expression
+                function: (identifier) @{FUNCTION_NAME}
+                arguments: (argument_list) @{ARGUMENTS}
+            ) @{FUNCTION_CALL}
+            "#
+        )
+    }
+
+    fn data_model_query(&self) -> Option<String> {
+        // named structs/unions, plus typedefs of anonymous struct/union/enum bodies
+        Some(format!(
+            r#"
+            [
+                (struct_specifier
+                    name: (type_identifier) @{STRUCT_NAME}
+                    body: (field_declaration_list)
+                )
+                (union_specifier
+                    name: (type_identifier) @{STRUCT_NAME}
+                    body: (field_declaration_list)
+                )
+                (type_definition
+                    type: [
+                        (struct_specifier
+                            !name
+                            body: (field_declaration_list)
+                        )
+                        (union_specifier
+                            !name
+                            body: (field_declaration_list)
+                        )
+                        (enum_specifier
+                            !name
+                            body: (enumerator_list)
+                        )
+                    ]
+                    declarator: (type_identifier) @{STRUCT_NAME}
+                )
+            ] @{STRUCT}
+            "#
+        ))
+    }
+
+    fn data_model_within_query(&self) -> Option<String> {
+        Some(format!(r#"(type_identifier) @{STRUCT_NAME}"#))
+    }
+}
@@ -40,11 +40,13 @@ pub const ENDPOINT_VERB: &str = "endpoint-verb";
 pub const ROUTE: &str = "route";
 pub const REQUEST_CALL: &str = "call";
 pub const ENDPOINT_GROUP: &str = "endpoint-group";
+pub const ENDPOINT_PREFIX: &str = "endpoint-prefix";
 
 pub const INTEGRATION_TEST: &str = "integration-test";
 pub const E2E_TEST: &str = "e2e-test";
 pub const E2E_TEST_NAME: &str = "e2e-test-name";
 pub const TEST_NAME: &str = "test-name";
+pub const TEST_CONTEXT: &str = "test-context";
 
 pub const STRUCT: &str = "struct";
 pub const STRUCT_NAME: &str = "struct-name";
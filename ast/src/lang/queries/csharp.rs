@@ -0,0 +1,348 @@
+use super::super::*;
+use super::consts::*;
+use shared::error::{Context, Result};
+use tree_sitter::{Language, Parser, Query, Tree};
+
+pub struct CSharp(Language);
+
+impl CSharp {
+    pub fn new() -> Self {
+        CSharp(tree_sitter_c_sharp::LANGUAGE.into())
+    }
+}
+
+impl Stack for CSharp {
+    fn q(&self, q: &str, _nt: &NodeType) -> Query {
+        Query::new(&self.0, q).unwrap()
+    }
+
+    fn parse(&self, code: &str, _nt: &NodeType) -> Result<Tree> {
+        let mut parser = Parser::new();
+        parser.set_language(&self.0)?;
+        parser.parse(code, None).context("failed to parse")
+    }
+
+    fn imports_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            (using_directive
+                [
+                    (identifier)
+                    (qualified_name)
+                ] @{IMPORTS_NAME} @{IMPORTS_FROM}
+            ) @{IMPORTS}
+            "#
+        ))
+    }
+
+    fn trait_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            (interface_declaration
+                name: (identifier) @{TRAIT_NAME}
+            ) @{TRAIT}
+            "#
+        ))
+    }
+
+    fn implements_query(&self) -> Option<String> {
+        // base_list mixes the base class and interfaces, only interfaces resolve to a Trait
+        Some(format!(
+            r#"
+            (class_declaration
+                name: (identifier) @{CLASS_NAME}
+                (base_list
+                    (identifier) @{TRAIT_NAME}
+                )
+            ) @{IMPLEMENTS}
+            "#
+        ))
+    }
+
+    fn class_definition_query(&self) -> String {
+        format!(
+            r#"
+            [
+                (class_declaration
+                    name: (identifier) @{CLASS_NAME}
+                    (base_list
+                        .
+                        (identifier) @{CLASS_PARENT}
+                    )?
+                )
+                (record_declaration
+                    name: (identifier) @{CLASS_NAME}
+                )
+                (struct_declaration
+                    name: (identifier) @{CLASS_NAME}
+                )
+            ] @{CLASS_DEFINITION}
+            "#
+        )
+    }
+
+    fn function_definition_query(&self) -> String {
+        // interface members have no body, so only implementations become functions
+        format!(
+            r#"
+            (_
+                name: (identifier) @{PARENT_TYPE}
+                body: (declaration_list
+                    (method_declaration
+                        returns: (_) @{RETURN_TYPES}
+                        name: (identifier) @{FUNCTION_NAME}
+                        parameters: (parameter_list) @{ARGUMENTS}
+                        body: [
+                            (block)
+                            (arrow_expression_clause)
+                        ]
+                    ) @{FUNCTION_DEFINITION}
+                )
+            )
+            "#
+        )
+    }
+
+    fn find_function_parent(
+        &self,
+        node: TreeNode,
+        _code: &str,
+        file: &str,
+        func_name: &str,
+        find_class: &dyn Fn(&str) -> Option<NodeData>,
+        parent_type: Option<&str>,
+    ) -> Result<Option<Operand>> {
+        let Some(parent_type) = parent_type else {
+            return Ok(None);
+        };
+        Ok(find_class(parent_type).map(|class| Operand {
+            source: NodeKeys::new(&class.name, &class.file, class.start),
+            target: NodeKeys::new(func_name, file, node.start_position().row),
+        }))
+    }
+
+    fn comment_query(&self) -> Option<String> {
+        Some(format!(r#"(comment) @{FUNCTION_COMMENT}"#))
+    }
+
+    fn function_call_query(&self) -> String {
+        format!(
+            r#"
+            (invocation_expression
+                function: [
+                    (identifier) @{FUNCTION_NAME}
+                    (generic_name
+                        (identifier) @{FUNCTION_NAME}
+                    )
+                    (member_access_expression
+                        expression: (_) @{OPERAND}
+                        name: [
+                            (identifier) @{FUNCTION_NAME}
+                            (generic_name
+                                (identifier) @{FUNCTION_NAME}
+                            )
+                        ]
+                    )
+                ]
+                arguments: (argument_list) @{ARGUMENTS}
+            ) @{FUNCTION_CALL}
+            "#
+        )
+    }
+
+    fn endpoint_finders(&self) -> Vec<String> {
+        let lambda_call = format!(
+            r#"(invocation_expression
+                function: [
+                    (identifier) @{HANDLER}
+                    (member_access_expression
+                        name: (identifier) @{HANDLER}
+                    )
+                ]
+            )"#
+        );
+        vec![
+            // attribute routing on controllers, the class [Route] is the prefix
+            format!(
+                r#"
+                (class_declaration
+                    (attribute_list
+                        (attribute
+                            name: (identifier) @route-attr (#eq? @route-attr "Route")
+                            (attribute_argument_list
+                                (attribute_argument
+                                    (string_literal) @{ENDPOINT_PREFIX}
+                                )
+                            )
+                        )
+                    )?
+                    name: (identifier) @{PARENT_NAME}
+                    body: (declaration_list
+                        (method_declaration
+                            (attribute_list
+                                (attribute
+                                    name: (identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^Http(Get|Post|Put|Delete|Patch)$")
+                                    (attribute_argument_list
+                                        (attribute_argument
+                                            (string_literal) @{ENDPOINT}
+                                        )
+                                    )?
+                                )
+                            )
+                            name: (identifier) @{HANDLER}
+                        ) @{ROUTE}
+                    )
+                )
+                "#
+            ),
+            // minimal APIs: app.MapGet("/path", Handler)
+            format!(
+                r#"
+                (invocation_expression
+                    function: (member_access_expression
+                        name: (identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^Map(Get|Post|Put|Delete|Patch)$")
+                    )
+                    arguments: (argument_list
+                        .
+                        (argument
+                            (string_literal) @{ENDPOINT}
+                        )
+                        .
+                        (argument
+                            [
+                                (identifier) @{HANDLER}
+                                (member_access_expression
+                                    name: (identifier) @{HANDLER}
+                                )
+                            ]
+                        )
+                    )
+                ) @{ROUTE}
+                "#
+            ),
+            // inline lambdas are handled by the call they make: app.MapGet("/path", () => Handle())
+            format!(
+                r#"
+                (invocation_expression
+                    function: (member_access_expression
+                        name: (identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^Map(Get|Post|Put|Delete|Patch)$")
+                    )
+                    arguments: (argument_list
+                        .
+                        (argument
+                            (string_literal) @{ENDPOINT}
+                        )
+                        .
+                        (argument
+                            (lambda_expression
+                                body: [
+                                    {lambda_call}
+                                    (block
+                                        (return_statement
+                                            {lambda_call}
+                                        )
+                                    )
+                                ]
+                            )
+                        )
+                    )
+                ) @{ROUTE}
+                "#
+            ),
+        ]
+    }
+
+    fn update_endpoint(&self, nd: &mut NodeData, _call: &Option<String>) {
+        if let Some(verb) = nd.meta.get("verb").cloned() {
+            let verb = verb
+                .trim_start_matches("HTTP")
+                .trim_start_matches("MAP")
+                .to_string();
+            nd.add_verb(&verb);
+        } else {
+            nd.add_verb("GET");
+        }
+        // expand the [controller] and [action] route tokens
+        if let Some(controller) = nd.meta.get("parent").cloned() {
+            let controller = controller.trim_end_matches("Controller");
+            nd.name = nd.name.replace("[controller]", controller);
+        }
+        if let Some(action) = nd.meta.get("handler").cloned() {
+            nd.name = nd.name.replace("[action]", &action);
+        }
+        if !nd.name.starts_with('/') {
+            nd.name = format!("/{}", nd.name);
+        }
+    }
+
+    fn handler_finder(
+        &self,
+        endpoint: NodeData,
+        find_fn: &dyn Fn(&str, &str) -> Option<NodeData>,
+        _find_fns_in: &dyn Fn(&str) -> Vec<NodeData>,
+        _handler_params: HandlerParams,
+    ) -> Vec<(NodeData, Option<Edge>)> {
+        if let Some(handler) = endpoint.meta.get("handler") {
+            if let Some(nd) = find_fn(handler, &endpoint.file) {
+                let edge = Edge::handler(&endpoint, &nd);
+                return vec![(endpoint, Some(edge))];
+            }
+        }
+        // a minimal API lambda may only call framework code, the endpoint still exists
+        vec![(endpoint, None)]
+    }
+
+    fn data_model_query(&self) -> Option<String> {
+        // EF Core entities are the T in DbSet<T> properties on a DbContext
+        Some(format!(
+            r#"
+            (property_declaration
+                type: (generic_name
+                    (identifier) @dbset (#eq? @dbset "DbSet")
+                    (type_argument_list
+                        (identifier) @{STRUCT_NAME}
+                    )
+                )
+            ) @{STRUCT}
+            "#
+        ))
+    }
+
+    fn resolve_data_model(
+        &self,
+        nd: NodeData,
+        find_class: &dyn Fn(&str) -> Option<NodeData>,
+    ) -> NodeData {
+        // the DbSet<T> property only names the entity, its class holds the fields
+        find_class(&nd.name).unwrap_or(nd)
+    }
+
+    fn data_model_within_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            [
+                (method_declaration
+                    returns: (identifier) @{STRUCT_NAME}
+                )
+                (parameter
+                    type: (identifier) @{STRUCT_NAME}
+                )
+                (type_argument_list
+                    (identifier) @{STRUCT_NAME}
+                )
+                (nullable_type
+                    type: (identifier) @{STRUCT_NAME}
+                )
+            ]
+            "#
+        ))
+    }
+
+    fn resolve_import_name(&self, import_name: &str) -> String {
+        import_name
+            .split('.')
+            .last()
+            .unwrap_or(import_name)
+            .to_string()
+    }
+}
@@ -1,8 +1,9 @@
 use super::super::*;
 use super::consts::*;
+use crate::lang::parse::{join_route, trim_quotes};
 use lsp::{Cmd as LspCmd, CmdSender, Position, Res as LspRes};
 use shared::error::{Context, Result};
-use tree_sitter::{Language, Parser, Query, Tree};
+use tree_sitter::{Language, Node as TreeNode, Parser, Query, Tree};
 
 pub struct Go(Language);
 
@@ -169,23 +170,131 @@ impl Stack for Go {
     //         vec![q1.to_string()]
     //     }
     fn endpoint_finders(&self) -> Vec<String> {
-        vec![format!(
-            r#"(call_expression
+        let handler = format!(
+            r#"[
+                        (selector_expression
+                            field: (field_identifier) @{HANDLER}
+                        )
+                        (identifier) @{HANDLER}
+                    ]"#
+        );
+        vec![
+            // chi and fiber (r.Get), gin and echo (r.GET)
+            format!(
+                r#"(call_expression
                 function: (selector_expression
                     operand: (identifier)
-                    field: (field_identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^Get$|^Post$|^Put$|^Delete$")
+                    field: (field_identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^(Get|Post|Put|Patch|Delete|GET|POST|PUT|PATCH|DELETE)$")
                 )
                 arguments: (argument_list
+                    .
                     (interpreted_string_literal) @{ENDPOINT}
+                    {handler}
+                    .
+                )
+            ) @{ROUTE}"#
+            ),
+            // gorilla/mux: r.HandleFunc("/items", h).Methods("POST", "PUT"), one match per method
+            format!(
+                r#"(call_expression
+                function: (selector_expression
+                    operand: (call_expression
+                        function: (selector_expression
+                            field: (field_identifier) @register (#match? @register "^(HandleFunc|Handle)$")
+                        )
+                        arguments: (argument_list
+                            .
+                            (interpreted_string_literal) @{ENDPOINT}
+                            .
+                            {handler}
+                            .
+                        )
+                    )
+                    field: (field_identifier) @methods (#eq? @methods "Methods")
+                )
+                arguments: (argument_list
                     [
+                        (interpreted_string_literal
+                            (interpreted_string_literal_content) @{ENDPOINT_VERB}
+                        )
                         (selector_expression
-                            field: (field_identifier) @{HANDLER}
+                            operand: (identifier) @http (#eq? @http "http")
+                            field: (field_identifier) @{ENDPOINT_VERB}
                         )
-                        (identifier) @{HANDLER}
                     ]
                 )
             ) @{ROUTE}"#
-        )]
+            ),
+            // net/http: mux.HandleFunc("GET /items/{id}", h), and "/items" for any method
+            format!(
+                r#"(call_expression
+                function: (selector_expression
+                    field: (field_identifier) @register (#match? @register "^(HandleFunc|Handle)$")
+                )
+                arguments: (argument_list
+                    .
+                    (interpreted_string_literal) @{ENDPOINT}
+                    .
+                    {handler}
+                    .
+                )
+            ) @{ROUTE}"#
+            ),
+        ]
+    }
+    fn is_route(&self, route: TreeNode, code: &str) -> bool {
+        let text = |n: TreeNode| n.utf8_text(code.as_bytes()).unwrap_or_default().to_string();
+        // gorilla's r.HandleFunc(...) is matched together with its .Methods(...)
+        let methods = route
+            .parent()
+            .filter(|p| p.kind() == "selector_expression")
+            .and_then(|p| p.child_by_field_name("field"));
+        if methods.map(text).as_deref() == Some("Methods") {
+            return false;
+        }
+        let Some((call, router)) = route_router(route) else {
+            return false;
+        };
+        match router.kind() {
+            // the default mux: http.HandleFunc("/x", h)
+            "identifier" if text(router) == "http" => call
+                .child_by_field_name("function")
+                .and_then(|f| f.child_by_field_name("field"))
+                .is_some_and(|f| matches!(text(f).as_str(), "HandleFunc" | "Handle")),
+            "identifier" => is_router(call, &text(router), code),
+            // s.router.Get(...): a struct field with a router type
+            "selector_expression" => router
+                .child_by_field_name("field")
+                .is_some_and(|f| has_router_field(route, &text(f), code)),
+            _ => false,
+        }
+    }
+    fn find_endpoint_prefix(&self, route: TreeNode, code: &str) -> Option<String> {
+        let (call, router) = route_router(route)?;
+        let name = router.utf8_text(code.as_bytes()).ok()?;
+        let prefix = router_prefix(call, name, code);
+        if prefix.is_empty() {
+            None
+        } else {
+            Some(prefix)
+        }
+    }
+    fn update_endpoint(&self, nd: &mut NodeData, _call: &Option<String>) {
+        // go 1.22 patterns carry the method: "GET /items/{id}"
+        let name = nd.name.clone();
+        if let Some((verb, path)) = name.split_once(' ') {
+            if matches!(verb, "GET" | "POST" | "PUT" | "PATCH" | "DELETE") {
+                nd.add_verb(verb);
+                nd.name = path.trim().to_string();
+            }
+        }
+        match nd.meta.get("verb").cloned() {
+            // .Methods(http.MethodPost)
+            Some(verb) if verb.starts_with("METHOD") => nd.add_verb(&verb["METHOD".len()..]),
+            Some(_) => {}
+            // a pattern without a method, or a mux route without .Methods, serves them all
+            None => nd.add_verb("ANY"),
+        }
     }
     fn endpoint_group_find(&self) -> Option<String> {
         Some(format!(
@@ -303,6 +412,254 @@ impl Stack for Go {
     }
 }
 
+const ROUTER_CONSTRUCTORS: [&str; 8] = [
+    "chi.NewRouter",
+    "chi.NewMux",
+    "gin.Default",
+    "gin.New",
+    "echo.New",
+    "fiber.New",
+    "mux.NewRouter",
+    "http.NewServeMux",
+];
+const ROUTER_TYPES: [&str; 13] = [
+    "chi.Router",
+    "chi.Mux",
+    "gin.Engine",
+    "gin.RouterGroup",
+    "gin.IRouter",
+    "gin.IRoutes",
+    "echo.Echo",
+    "echo.Group",
+    "fiber.App",
+    "fiber.Router",
+    "fiber.Group",
+    "mux.Router",
+    "http.ServeMux",
+];
+
+fn is_router_type(ty: &str) -> bool {
+    ROUTER_TYPES.contains(&ty.trim_start_matches('*'))
+}
+
+// the innermost call of a route registration, and the router it is made on
+fn route_router(route: TreeNode) -> Option<(TreeNode, TreeNode)> {
+    let mut call = route;
+    loop {
+        let operand = call
+            .child_by_field_name("function")?
+            .child_by_field_name("operand")?;
+        if operand.kind() == "call_expression" {
+            call = operand;
+        } else {
+            return Some((call, operand));
+        }
+    }
+}
+
+// whether name is a router where node uses it: made by a router constructor (or a
+// function of the file that returns a router), a group of another router, or a
+// parameter with a router type
+fn is_router(node: TreeNode, name: &str, code: &str) -> bool {
+    let text = |n: TreeNode| n.utf8_text(code.as_bytes()).unwrap_or_default().to_string();
+    let mut current = Some(node);
+    while let Some(cur) = current {
+        let mut sibling = cur.prev_named_sibling();
+        while let Some(s) = sibling {
+            if matches!(s.kind(), "short_var_declaration" | "assignment_statement") {
+                let left = s.child_by_field_name("left").and_then(|l| l.named_child(0));
+                if left.map(text).as_deref() == Some(name) {
+                    let right = s
+                        .child_by_field_name("right")
+                        .and_then(|r| r.named_child(0));
+                    return right.is_some_and(|r| makes_router(r, s, code));
+                }
+            }
+            sibling = s.prev_named_sibling();
+        }
+        if matches!(
+            cur.kind(),
+            "func_literal" | "function_declaration" | "method_declaration"
+        ) {
+            if let Some(ty) = param_type(cur, name, code) {
+                return is_router_type(&ty);
+            }
+        }
+        current = cur.parent();
+    }
+    false
+}
+
+fn makes_router(expr: TreeNode, decl: TreeNode, code: &str) -> bool {
+    if let Some((parent, _)) = group_call(expr, code) {
+        return is_router(decl, &parent, code);
+    }
+    let Some(function) = expr
+        .child_by_field_name("function")
+        .filter(|_| expr.kind() == "call_expression")
+    else {
+        return false;
+    };
+    let name = function.utf8_text(code.as_bytes()).unwrap_or_default();
+    if ROUTER_CONSTRUCTORS.contains(&name) {
+        return true;
+    }
+    // r := initChi(), with func initChi() *chi.Mux
+    function.kind() == "identifier"
+        && declarations(expr, "function_declaration").any(|f| {
+            f.child_by_field_name("name")
+                .is_some_and(|n| n.utf8_text(code.as_bytes()) == Ok(name))
+                && f.child_by_field_name("result").is_some_and(|r| {
+                    is_router_type(r.utf8_text(code.as_bytes()).unwrap_or_default())
+                })
+        })
+}
+
+// the type of a parameter of a function
+fn param_type(func: TreeNode, name: &str, code: &str) -> Option<String> {
+    let params = func.child_by_field_name("parameters")?;
+    let mut cursor = params.walk();
+    let found = params
+        .named_children(&mut cursor)
+        .filter(|p| p.kind() == "parameter_declaration")
+        .find(|p| {
+            let mut cursor = p.walk();
+            let found = p
+                .children_by_field_name("name", &mut cursor)
+                .any(|n| n.utf8_text(code.as_bytes()) == Ok(name));
+            found
+        })?;
+    let ty = found.child_by_field_name("type")?;
+    Some(ty.utf8_text(code.as_bytes()).ok()?.to_string())
+}
+
+// a struct field of the file named field, with a router type
+fn has_router_field(node: TreeNode, field: &str, code: &str) -> bool {
+    declarations(node, "field_declaration").any(|f| {
+        let mut cursor = f.walk();
+        let named = f
+            .children_by_field_name("name", &mut cursor)
+            .any(|n| n.utf8_text(code.as_bytes()) == Ok(field));
+        named
+            && f.child_by_field_name("type")
+                .is_some_and(|t| is_router_type(t.utf8_text(code.as_bytes()).unwrap_or_default()))
+    })
+}
+
+// the nodes of a kind in the file of node
+fn declarations<'a>(node: TreeNode<'a>, kind: &'static str) -> impl Iterator<Item = TreeNode<'a>> {
+    let mut root = node;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+    let mut found = Vec::new();
+    let mut stack = vec![root];
+    while let Some(n) = stack.pop() {
+        if n.kind() == kind {
+            found.push(n);
+        }
+        let mut cursor = n.walk();
+        stack.extend(n.named_children(&mut cursor));
+    }
+    found.into_iter()
+}
+
+// walks back from a route registration to the declaration of its router:
+//   v1 := r.Group("/v1")                    (gin, echo, fiber)
+//   s := r.PathPrefix("/v1").Subrouter()    (gorilla/mux)
+//   r.Route("/v1", func(r chi.Router) {...}) (chi)
+// nested groups are followed up to the root router
+fn router_prefix(node: TreeNode, name: &str, code: &str) -> String {
+    let text = |n: TreeNode| n.utf8_text(code.as_bytes()).unwrap_or_default().to_string();
+    let mut current = Some(node);
+    while let Some(cur) = current {
+        let mut sibling = cur.prev_named_sibling();
+        while let Some(s) = sibling {
+            if matches!(s.kind(), "short_var_declaration" | "assignment_statement") {
+                let left = s.child_by_field_name("left").and_then(|l| l.named_child(0));
+                if left.map(text).as_deref() == Some(name) {
+                    let right = s.child_by_field_name("right").and_then(|r| r.named_child(0));
+                    return match right.and_then(|r| group_call(r, code)) {
+                        Some((parent, path)) => {
+                            join_route(&router_prefix(s, &parent, code), &path)
+                        }
+                        None => String::new(),
+                    };
+                }
+            }
+            sibling = s.prev_named_sibling();
+        }
+        if cur.kind() == "func_literal" {
+            let param = cur
+                .child_by_field_name("parameters")
+                .and_then(|p| p.named_child(0))
+                .and_then(|p| p.child_by_field_name("name"));
+            if param.map(text).as_deref() == Some(name) {
+                let call = cur.parent().and_then(|args| args.parent());
+                return match call.and_then(|c| route_call(c, code)) {
+                    Some((c, parent, path)) => join_route(&router_prefix(c, &parent, code), &path),
+                    None => String::new(),
+                };
+            }
+        }
+        current = cur.parent();
+    }
+    String::new()
+}
+
+// r.Group("/v1") or r.PathPrefix("/v1").Subrouter() => ("r", "/v1")
+fn group_call(node: TreeNode, code: &str) -> Option<(String, String)> {
+    let (operand, field, args) = selector_call(node, code)?;
+    match field.as_str() {
+        "Group" => {
+            let path = first_string_arg(args, code)?;
+            Some((operand.utf8_text(code.as_bytes()).ok()?.to_string(), path))
+        }
+        "Subrouter" => {
+            let (operand, field, args) = selector_call(operand, code)?;
+            if field != "PathPrefix" {
+                return None;
+            }
+            let path = first_string_arg(args, code)?;
+            Some((operand.utf8_text(code.as_bytes()).ok()?.to_string(), path))
+        }
+        _ => None,
+    }
+}
+
+// chi: r.Route("/v1", func(r chi.Router) {...}) => prefixed, r.Group(func(r chi.Router) {...}) => not
+fn route_call<'a>(node: TreeNode<'a>, code: &str) -> Option<(TreeNode<'a>, String, String)> {
+    let (operand, field, args) = selector_call(node, code)?;
+    let parent = operand.utf8_text(code.as_bytes()).ok()?.to_string();
+    match field.as_str() {
+        "Route" => Some((node, parent, first_string_arg(args, code)?)),
+        "Group" => Some((node, parent, String::new())),
+        _ => None,
+    }
+}
+
+fn selector_call<'a>(node: TreeNode<'a>, code: &str) -> Option<(TreeNode<'a>, String, TreeNode<'a>)> {
+    if node.kind() != "call_expression" {
+        return None;
+    }
+    let function = node.child_by_field_name("function")?;
+    if function.kind() != "selector_expression" {
+        return None;
+    }
+    let operand = function.child_by_field_name("operand")?;
+    let field = function.child_by_field_name("field")?;
+    let args = node.child_by_field_name("arguments")?;
+    Some((operand, field.utf8_text(code.as_bytes()).ok()?.to_string(), args))
+}
+
+fn first_string_arg(args: TreeNode, code: &str) -> Option<String> {
+    let first = args.named_child(0)?;
+    if first.kind() != "interpreted_string_literal" {
+        return None;
+    }
+    Some(trim_quotes(first.utf8_text(code.as_bytes()).ok()?).to_string())
+}
+
 /*
 
 fn endpoint_finder(&self) -> Option<String> {
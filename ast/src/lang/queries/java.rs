@@ -1,7 +1,10 @@
 use super::super::*;
 use super::consts::*;
 use shared::error::{Context, Result};
-use tree_sitter::{Language, Parser, Query, Tree};
+use tree_sitter::{Language, Node as TreeNode, Parser, Query, Tree};
+
+// JUnit 4/5 test method annotations
+const TEST_ANNOTATIONS: &str = "Test|ParameterizedTest|RepeatedTest|TestFactory";
 
 pub struct Java(Language);
 
@@ -108,6 +111,31 @@ impl Stack for Java {
         )
     }
 
+    fn test_query(&self) -> Option<String> {
+        // the class annotations (@SpringBootTest, @WebMvcTest...) decide the kind of test
+        Some(format!(
+            r#"
+            (class_declaration
+                (modifiers)? @{TEST_CONTEXT}
+                body: (class_body
+                    (method_declaration
+                        (modifiers
+                            [
+                                (marker_annotation
+                                    name: (identifier) @test-annotation)
+                                (annotation
+                                    name: (identifier) @test-annotation)
+                            ]
+                        )
+                        name: (identifier) @{FUNCTION_NAME}
+                    ) @{FUNCTION_DEFINITION}
+                )
+                (#match? @test-annotation "^({TEST_ANNOTATIONS})$")
+            )
+            "#
+        ))
+    }
+
     fn comment_query(&self) -> Option<String> {
         Some(format!(r#" [
                     (line_comment)+
@@ -167,6 +195,59 @@ impl Stack for Java {
         ))
     }
 
+    fn request_finder(&self) -> Option<String> {
+        // MockMvc request builders and RestAssured calls:
+        // perform(get("/items/{id}", 1)), MockMvcRequestBuilders.post("/items"), given().when().post("/items")
+        // statically imported builders only count inside perform(), optionally followed by a few setters
+        let builder = format!(
+            r#"(method_invocation
+                    !object
+                    name: (identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^(get|post|put|patch|delete)$")
+                    arguments: (argument_list
+                        .
+                        (string_literal) @{ENDPOINT}
+                    )
+                ) @{ROUTE}"#
+        );
+        Some(format!(
+            r#"
+            (method_invocation
+                name: (identifier) @perform (#eq? @perform "perform")
+                arguments: (argument_list
+                    [
+                        {builder}
+                        (method_invocation
+                            object: {builder})
+                        (method_invocation
+                            object: (method_invocation
+                                object: {builder}))
+                    ]
+                )
+            )
+
+            (method_invocation
+                object: (identifier) @builder (#match? @builder "^(MockMvcRequestBuilders|RestAssured)$")
+                name: (identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^(get|post|put|patch|delete)$")
+                arguments: (argument_list
+                    .
+                    (string_literal) @{ENDPOINT}
+                )
+            ) @{ROUTE}
+
+            (method_invocation
+                object: (method_invocation
+                    name: (identifier) @chain (#match? @chain "^(given|when|with|and)$")
+                )
+                name: (identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^(get|post|put|patch|delete)$")
+                arguments: (argument_list
+                    .
+                    (string_literal) @{ENDPOINT}
+                )
+            ) @{ROUTE}
+            "#
+        ))
+    }
+
     fn update_endpoint(&self, nd: &mut NodeData, _call: &Option<String>) {
         if let Some(verb_annotation) = nd.meta.get("verb").cloned() {
             let http_verb = match verb_annotation.as_str() {
@@ -175,6 +256,8 @@ impl Stack for Java {
                 "PUTMAPPING" => "PUT",
                 "DELETEMAPPING" => "DELETE",
                 "PATCHMAPPING" => "PATCH",
+                // requests found in tests already carry the verb
+                "GET" | "POST" | "PUT" | "DELETE" | "PATCH" => verb_annotation.as_str(),
                 _ => "GET",
             };
 
@@ -225,6 +308,61 @@ impl Stack for Java {
             "#
         ))
     }
+    fn is_test_definition(&self, node: &TreeNode, code: &str) -> bool {
+        // @Test, @ParameterizedTest ... among the method's modifiers
+        let mut cursor = node.walk();
+        let Some(modifiers) = node
+            .named_children(&mut cursor)
+            .find(|c| c.kind() == "modifiers")
+        else {
+            return false;
+        };
+        let mut cursor = modifiers.walk();
+        let is_test = modifiers.named_children(&mut cursor).any(|a| {
+            matches!(a.kind(), "marker_annotation" | "annotation")
+                && a.child_by_field_name("name")
+                    .and_then(|n| n.utf8_text(code.as_bytes()).ok())
+                    .is_some_and(|name| TEST_ANNOTATIONS.split('|').any(|t| t == name))
+        });
+        is_test
+    }
+
+    fn links_tests_by_request(&self) -> bool {
+        true
+    }
+
+    fn is_test_file(&self, filename: &str) -> bool {
+        filename.contains("/src/test/")
+            || filename.ends_with("Test.java")
+            || filename.ends_with("Tests.java")
+            || filename.ends_with("IT.java")
+    }
+
+    fn is_test(&self, func_name: &str, func_file: &str) -> bool {
+        // JUnit 3 style tests have no annotation
+        self.is_test_file(func_file) && func_name.starts_with("test")
+    }
+
+    fn classify_test(&self, _name: &str, file: &str, body: &str) -> NodeType {
+        // failsafe runs *IT classes as integration tests
+        if file.ends_with("IT.java") {
+            return NodeType::IntegrationTest;
+        }
+        let integration_markers = [
+            "@SpringBootTest",
+            "@WebMvcTest",
+            "@Testcontainers",
+            "mockMvc.perform(",
+            "RestAssured",
+            "given()",
+        ];
+        if integration_markers.iter().any(|m| body.contains(m)) {
+            NodeType::IntegrationTest
+        } else {
+            NodeType::UnitTest
+        }
+    }
+
     fn resolve_import_name(&self, import_name: &str) -> String {
         let import_name = import_name.to_string();
         let name = import_name
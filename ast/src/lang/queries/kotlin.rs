@@ -1,6 +1,7 @@
 use super::super::*;
 use super::consts::*;
 use shared::error::{Context, Result};
+use std::collections::{BTreeMap, BTreeSet};
 use tree_sitter::{Language, Node as TreeNode, Parser, Query, Tree};
 
 pub struct Kotlin(Language);
@@ -204,6 +205,141 @@ impl Stack for Kotlin {
         ))
     }
 
+    fn endpoint_finders(&self) -> Vec<String> {
+        vec![
+            // ktor: get("/users/{id}") { ... } in a routing block or a Route extension
+            format!(
+                r#"
+            (call_expression
+                (simple_identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^(get|post|put|patch|delete|head|options)$")
+                (call_suffix
+                    (value_arguments
+                        .
+                        (value_argument
+                            (string_literal) @{ENDPOINT}
+                        )
+                    )?
+                    (annotated_lambda)
+                )
+            ) @{ROUTE}
+            "#
+            ),
+            // spring: @GetMapping("/{id}") fun getPerson(...) in a controller
+            format!(
+                r#"
+            (class_declaration
+                (modifiers
+                    (annotation) @controller (#match? @controller "^@(RestController|Controller)\\b")
+                )
+                (class_body
+                    (function_declaration
+                        (modifiers
+                            (annotation
+                                [
+                                    (user_type
+                                        (type_identifier) @{ENDPOINT_VERB}
+                                    )
+                                    (constructor_invocation
+                                        (user_type
+                                            (type_identifier) @{ENDPOINT_VERB}
+                                        )
+                                        (value_arguments
+                                            .
+                                            (value_argument
+                                                (string_literal) @{ENDPOINT}
+                                            )
+                                        )?
+                                    )
+                                ]
+                                (#match? @{ENDPOINT_VERB} "^(Get|Post|Put|Patch|Delete|Request)Mapping$")
+                            )
+                        )
+                        (simple_identifier) @{HANDLER}
+                    ) @{ROUTE}
+                )
+            )
+            "#
+            ),
+        ]
+    }
+
+    fn is_route(&self, route: TreeNode, code: &str) -> bool {
+        // ktor's verbs are plain functions, only routes in a routing block count
+        route.kind() != "call_expression" || in_routing(route, code)
+    }
+
+    fn find_endpoint_prefix(&self, route: TreeNode, code: &str) -> Option<String> {
+        let prefix = if route.kind() == "function_declaration" {
+            // the @RequestMapping of the controller class
+            let class = route.parent()?.parent()?;
+            annotations(class, code)
+                .into_iter()
+                .find(|(name, _)| name == "RequestMapping")
+                .and_then(|(_, a)| first_string(a, code))?
+        } else {
+            route_prefix(route, code)
+        };
+        (!prefix.is_empty()).then_some(prefix)
+    }
+
+    // routes in a Route extension function are served under the route blocks
+    // that call it, in any file: routing { route("/api") { userRoutes() } }
+    fn mount_endpoints(
+        &self,
+        endpoints: Vec<(NodeData, Option<Edge>)>,
+        files: &[(String, String)],
+    ) -> Vec<(NodeData, Option<Edge>)> {
+        let mounts = KtorMounts::new(self, files);
+        endpoints
+            .into_iter()
+            .flat_map(|(endpoint, edge)| mounts.mount(endpoint, edge))
+            .collect()
+    }
+
+    fn use_handler_finder(&self) -> bool {
+        true
+    }
+
+    fn handler_finder(
+        &self,
+        endpoint: NodeData,
+        find_fn: &dyn Fn(&str, &str) -> Option<NodeData>,
+        find_fns_in: &dyn Fn(&str) -> Vec<NodeData>,
+        _handler_params: HandlerParams,
+    ) -> Vec<(NodeData, Option<Edge>)> {
+        let handler = match endpoint.meta.get("handler") {
+            Some(handler) => find_fn(handler, &endpoint.file),
+            // a ktor route lambda is handled by the function declaring it
+            None => find_fns_in(&endpoint.file)
+                .into_iter()
+                .filter(|f| {
+                    f.file == endpoint.file && f.start <= endpoint.start && f.end >= endpoint.end
+                })
+                .min_by_key(|f| f.end - f.start),
+        };
+        let edge = handler.map(|h| Edge::handler(&endpoint, &h));
+        vec![(endpoint, edge)]
+    }
+
+    fn update_endpoint(&self, nd: &mut NodeData, _call: &Option<String>) {
+        if let Some(verb) = nd.meta.get("verb").cloned() {
+            if verb == "REQUESTMAPPING" {
+                // without a method, @RequestMapping serves them all
+                let annotation = nd
+                    .body
+                    .split_once("@RequestMapping")
+                    .and_then(|(_, rest)| rest.split(')').next())
+                    .unwrap_or_default();
+                nd.add_verb(request_method(annotation).unwrap_or("ANY"));
+            } else if let Some((_, v)) = SPRING_MAPPINGS.iter().find(|(m, _)| *m == verb) {
+                nd.add_verb(v);
+            }
+        }
+        if !nd.name.starts_with('/') {
+            nd.name = format!("/{}", nd.name);
+        }
+    }
+
     fn add_endpoint_verb(&self, inst: &mut NodeData, call: &Option<String>) {
         if inst.meta.get("verb").is_none() {
             if let Some(call) = call {
@@ -291,3 +427,265 @@ fn extract_path_from_url(url: &str) -> String {
 
     url.to_string()
 }
+
+const SPRING_MAPPINGS: [(&str, &str); 5] = [
+    ("GETMAPPING", "GET"),
+    ("POSTMAPPING", "POST"),
+    ("PUTMAPPING", "PUT"),
+    ("PATCHMAPPING", "PATCH"),
+    ("DELETEMAPPING", "DELETE"),
+];
+const MAX_MOUNT_DEPTH: usize = 10;
+
+// the Route extension functions, like fun Route.userRoutes() { ... }, and where they are called
+struct KtorMounts {
+    // name -> (file, start, end)
+    extensions: BTreeMap<String, (String, usize, usize)>,
+    // name -> (the extension calling it, if any, and the route prefix of the call)
+    calls: BTreeMap<String, Vec<(Option<String>, String)>>,
+}
+
+impl KtorMounts {
+    fn new(lang: &Kotlin, files: &[(String, String)]) -> Self {
+        let trees = files
+            .iter()
+            .filter(|(f, _)| f.ends_with(".kt"))
+            .filter_map(|(f, c)| {
+                let tree = lang.parse(c, &NodeType::Endpoint).ok()?;
+                Some((f.as_str(), c.as_str(), tree))
+            })
+            .collect::<Vec<_>>();
+        let mut extensions = BTreeMap::new();
+        for (file, code, tree) in &trees {
+            for func in descendants(tree.root_node(), "function_declaration") {
+                if let Some(name) = route_extension(func, code) {
+                    let span = (file.to_string(), func.start_position().row, func.end_position().row);
+                    extensions.insert(name, span);
+                }
+            }
+        }
+        let mut calls = BTreeMap::<String, Vec<_>>::new();
+        for (_, code, tree) in &trees {
+            for call in descendants(tree.root_node(), "call_expression") {
+                let Some((name, true)) = callee(call, code) else {
+                    continue;
+                };
+                if !extensions.contains_key(&name) || !in_routing(call, code) {
+                    continue;
+                }
+                let caller = enclosing_extension(call, code);
+                calls
+                    .entry(name)
+                    .or_default()
+                    .push((caller, route_prefix(call, code)));
+            }
+        }
+        KtorMounts { extensions, calls }
+    }
+
+    fn prefixes(&self, name: &str, depth: usize) -> BTreeSet<String> {
+        let Some(calls) = self.calls.get(name).filter(|_| depth < MAX_MOUNT_DEPTH) else {
+            // extensions that no routing block mounts serve their routes as they are
+            return BTreeSet::from([String::new()]);
+        };
+        calls
+            .iter()
+            .flat_map(|(caller, prefix)| {
+                let outer = match caller {
+                    Some(caller) => self.prefixes(caller, depth + 1),
+                    None => BTreeSet::from([String::new()]),
+                };
+                outer
+                    .into_iter()
+                    .map(|o| join_prefix(&o, prefix))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn mount(&self, endpoint: NodeData, edge: Option<Edge>) -> Vec<(NodeData, Option<Edge>)> {
+        let extension = self
+            .extensions
+            .iter()
+            .filter(|(_, (file, start, end))| {
+                file == &endpoint.file && *start <= endpoint.start && *end >= endpoint.end
+            })
+            .min_by_key(|(_, (_, start, end))| end - start);
+        let Some((name, _)) = extension else {
+            return vec![(endpoint, edge)];
+        };
+        let mut res = Vec::new();
+        for prefix in self.prefixes(name, 0) {
+            if prefix.is_empty() {
+                res.push((endpoint.clone(), edge.clone()));
+                continue;
+            }
+            let mut mounted = endpoint.clone();
+            mounted.name = parse::join_route(&prefix, &endpoint.name);
+            let edge = edge.clone().map(|mut e| {
+                e.source.node_data.name = mounted.name.clone();
+                e
+            });
+            res.push((mounted, edge));
+        }
+        res
+    }
+}
+
+// inside a routing { ... } block, or a Route extension function
+fn in_routing(node: TreeNode, code: &str) -> bool {
+    let mut parent = node.parent();
+    while let Some(p) = parent {
+        match p.kind() {
+            "call_expression"
+                if callee(p, code).is_some_and(|(name, _)| name == "routing")
+                    && call_lambda(p).is_some() =>
+            {
+                return true;
+            }
+            "function_declaration" => return route_extension(p, code).is_some(),
+            _ => (),
+        }
+        parent = p.parent();
+    }
+    false
+}
+
+fn enclosing_extension(node: TreeNode, code: &str) -> Option<String> {
+    let mut parent = node.parent();
+    while let Some(p) = parent {
+        if p.kind() == "function_declaration" {
+            return route_extension(p, code);
+        }
+        parent = p.parent();
+    }
+    None
+}
+
+// the paths of the route("/x") { ... } blocks around a call, outermost first
+fn route_prefix(node: TreeNode, code: &str) -> String {
+    let mut paths = Vec::new();
+    let mut parent = node.parent();
+    while let Some(p) = parent {
+        if p.kind() == "function_declaration" {
+            break;
+        }
+        if p.kind() == "call_expression"
+            && callee(p, code).is_some_and(|(name, plain)| name == "route" && plain)
+            && call_lambda(p).is_some()
+        {
+            paths.push(string_arg(p, code).unwrap_or_default());
+        }
+        parent = p.parent();
+    }
+    paths
+        .iter()
+        .rev()
+        .fold(String::new(), |prefix, path| join_prefix(&prefix, path))
+}
+
+fn join_prefix(prefix: &str, path: &str) -> String {
+    if path.is_empty() {
+        prefix.to_string()
+    } else {
+        parse::join_route(prefix, path)
+    }
+}
+
+fn text(node: TreeNode, code: &str) -> String {
+    node.utf8_text(code.as_bytes()).unwrap_or_default().to_string()
+}
+
+fn descendants<'a>(node: TreeNode<'a>, kind: &str) -> Vec<TreeNode<'a>> {
+    let mut res = Vec::new();
+    let mut stack = vec![node];
+    while let Some(n) = stack.pop() {
+        if n.kind() == kind {
+            res.push(n);
+        }
+        let mut cursor = n.walk();
+        let children = n.named_children(&mut cursor).collect::<Vec<_>>();
+        stack.extend(children.into_iter().rev());
+    }
+    res
+}
+
+fn named_child<'a>(node: TreeNode<'a>, kind: &str) -> Option<TreeNode<'a>> {
+    let mut cursor = node.walk();
+    let child = node.named_children(&mut cursor).find(|c| c.kind() == kind);
+    child
+}
+
+// the called name, and whether it is called without a receiver: get("/x") but not client.get("/x")
+fn callee(call: TreeNode, code: &str) -> Option<(String, bool)> {
+    let function = call.named_child(0)?;
+    match function.kind() {
+        "simple_identifier" => Some((text(function, code), true)),
+        "navigation_expression" => {
+            let suffix = function.named_child(function.named_child_count().checked_sub(1)?)?;
+            let name = named_child(suffix, "simple_identifier")?;
+            Some((text(name, code), false))
+        }
+        _ => None,
+    }
+}
+
+// the trailing lambda of a call: get("/x") { ... }
+fn call_lambda(call: TreeNode) -> Option<TreeNode> {
+    let suffix = named_child(call, "call_suffix")?;
+    let annotated = named_child(suffix, "annotated_lambda")?;
+    named_child(annotated, "lambda_literal")
+}
+
+// the first string argument of a call, unquoted
+fn string_arg(call: TreeNode, code: &str) -> Option<String> {
+    let suffix = named_child(call, "call_suffix")?;
+    let args = named_child(suffix, "value_arguments")?;
+    first_string(args, code)
+}
+
+fn first_string(node: TreeNode, code: &str) -> Option<String> {
+    let literal = descendants(node, "string_literal").into_iter().next()?;
+    Some(text(literal, code).trim_matches('"').to_string())
+}
+
+// userRoutes for fun Route.userRoutes() { ... }
+fn route_extension(func: TreeNode, code: &str) -> Option<String> {
+    let header = text(func, code);
+    let header = header.split('(').next()?;
+    let receiver = header.split_whitespace().find(|w| w.contains('.'))?;
+    let (receiver, name) = receiver.rsplit_once('.')?;
+    (receiver == "Route").then(|| name.to_string())
+}
+
+// the annotations on a class or function: (name, annotation)
+fn annotations<'a>(node: TreeNode<'a>, code: &str) -> Vec<(String, TreeNode<'a>)> {
+    let Some(modifiers) = named_child(node, "modifiers") else {
+        return Vec::new();
+    };
+    let mut cursor = modifiers.walk();
+    modifiers
+        .named_children(&mut cursor)
+        .filter(|a| a.kind() == "annotation")
+        .filter_map(|a| {
+            let t = text(a, code);
+            let name = t
+                .trim_start_matches('@')
+                .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+                .next()?
+                .rsplit('.')
+                .next()?
+                .to_string();
+            Some((name, a))
+        })
+        .collect()
+}
+
+// @RequestMapping("/x", method = [RequestMethod.POST])
+fn request_method(annotation: &str) -> Option<&'static str> {
+    let verbs = ["GET", "POST", "PUT", "PATCH", "DELETE"];
+    verbs
+        .iter()
+        .find(|v| annotation.contains(&format!("RequestMethod.{}", v)))
+        .copied()
+}
@@ -1,12 +1,15 @@
 pub mod angular;
 pub mod bash;
+pub mod c;
 pub mod consts;
 pub mod cpp;
+pub mod csharp;
 pub mod erb;
 pub mod go;
 pub mod haml;
 pub mod java;
 pub mod kotlin;
+pub mod php;
 pub mod python;
 mod rails_routes;
 pub mod react;
@@ -16,9 +19,10 @@ pub mod svelte;
 pub mod swift;
 pub mod toml;
 pub mod typescript;
+pub mod vue;
 
 use crate::lang::asg::Operand;
-use crate::lang::graphs::Edge;
+use crate::lang::graphs::{Edge, GraphEdit};
 use crate::lang::{Function, NodeData, NodeType};
 use lsp::Language as LspLanguage;
 use lsp::{CmdSender, Position};
@@ -65,6 +69,14 @@ pub trait Stack {
     fn is_component(&self, _func_name: &str) -> bool {
         false
     }
+    // single-file components (like .vue files) have no name node of their own
+    fn component_name_from_file(&self, _file: &str) -> Option<String> {
+        None
+    }
+    // drop function matches left without a name, instead of keeping them unnamed
+    fn skip_unnamed_functions(&self) -> bool {
+        false
+    }
     // hack for now: imports are all concatenated into one section
     // so must be ONLY at the beginning of the file, with no other elements
     // only empty lines will be added between imports
@@ -109,6 +121,16 @@ pub trait Stack {
     fn data_model_query(&self) -> Option<String> {
         None
     }
+    // metadata read from the data model's own body, like its fields
+    fn update_data_model(&self, _nd: &mut NodeData) {}
+    // the definition a data model match refers to, when the match is only a reference to it
+    fn resolve_data_model(
+        &self,
+        nd: NodeData,
+        _find_class: &dyn Fn(&str) -> Option<NodeData>,
+    ) -> NodeData {
+        nd
+    }
     // data model CONTAINS edge within a function
     fn data_model_within_query(&self) -> Option<String> {
         None
@@ -126,6 +148,8 @@ pub trait Stack {
     ) -> Vec<Edge> {
         Vec::new()
     }
+    // models a function uses without building them, like the ones in its type hints
+    fn add_annotation_models(&self, _graph: &mut dyn GraphEdit, _funcs: &[Function]) {}
     fn data_model_name(&self, dm_name: &str) -> String {
         dm_name.to_string()
     }
@@ -165,21 +189,55 @@ pub trait Stack {
     ) -> Result<Vec<HandlerItem>> {
         Ok(Vec::new())
     }
+    // router prefixes that are not part of the endpoint query match,
+    // like a group variable declared earlier in the same function
+    fn find_endpoint_prefix(&self, _route: TreeNode, _code: &str) -> Option<String> {
+        None
+    }
+    // false for a call an endpoint finder matches that does not register a route,
+    // like a Get on an http client
+    fn is_route(&self, _route: TreeNode, _code: &str) -> bool {
+        true
+    }
     fn endpoint_group_find(&self) -> Option<String> {
         None
     }
+    // prefixes that routers mounted in other files put on the endpoints
+    fn mount_endpoints(
+        &self,
+        endpoints: Vec<(NodeData, Option<Edge>)>,
+        _files: &[(String, String)],
+    ) -> Vec<(NodeData, Option<Edge>)> {
+        endpoints
+    }
+    // endpoints the endpoint finders can't see, returns how many were added
+    fn add_extra_endpoints(&self, _graph: &mut dyn GraphEdit, _files: &[(String, String)]) -> usize {
+        0
+    }
     fn endpoint_path_filter(&self) -> Option<String> {
         None
     }
     fn request_finder(&self) -> Option<String> {
         None
     }
+    // integration tests were linked to the endpoints they request, so skip matching them by name
+    fn links_tests_by_request(&self) -> bool {
+        false
+    }
     fn is_test(&self, _func_name: &str, _func_file: &str) -> bool {
         false
     }
     fn is_test_file(&self, _filename: &str) -> bool {
         false
     }
+    // definitions marked as tests (#[test], @Test...) that the test query collects instead
+    fn is_test_definition(&self, _node: &TreeNode, _code: &str) -> bool {
+        false
+    }
+    // tests that live next to the code they test, like rust's #[cfg(test)] modules
+    fn has_inline_tests(&self, _code: &str) -> bool {
+        false
+    }
     fn classify_test(&self, _name: &str, _file: &str, _body: &str) -> NodeType { NodeType::UnitTest }
     fn add_endpoint_verb(&self, _nd: &mut NodeData, _call: &Option<String>) {}
     fn update_endpoint(&self, _nd: &mut NodeData, _call: &Option<String>) {}
@@ -205,6 +263,10 @@ pub trait Stack {
     fn use_handler_finder(&self) -> bool {
         false
     }
+    // without lsp, resolve a call on an instance (like an injected service) through the instance's type
+    fn use_operand_finder(&self) -> bool {
+        false
+    }
     fn handler_finder(
         &self,
         endpoint: NodeData,
@@ -277,10 +339,22 @@ pub trait Stack {
     ) -> Option<(NodeData, Option<Edge>)> {
         None
     }
+    // pages from route configs, returns how many were added
+    fn add_extra_pages(&self, _graph: &mut dyn GraphEdit, _files: &[(String, String)]) -> usize {
+        0
+    }
+    // Job nodes, linked to their handlers and to the functions that enqueue them
+    fn add_jobs(&self, _graph: &mut dyn GraphEdit, _files: &[(String, String)], _root: &str) {}
+    // scripts that source or run each other, and the script entries that run them
+    fn add_script_links(&self, _graph: &mut dyn GraphEdit, _files: &[(String, String)]) {}
     fn clean_graph(&self, _callback: &mut dyn FnMut(NodeType, NodeType, &str)) {}
     fn direct_class_calls(&self) -> bool {
         false
     }
+    // calls that another pass links, like job enqueues to the job's handler
+    fn skip_call(&self, _called: &str, _operand: Option<&str>) -> bool {
+        false
+    }
     fn convert_association_to_name(&self, name: &str) -> String {
         name.to_string()
     }
@@ -318,6 +392,10 @@ pub fn treesitter_from_lsp_language(ll: LspLanguage) -> tree_sitter::Language {
         LspLanguage::Svelte => tree_sitter_svelte_ng::LANGUAGE.into(),
         LspLanguage::Angular => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
         LspLanguage::Cpp => tree_sitter_cpp::LANGUAGE.into(),
+        LspLanguage::C => tree_sitter_c::LANGUAGE.into(),
+        LspLanguage::CSharp => tree_sitter_c_sharp::LANGUAGE.into(),
+        LspLanguage::Php => tree_sitter_php::LANGUAGE_PHP.into(),
+        LspLanguage::Vue => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
         // _ => tree_sitter_bash::LANGUAGE.into(),
     }
 }
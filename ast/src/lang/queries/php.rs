@@ -0,0 +1,354 @@
+use super::super::*;
+use super::consts::*;
+use crate::lang::parse::trim_quotes;
+use shared::error::{Context, Result};
+use tree_sitter::{Language, Parser, Query, Tree};
+
+pub struct Php(Language);
+
+impl Php {
+    pub fn new() -> Self {
+        Php(tree_sitter_php::LANGUAGE_PHP.into())
+    }
+}
+
+impl Stack for Php {
+    fn q(&self, q: &str, nt: &NodeType) -> Query {
+        if matches!(nt, NodeType::Library) {
+            Query::new(&tree_sitter_json::LANGUAGE.into(), q).unwrap()
+        } else {
+            Query::new(&self.0, q).unwrap()
+        }
+    }
+
+    fn parse(&self, code: &str, nt: &NodeType) -> Result<Tree> {
+        let mut parser = Parser::new();
+        if matches!(nt, NodeType::Library) {
+            parser.set_language(&tree_sitter_json::LANGUAGE.into())?;
+        } else {
+            parser.set_language(&self.0)?;
+        }
+        parser.parse(code, None).context("failed to parse")
+    }
+
+    fn identifier_query(&self) -> String {
+        format!("(name) @identifier")
+    }
+
+    fn lib_query(&self) -> Option<String> {
+        // composer.json, skipping platform requirements like "php" or "ext-json"
+        Some(format!(
+            r#"
+            (pair
+                key: (string (string_content) @section (#match? @section "^require(-dev)?$"))
+                value: (object
+                    (pair
+                        key: (string (string_content) @{LIBRARY_NAME}) (#match? @{LIBRARY_NAME} "/")
+                        value: (string (string_content) @{LIBRARY_VERSION})
+                    ) @{LIBRARY}
+                )
+            )
+            "#
+        ))
+    }
+
+    fn imports_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            (namespace_use_declaration
+                (namespace_use_clause
+                    .
+                    [
+                        (name)
+                        (qualified_name)
+                    ] @{IMPORTS_NAME} @{IMPORTS_FROM}
+                )
+            ) @{IMPORTS}
+            "#
+        ))
+    }
+
+    fn variables_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            (program
+                (const_declaration
+                    (const_element
+                        (name) @{VARIABLE_NAME}
+                        (_) @{VARIABLE_VALUE}
+                    )
+                ) @{VARIABLE_DECLARATION}
+            )
+            "#
+        ))
+    }
+
+    fn trait_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            [
+                (trait_declaration
+                    name: (name) @{TRAIT_NAME}
+                )
+                (interface_declaration
+                    name: (name) @{TRAIT_NAME}
+                )
+            ] @{TRAIT}
+            "#
+        ))
+    }
+
+    fn implements_query(&self) -> Option<String> {
+        // both `use SomeTrait;` inside the class body and `implements SomeInterface`
+        Some(format!(
+            r#"
+            [
+                (class_declaration
+                    name: (name) @{CLASS_NAME}
+                    body: (declaration_list
+                        (use_declaration
+                            (name) @{TRAIT_NAME}
+                        )
+                    )
+                )
+                (class_declaration
+                    name: (name) @{CLASS_NAME}
+                    (class_interface_clause
+                        (name) @{TRAIT_NAME}
+                    )
+                )
+            ] @{IMPLEMENTS}
+            "#
+        ))
+    }
+
+    fn class_definition_query(&self) -> String {
+        format!(
+            r#"
+            (class_declaration
+                name: (name) @{CLASS_NAME}
+                (base_clause
+                    (name) @{CLASS_PARENT}
+                )?
+            ) @{CLASS_DEFINITION}
+            "#
+        )
+    }
+
+    fn function_definition_query(&self) -> String {
+        // abstract and interface methods have no body, so they are skipped
+        format!(
+            r#"
+            [
+                (_
+                    name: (name) @{PARENT_TYPE}
+                    body: (declaration_list
+                        (method_declaration
+                            name: (name) @{FUNCTION_NAME}
+                            parameters: (formal_parameters) @{ARGUMENTS}
+                            return_type: (_)? @{RETURN_TYPES}
+                            body: (compound_statement)
+                        ) @{FUNCTION_DEFINITION}
+                    )
+                )
+                (function_definition
+                    name: (name) @{FUNCTION_NAME}
+                    parameters: (formal_parameters) @{ARGUMENTS}
+                    return_type: (_)? @{RETURN_TYPES}
+                ) @{FUNCTION_DEFINITION}
+            ]
+            "#
+        )
+    }
+
+    fn find_function_parent(
+        &self,
+        node: TreeNode,
+        _code: &str,
+        file: &str,
+        func_name: &str,
+        find_class: &dyn Fn(&str) -> Option<NodeData>,
+        parent_type: Option<&str>,
+    ) -> Result<Option<Operand>> {
+        let Some(parent_type) = parent_type else {
+            return Ok(None);
+        };
+        Ok(find_class(parent_type).map(|class| Operand {
+            source: NodeKeys::new(&class.name, &class.file, class.start),
+            target: NodeKeys::new(func_name, file, node.start_position().row),
+        }))
+    }
+
+    fn comment_query(&self) -> Option<String> {
+        Some(format!(r#"(comment) @{FUNCTION_COMMENT}"#))
+    }
+
+    fn function_call_query(&self) -> String {
+        format!(
+            r#"
+            [
+                (function_call_expression
+                    function: (name) @{FUNCTION_NAME}
+                    arguments: (arguments) @{ARGUMENTS}
+                )
+                (member_call_expression
+                    object: (_) @{OPERAND}
+                    name: (name) @{FUNCTION_NAME}
+                    arguments: (arguments) @{ARGUMENTS}
+                )
+                (nullsafe_member_call_expression
+                    object: (_) @{OPERAND}
+                    name: (name) @{FUNCTION_NAME}
+                    arguments: (arguments) @{ARGUMENTS}
+                )
+                (scoped_call_expression
+                    scope: (_) @{OPERAND}
+                    name: (name) @{FUNCTION_NAME}
+                    arguments: (arguments) @{ARGUMENTS}
+                )
+            ] @{FUNCTION_CALL}
+            "#
+        )
+    }
+
+    fn endpoint_finders(&self) -> Vec<String> {
+        // Route::get('/path', [Controller::class, 'method']), 'Controller@method' or a closure
+        vec![format!(
+            r#"
+            (scoped_call_expression
+                scope: (name) @facade (#eq? @facade "Route")
+                name: (name) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^(get|post|put|patch|delete|options|any)$")
+                arguments: (arguments
+                    .
+                    (argument
+                        (string) @{ENDPOINT}
+                    )
+                    .
+                    (argument
+                        [
+                            (array_creation_expression
+                                .
+                                (array_element_initializer
+                                    (class_constant_access_expression
+                                        .
+                                        (name) @{PARENT_NAME}
+                                    )
+                                )
+                                .
+                                (array_element_initializer
+                                    (string) @{HANDLER}
+                                )
+                            )
+                            (string) @{HANDLER}
+                            (anonymous_function)
+                            (arrow_function)
+                        ]
+                    )
+                )
+            ) @{ROUTE}
+            "#
+        )]
+    }
+
+    fn update_endpoint(&self, nd: &mut NodeData, _call: &Option<String>) {
+        // legacy 'Controller@method' handlers
+        if let Some(handler) = nd.meta.get("handler").cloned() {
+            if let Some((controller, method)) = handler.split_once('@') {
+                nd.add_parent(controller);
+                nd.add_handler(method);
+            }
+        }
+        if !nd.name.starts_with('/') {
+            nd.name = format!("/{}", nd.name);
+        }
+        // closures have no name of their own, so they are named after the route
+        if nd.meta.get("handler").is_none() {
+            let verb = nd.meta.get("verb").cloned().unwrap_or_default();
+            let handler = format!("{} {}", verb, nd.name);
+            nd.add_handler(&handler);
+        }
+    }
+
+    fn use_handler_finder(&self) -> bool {
+        true
+    }
+
+    fn handler_finder(
+        &self,
+        endpoint: NodeData,
+        find_fn: &dyn Fn(&str, &str) -> Option<NodeData>,
+        _find_fns_in: &dyn Fn(&str) -> Vec<NodeData>,
+        _handler_params: HandlerParams,
+    ) -> Vec<(NodeData, Option<Edge>)> {
+        if let Some(handler) = endpoint.meta.get("handler") {
+            // controllers follow PSR-4, so the class name is the file name
+            let suffix = match endpoint.meta.get("parent") {
+                Some(controller) => {
+                    let controller = controller.rsplit('\\').next().unwrap_or(controller);
+                    format!("{}.php", controller)
+                }
+                None => endpoint.file.clone(),
+            };
+            if let Some(nd) = find_fn(trim_quotes(handler), &suffix) {
+                let edge = Edge::handler(&endpoint, &nd);
+                return vec![(endpoint, Some(edge))];
+            }
+        }
+        vec![(endpoint, None)]
+    }
+
+    fn data_model_query(&self) -> Option<String> {
+        // Eloquent models
+        Some(format!(
+            r#"
+            (class_declaration
+                name: (name) @{STRUCT_NAME}
+                (base_clause
+                    (name) @base (#match? @base "^(Model|Authenticatable|Pivot)$")
+                )
+            ) @{STRUCT}
+            "#
+        ))
+    }
+
+    fn data_model_within_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            [
+                (named_type
+                    (name) @{STRUCT_NAME}
+                )
+                (object_creation_expression
+                    (name) @{STRUCT_NAME}
+                )
+                (scoped_call_expression
+                    scope: (name) @{STRUCT_NAME}
+                )
+                (class_constant_access_expression
+                    .
+                    (name) @{STRUCT_NAME}
+                )
+            ]
+            "#
+        ))
+    }
+
+    fn resolve_import_name(&self, import_name: &str) -> String {
+        import_name
+            .rsplit('\\')
+            .next()
+            .unwrap_or(import_name)
+            .to_string()
+    }
+
+    fn resolve_import_path(&self, import_path: &str, _current_file: &str) -> String {
+        // App\Models\Post => app/Models (the default composer psr-4 mapping)
+        let mut parts: Vec<&str> = import_path.trim_start_matches('\\').split('\\').collect();
+        parts.pop();
+        let path = parts.join("/");
+        match path.strip_prefix("App") {
+            Some(rest) => format!("app{}", rest),
+            None => path,
+        }
+    }
+}
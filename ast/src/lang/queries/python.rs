@@ -1,13 +1,17 @@
 use super::super::*;
 use super::consts::*;
+use crate::lang::jobs;
 use shared::error::{Context, Result};
-use tree_sitter::{Language, Node as TreeNode, Parser, Query, Tree};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use tree_sitter::{Language, Node as TreeNode, Parser, Query, QueryCursor, Tree};
 
-pub struct Python(Language);
+// the route table mount_endpoints builds, kept for add_extra_endpoints
+pub struct Python(Language, Mutex<Option<PythonRoutes>>);
 
 impl Python {
     pub fn new() -> Self {
-        Python(tree_sitter_python::LANGUAGE.into())
+        Python(tree_sitter_python::LANGUAGE.into(), Mutex::new(None))
     }
 }
 
@@ -198,10 +202,11 @@ impl Stack for Python {
                                 (string) @{ENDPOINT}
                                 .
                                 [
+                                    ;; views.stats, resolved in views.py by the handler finder
                                     (attribute
                                         (identifier)
-                                        (identifier) @{HANDLER}
-                                    )
+                                        (identifier)
+                                    ) @{HANDLER}
                                     (identifier) @{HANDLER}
                                 ]
                                 .
@@ -217,6 +222,15 @@ impl Stack for Python {
         ]
     }
 
+    fn find_endpoint_prefix(&self, route: TreeNode, code: &str) -> Option<String> {
+        // @router.get("/x") on a router = APIRouter(prefix="/users") (or a flask Blueprint)
+        let router = decorator_router(route, code)?;
+        let mut root = route;
+        while let Some(parent) = root.parent() {
+            root = parent;
+        }
+        router_prefix(root, &router, code).filter(|p| !p.is_empty())
+    }
     fn add_endpoint_verb(&self, nd: &mut NodeData, call: &Option<String>) {
         if nd.meta.get("verb").is_some() {
             return;
@@ -255,15 +269,34 @@ impl Stack for Python {
             nd.add_verb("GET");
         }
     }
-    // Now we need to exclude class definitinos that have methods in their body
+    // SQLAlchemy declarative and Django models, pydantic schemas, and dataclass/attrs classes
     fn data_model_query(&self) -> Option<String> {
         Some(format!(
-            "(class_definition
-                name: (identifier) @{STRUCT_NAME}
-             ) @{STRUCT}"
+            r#"[
+                (class_definition
+                    name: (identifier) @{STRUCT_NAME}
+                    superclasses: (argument_list) @bases (#match? @bases "\\b(Base|DeclarativeBase|SQLModel|BaseModel|Model)\\b")
+                ) @{STRUCT}
+                (decorated_definition
+                    (decorator) @decorator (#match? @decorator "^@((dataclasses\\.)?dataclass|(attr|attrs)\\.(s|define|frozen|mutable)|define|frozen)\\b")
+                    definition: (class_definition
+                        name: (identifier) @{STRUCT_NAME}
+                    )
+                ) @{STRUCT}
+            ]"#
         ))
     }
 
+    fn update_data_model(&self, nd: &mut NodeData) {
+        if let Some(kind) = model_kind(&nd.body) {
+            nd.meta.insert("model".to_string(), kind.to_string());
+        }
+        let fields = model_fields(&nd.body);
+        if !fields.is_empty() {
+            nd.meta.insert("fields".to_string(), fields.join(";"));
+        }
+    }
+
     fn data_model_within_query(&self) -> Option<String> {
         Some(format!(
             r#"[
@@ -287,14 +320,19 @@ impl Stack for Python {
         ))
     }
 
-    // A trait in python is an ABC class that inherits from ABC
+    // A trait in python is an ABC class that inherits from ABC, or a typing Protocol
     fn trait_query(&self) -> Option<String> {
         Some(format!(
             r#"
             (class_definition
                 name: (identifier)@{TRAIT_NAME}
                 superclasses: (argument_list
-                    (identifier) @parent (#eq? @parent "ABC")
+                    [
+                        (identifier) @parent
+                        (attribute
+                            attribute: (identifier) @parent
+                        )
+                    ] (#match? @parent "^(ABC|Protocol)$")
                 )
             )@{TRAIT}
             "#
@@ -366,4 +404,1255 @@ impl Stack for Python {
     fn use_handler_finder(&self) -> bool {
         true
     }
+
+    // python functions use the models named in their parameter and return type hints
+    fn add_annotation_models(&self, graph: &mut dyn GraphEdit, funcs: &[Function]) {
+        let mut bindings: HashMap<String, HashMap<String, String>> = HashMap::new();
+        for (func, _, _, models_within, _, return_types) in funcs {
+            // skip the models the function already contains, whatever the graph backend
+            let mut contained: HashSet<(String, String)> = models_within
+                .iter()
+                .chain(return_types)
+                .filter(|e| e.target.node_type == NodeType::DataModel)
+                .map(|e| (e.target.node_data.name.clone(), e.target.node_data.file.clone()))
+                .collect();
+            let imported = bindings
+                .entry(func.file.clone())
+                .or_insert_with(|| file_import_bindings(graph, &func.file));
+            for name in annotation_types(&func.body) {
+                let models = graph.find_nodes_by_name(NodeType::DataModel, &name);
+                // the model in the function's own file, or the one it imports
+                let model = models.iter().find(|m| m.file == func.file).or_else(|| {
+                    let (module, _) = imported.get(&name)?.rsplit_once('/')?;
+                    models.iter().find(|m| is_module_file(&m.file, module))
+                });
+                let Some(model) = model else {
+                    continue;
+                };
+                if !contained.insert((model.name.clone(), model.file.clone())) {
+                    continue;
+                }
+                graph.add_edge(Edge::contains(NodeType::Function, func, NodeType::DataModel, model));
+            }
+        }
+    }
+
+    fn mount_endpoints(
+        &self,
+        endpoints: Vec<(NodeData, Option<Edge>)>,
+        files: &[(String, String)],
+    ) -> Vec<(NodeData, Option<Edge>)> {
+        let routes = PythonRoutes::new(files);
+        let mounted = endpoints
+            .into_iter()
+            .flat_map(|endpoint| {
+                let file = endpoint.0.file.clone();
+                routes.mount(&file, vec![endpoint])
+            })
+            .collect();
+        *self.1.lock().unwrap() = Some(routes);
+        mounted
+    }
+
+    fn add_jobs(&self, graph: &mut dyn GraphEdit, files: &[(String, String)], root: &str) {
+        let files = files
+            .iter()
+            .filter(|(f, _)| f.ends_with(".py"))
+            .collect::<Vec<_>>();
+        let mut found = Vec::new();
+        let mut schedules = Vec::new();
+        for (filename, code) in &files {
+            found.extend(jobs::python_jobs(code, filename, root));
+            schedules.extend(jobs::python_schedules(code, filename));
+        }
+        jobs::add_jobs(graph, &files, root, found, schedules, jobs::python_enqueues);
+    }
+    // django class based views, routed with as_view() or a DRF router. Their
+    // handlers are the methods of the view class (or its DRF bases), so they
+    // need the class nodes, which the endpoint finders don't see
+    fn add_extra_endpoints(&self, graph: &mut dyn GraphEdit, files: &[(String, String)]) -> usize {
+        let routes = self
+            .1
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(|| PythonRoutes::new(files));
+        let mut endpoints = Vec::new();
+        for view in &routes.views {
+            let Some(class) = graph
+                .find_nodes_by_name(NodeType::Class, &view.class)
+                .into_iter()
+                .next()
+            else {
+                continue;
+            };
+            let methods: Vec<NodeData> = graph
+                .find_nodes_by_file_ends_with(NodeType::Function, &class.file)
+                .into_iter()
+                .filter(|f| f.file == class.file && f.start > class.start && f.end <= class.end)
+                .collect();
+            let code = files
+                .iter()
+                .find(|(f, _)| f == &class.file)
+                .map(|(_, c)| c.as_str())
+                .unwrap_or_default();
+            endpoints.extend(routes.view_endpoints(view, &class, &methods, code));
+        }
+        let count = endpoints.len();
+        graph.add_endpoints(endpoints);
+        count
+    }
+}
+
+const URLPATTERNS: &str = "urlpatterns";
+const ROUTER_CLASSES: [&str; 4] = ["APIRouter", "Blueprint", "DefaultRouter", "SimpleRouter"];
+const INCLUDE_CALLS: [&str; 2] = ["include_router", "register_blueprint"];
+const VIEW_METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+// the routes a DRF router generates for a viewset: (action, verb, detail)
+const VIEWSET_ACTIONS: [(&str, &str, bool); 6] = [
+    ("list", "GET", false),
+    ("create", "POST", false),
+    ("retrieve", "GET", true),
+    ("update", "PUT", true),
+    ("partial_update", "PATCH", true),
+    ("destroy", "DELETE", true),
+];
+const MAX_MOUNT_DEPTH: usize = 10;
+// capitalized annotation types that are never the project's models: typing
+// and stdlib types, and the framework's request and session types
+const NOT_MODEL_TYPES: [&str; 34] = [
+    "Any", "Annotated", "AsyncIterator", "Awaitable", "Callable", "ClassVar", "Coroutine",
+    "Dict", "Final", "FrozenSet", "Generator", "Iterable", "Iterator", "List", "Literal",
+    "Mapping", "Optional", "Self", "Sequence", "Set", "Tuple", "Type", "Union",
+    "Decimal", "Enum", "Path", "UUID", "AsyncSession", "Session", "Request", "Response",
+    "HTTPException", "BackgroundTasks", "Depends",
+];
+// the calls that mount routers, register viewsets and route views
+const ROUTE_CALLS_QUERY: &str = r#"
+(call
+    function: [
+        (identifier) @name
+        (attribute attribute: (identifier) @name)
+    ]
+    (#match? @name "^(include_router|register_blueprint|register|path|re_path|url)$")
+) @call
+"#;
+
+// a router (or a django urls module) included into another one under a prefix
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RouteMount {
+    // (file, router variable)
+    pub parent: (String, String),
+    pub child: (String, String),
+    pub prefix: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViewKind {
+    // router.register('users', UserViewSet)
+    ViewSet,
+    // path('users/', UserView.as_view())
+    View,
+}
+
+// a class based view routed from a urls module
+#[derive(Debug, Clone)]
+pub struct ViewRoute {
+    pub file: String,
+    pub router: String,
+    pub path: String,
+    pub class: String,
+    pub kind: ViewKind,
+    pub start: usize,
+    pub end: usize,
+    pub body: String,
+}
+
+// an @action method on a viewset
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewSetAction {
+    pub name: String,
+    pub detail: bool,
+    pub verbs: Vec<String>,
+    pub url_path: String,
+}
+
+// how the routers of a python project are wired together: django include()
+// trees, DRF routers, fastapi include_router and flask blueprints
+#[derive(Debug, Clone, Default)]
+pub struct PythonRoutes {
+    pub mounts: Vec<RouteMount>,
+    // the prefix each router was created with, by (file, router variable)
+    pub router_prefixes: HashMap<(String, String), String>,
+    pub views: Vec<ViewRoute>,
+}
+
+impl PythonRoutes {
+    pub fn new(files: &[(String, String)]) -> Self {
+        let paths = files.iter().map(|(f, _)| f.as_str()).collect::<Vec<_>>();
+        let mut routes = PythonRoutes::default();
+        let language: Language = tree_sitter_python::LANGUAGE.into();
+        let mut parser = Parser::new();
+        if parser.set_language(&language).is_err() {
+            return routes;
+        }
+        let Ok(query) = Query::new(&language, ROUTE_CALLS_QUERY) else {
+            return routes;
+        };
+        let Some(call_index) = query.capture_index_for_name("call") else {
+            return routes;
+        };
+        for (file, code) in files {
+            if !file.ends_with(".py") {
+                continue;
+            }
+            let Some(tree) = parser.parse(code, None) else {
+                continue;
+            };
+            let root = tree.root_node();
+            let bindings = import_bindings(root, code, file);
+            for assignment in assignments(root) {
+                let (Some(left), Some(right)) = (
+                    assignment.child_by_field_name("left"),
+                    assignment.child_by_field_name("right"),
+                ) else {
+                    continue;
+                };
+                let left = text(left, code);
+                if right.kind() == "call"
+                    && call_name(right, code).is_some_and(|n| ROUTER_CLASSES.contains(&n.as_str()))
+                {
+                    let prefix = keyword_arg(right, &["prefix", "url_prefix"], code)
+                        .and_then(|v| string_value(v, code))
+                        .unwrap_or_default();
+                    routes.router_prefixes.insert((file.clone(), left), prefix);
+                } else if left == URLPATTERNS {
+                    // urlpatterns += router.urls
+                    for router in router_urls(right, code) {
+                        routes.mounts.push(RouteMount {
+                            parent: (file.clone(), URLPATTERNS.to_string()),
+                            child: (file.clone(), router),
+                            prefix: String::new(),
+                        });
+                    }
+                }
+            }
+            let mut cursor = QueryCursor::new();
+            let mut calls = cursor.matches(&query, root, code.as_bytes());
+            while let Some(m) = calls.next() {
+                for cap in m.captures.iter().filter(|c| c.index == call_index) {
+                    routes.add_call(cap.node, code, file, &bindings, &paths);
+                }
+            }
+        }
+        routes
+    }
+
+    fn add_call(
+        &mut self,
+        call: TreeNode,
+        code: &str,
+        file: &str,
+        bindings: &HashMap<String, String>,
+        paths: &[&str],
+    ) {
+        let Some(name) = call_name(call, code) else {
+            return;
+        };
+        let args = positional_args(call);
+        let object = call
+            .child_by_field_name("function")
+            .and_then(|f| f.child_by_field_name("object"))
+            .map(|o| text(o, code));
+        match name.as_str() {
+            n if INCLUDE_CALLS.contains(&n) => {
+                let (Some(parent), Some(arg)) = (object, args.first()) else {
+                    return;
+                };
+                if let Some(child) = resolve_router(*arg, code, file, bindings, paths) {
+                    let prefix = keyword_arg(call, &["prefix", "url_prefix"], code)
+                        .and_then(|v| string_value(v, code))
+                        .unwrap_or_default();
+                    self.mounts.push(RouteMount {
+                        parent: (file.to_string(), parent),
+                        child,
+                        prefix,
+                    });
+                }
+            }
+            "register" => {
+                let Some(router) = object else {
+                    return;
+                };
+                if !self
+                    .router_prefixes
+                    .contains_key(&(file.to_string(), router.clone()))
+                {
+                    return;
+                }
+                let (Some(path), Some(class)) = (
+                    args.first().and_then(|a| string_value(*a, code)),
+                    args.get(1).and_then(|a| last_name(*a, code)),
+                ) else {
+                    return;
+                };
+                self.views.push(ViewRoute {
+                    file: file.to_string(),
+                    router,
+                    path: clean_pattern(&path),
+                    class,
+                    kind: ViewKind::ViewSet,
+                    start: call.start_position().row,
+                    end: call.end_position().row,
+                    body: text(call, code),
+                });
+            }
+            "path" | "re_path" | "url" => {
+                let (Some(pattern), Some(target)) = (
+                    args.first().and_then(|a| string_value(*a, code)),
+                    args.get(1).filter(|a| a.kind() == "call"),
+                ) else {
+                    return;
+                };
+                let pattern = clean_pattern(&pattern);
+                match call_name(*target, code).as_deref() {
+                    Some("include") => {
+                        let included = positional_args(*target).first().and_then(|arg| {
+                            let arg = match arg.kind() {
+                                // include(('blog.urls', 'blog'))
+                                "tuple" => arg.named_child(0)?,
+                                _ => *arg,
+                            };
+                            match string_value(arg, code) {
+                                Some(module) => module_file(&module, file, paths)
+                                    .map(|f| (f.to_string(), URLPATTERNS.to_string())),
+                                // include(router.urls)
+                                None => router_urls(arg, code)
+                                    .into_iter()
+                                    .next()
+                                    .map(|router| (file.to_string(), router)),
+                            }
+                        });
+                        if let Some(child) = included {
+                            self.mounts.push(RouteMount {
+                                parent: (file.to_string(), URLPATTERNS.to_string()),
+                                child,
+                                prefix: pattern,
+                            });
+                        }
+                    }
+                    Some("as_view") => {
+                        let class = target
+                            .child_by_field_name("function")
+                            .and_then(|f| f.child_by_field_name("object"))
+                            .and_then(|o| last_name(o, code));
+                        if let Some(class) = class {
+                            self.views.push(ViewRoute {
+                                file: file.to_string(),
+                                router: URLPATTERNS.to_string(),
+                                path: pattern,
+                                class,
+                                kind: ViewKind::View,
+                                start: call.start_position().row,
+                                end: call.end_position().row,
+                                body: text(call, code),
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // every prefix a router is mounted under. Routers that nothing includes
+    // are served at the root.
+    pub fn prefixes(&self, file: &str, router: &str) -> Vec<String> {
+        self.prefixes_at(&(file.to_string(), router.to_string()), 0)
+    }
+
+    fn prefixes_at(&self, key: &(String, String), depth: usize) -> Vec<String> {
+        let parents = self
+            .mounts
+            .iter()
+            .filter(|m| &m.child == key)
+            .collect::<Vec<_>>();
+        if parents.is_empty() || depth > MAX_MOUNT_DEPTH {
+            return vec![String::new()];
+        }
+        let mut res = Vec::new();
+        for mount in parents {
+            let own = self
+                .router_prefixes
+                .get(&mount.parent)
+                .cloned()
+                .unwrap_or_default();
+            for prefix in self.prefixes_at(&mount.parent, depth + 1) {
+                let prefix = join_prefix(&join_prefix(&prefix, &own), &mount.prefix);
+                if !res.contains(&prefix) {
+                    res.push(prefix);
+                }
+            }
+        }
+        res
+    }
+
+    // prefixes the endpoints found in a file with the routes it is included under
+    pub fn mount(
+        &self,
+        file: &str,
+        endpoints: Vec<(NodeData, Option<Edge>)>,
+    ) -> Vec<(NodeData, Option<Edge>)> {
+        let mut res = Vec::new();
+        for (endpoint, edge) in endpoints {
+            let router = endpoint_router(&endpoint.body);
+            for prefix in self.prefixes(file, &router) {
+                if prefix.is_empty() {
+                    res.push((endpoint.clone(), edge.clone()));
+                    continue;
+                }
+                let mut mounted = endpoint.clone();
+                mounted.name = parse::join_route(&prefix, &endpoint.name);
+                let edge = edge.clone().map(|mut e| {
+                    e.source.node_data.name = mounted.name.clone();
+                    e
+                });
+                res.push((mounted, edge));
+            }
+        }
+        res
+    }
+
+    // the endpoints of a viewset or class based view, with Handler edges to
+    // the methods the class defines
+    pub fn view_endpoints(
+        &self,
+        view: &ViewRoute,
+        class: &NodeData,
+        methods: &[NodeData],
+        class_code: &str,
+    ) -> Vec<(NodeData, Option<Edge>)> {
+        let defines = |name: &str| methods.iter().any(|m| m.name == name);
+        let inherited = inherited_methods(&view.kind, &class.body);
+        let has = |name: &str| defines(name) || inherited.contains(&name);
+        // (path, verb, method)
+        let mut routes = Vec::new();
+        match view.kind {
+            ViewKind::View => {
+                for method in VIEW_METHODS {
+                    if has(method) {
+                        routes.push((view.path.clone(), method.to_uppercase(), method.to_string()));
+                    }
+                }
+            }
+            ViewKind::ViewSet => {
+                let base = view.path.trim_matches('/');
+                for (action, verb, detail) in VIEWSET_ACTIONS {
+                    if has(action) {
+                        let path = if detail {
+                            format!("{}/<pk>/", base)
+                        } else {
+                            format!("{}/", base)
+                        };
+                        routes.push((path, verb.to_string(), action.to_string()));
+                    }
+                }
+                for action in viewset_actions(class_code, &class.name) {
+                    let path = if action.detail {
+                        format!("{}/<pk>/{}/", base, action.url_path)
+                    } else {
+                        format!("{}/{}/", base, action.url_path)
+                    };
+                    for verb in &action.verbs {
+                        routes.push((path.clone(), verb.to_uppercase(), action.name.clone()));
+                    }
+                }
+            }
+        }
+        let mut res = Vec::new();
+        for prefix in self.prefixes(&view.file, &view.router) {
+            for (path, verb, method) in &routes {
+                let mut endpoint = NodeData::name_file(&join_prefix(&prefix, path), &view.file);
+                endpoint.start = view.start;
+                endpoint.end = view.end;
+                endpoint.body = view.body.clone();
+                endpoint.add_verb(verb);
+                endpoint
+                    .meta
+                    .insert("handler".to_string(), format!("{}.{}", class.name, method));
+                let edge = methods
+                    .iter()
+                    .find(|m| &m.name == method)
+                    .map(|m| Edge::handler(&endpoint, m));
+                res.push((endpoint, edge));
+            }
+        }
+        res
+    }
+}
+
+fn text(node: TreeNode, code: &str) -> String {
+    node.utf8_text(code.as_bytes()).unwrap_or_default().to_string()
+}
+
+// the value of a string literal, without its prefix and quotes
+pub(crate) fn string_value(node: TreeNode, code: &str) -> Option<String> {
+    if node.kind() != "string" {
+        return None;
+    }
+    let t = text(node, code);
+    let t = t.trim_start_matches(|c: char| "rRbBuU".contains(c));
+    Some(t.trim_matches(|c| c == '"' || c == '\'').to_string())
+}
+
+// r'^api/' => api/
+fn clean_pattern(pattern: &str) -> String {
+    pattern
+        .trim_start_matches('^')
+        .trim_end_matches('$')
+        .to_string()
+}
+
+fn join_prefix(prefix: &str, path: &str) -> String {
+    if prefix.is_empty() {
+        path.to_string()
+    } else if path.is_empty() {
+        prefix.to_string()
+    } else {
+        parse::join_route(prefix, path)
+    }
+}
+
+// the router of a decorator endpoint (@router.get), or the urlpatterns of a django path()
+fn endpoint_router(body: &str) -> String {
+    body.strip_prefix('@')
+        .and_then(|b| b.split_once('.'))
+        .map(|(router, _)| router.trim().to_string())
+        .unwrap_or_else(|| URLPATTERNS.to_string())
+}
+
+pub(crate) fn keyword_arg<'a>(
+    call: TreeNode<'a>,
+    names: &[&str],
+    code: &str,
+) -> Option<TreeNode<'a>> {
+    let args = call.child_by_field_name("arguments")?;
+    let mut cursor = args.walk();
+    let arg = args.named_children(&mut cursor).find(|a| {
+        a.kind() == "keyword_argument"
+            && a
+                .child_by_field_name("name")
+                .is_some_and(|n| names.contains(&text(n, code).as_str()))
+    });
+    arg.and_then(|a| a.child_by_field_name("value"))
+}
+
+pub(crate) fn positional_args(call: TreeNode) -> Vec<TreeNode> {
+    let Some(args) = call.child_by_field_name("arguments") else {
+        return Vec::new();
+    };
+    let mut cursor = args.walk();
+    args.named_children(&mut cursor)
+        .filter(|a| !matches!(a.kind(), "keyword_argument" | "comment"))
+        .collect()
+}
+
+// the last name of a called function, APIRouter for fastapi.APIRouter()
+pub(crate) fn call_name(call: TreeNode, code: &str) -> Option<String> {
+    last_name(call.child_by_field_name("function")?, code)
+}
+
+// UserViewSet for views.UserViewSet
+pub(crate) fn last_name(node: TreeNode, code: &str) -> Option<String> {
+    match node.kind() {
+        "identifier" => Some(text(node, code)),
+        "attribute" => node.child_by_field_name("attribute").map(|a| text(a, code)),
+        _ => None,
+    }
+}
+
+// the router object of a decorator route, router for @router.get("/x")
+fn decorator_router(route: TreeNode, code: &str) -> Option<String> {
+    if route.kind() != "decorator" {
+        return None;
+    }
+    let call = route.named_child(0).filter(|c| c.kind() == "call")?;
+    let object = call
+        .child_by_field_name("function")
+        .filter(|f| f.kind() == "attribute")?
+        .child_by_field_name("object")
+        .filter(|o| o.kind() == "identifier")?;
+    Some(text(object, code))
+}
+
+// the prefix a router was created with: APIRouter(prefix=...) or Blueprint(url_prefix=...)
+fn router_prefix(root: TreeNode, router: &str, code: &str) -> Option<String> {
+    assignments(root).into_iter().find_map(|a| {
+        let left = a.child_by_field_name("left")?;
+        let right = a.child_by_field_name("right").filter(|r| r.kind() == "call")?;
+        if text(left, code) != router
+            || !call_name(right, code).is_some_and(|n| ROUTER_CLASSES.contains(&n.as_str()))
+        {
+            return None;
+        }
+        Some(
+            keyword_arg(right, &["prefix", "url_prefix"], code)
+                .and_then(|v| string_value(v, code))
+                .unwrap_or_default(),
+        )
+    })
+}
+
+// module level assignments, like router = APIRouter()
+pub(crate) fn assignments(root: TreeNode) -> Vec<TreeNode> {
+    let mut cursor = root.walk();
+    root.named_children(&mut cursor)
+        .filter(|n| n.kind() == "expression_statement")
+        .filter_map(|n| n.named_child(0))
+        .filter(|n| matches!(n.kind(), "assignment" | "augmented_assignment"))
+        .collect()
+}
+
+// the routers in `router.urls` expressions that are not inside an include()
+fn router_urls(node: TreeNode, code: &str) -> Vec<String> {
+    let mut res = Vec::new();
+    let mut stack = vec![node];
+    while let Some(n) = stack.pop() {
+        if n.kind() == "call" && call_name(n, code).as_deref() == Some("include") && n != node {
+            continue;
+        }
+        if n.kind() == "attribute"
+            && n.child_by_field_name("attribute").map(|a| text(a, code)).as_deref() == Some("urls")
+        {
+            if let Some(object) = n.child_by_field_name("object").filter(|o| o.kind() == "identifier") {
+                res.push(text(object, code));
+            }
+            continue;
+        }
+        let mut cursor = n.walk();
+        stack.extend(n.named_children(&mut cursor));
+    }
+    res
+}
+
+// imported names, mapped to the module path they refer to ("app/routers/users").
+// Relative imports are resolved against the importing file.
+fn import_bindings(
+    root: TreeNode,
+    code: &str,
+    file: &str,
+) -> HashMap<String, String> {
+    let mut bindings = HashMap::new();
+    let module_path = |module: &str| -> String {
+        let dots = module.len() - module.trim_start_matches('.').len();
+        let rest = module.trim_start_matches('.').replace('.', "/");
+        if dots == 0 {
+            return rest;
+        }
+        let mut parts = file.split('/').collect::<Vec<_>>();
+        parts.pop();
+        for _ in 1..dots {
+            parts.pop();
+        }
+        if !rest.is_empty() {
+            parts.push(&rest);
+        }
+        parts.join("/")
+    };
+    let mut cursor = root.walk();
+    for stmt in root.named_children(&mut cursor) {
+        let from = match stmt.kind() {
+            "import_from_statement" => stmt
+                .child_by_field_name("module_name")
+                .map(|m| module_path(&text(m, code))),
+            "import_statement" => None,
+            _ => continue,
+        };
+        let mut c = stmt.walk();
+        for name in stmt.children_by_field_name("name", &mut c) {
+            let (imported, alias) = match name.kind() {
+                "aliased_import" => (
+                    name.child_by_field_name("name").map(|n| text(n, code)),
+                    name.child_by_field_name("alias").map(|a| text(a, code)),
+                ),
+                _ => (Some(text(name, code)), None),
+            };
+            let Some(imported) = imported else {
+                continue;
+            };
+            let (local, target) = match &from {
+                Some(module) => (
+                    alias.unwrap_or_else(|| imported.clone()),
+                    format!("{}/{}", module, imported),
+                ),
+                None => (
+                    alias.unwrap_or_else(|| imported.split('.').next().unwrap_or_default().to_string()),
+                    imported.replace('.', "/"),
+                ),
+            };
+            bindings.insert(local, target.trim_start_matches('/').to_string());
+        }
+    }
+    bindings
+}
+
+// the file of a python module, "blog.urls" or "blog/urls"
+fn module_file<'a>(module: &str, from_file: &str, paths: &[&'a str]) -> Option<&'a str> {
+    let module = module.replace('.', "/");
+    let candidates = [format!("{}.py", module), format!("{}/__init__.py", module)];
+    let matches = |p: &&&str| {
+        candidates
+            .iter()
+            .any(|c| p.ends_with(&format!("/{}", c)) || **p == c.as_str())
+    };
+    // prefer the module closest to the including file
+    let mut found = paths.iter().filter(matches).collect::<Vec<_>>();
+    found.sort_by_key(|p| {
+        let common = p
+            .split('/')
+            .zip(from_file.split('/'))
+            .take_while(|(a, b)| a == b)
+            .count();
+        std::cmp::Reverse(common)
+    });
+    found.first().map(|p| **p)
+}
+
+// whether a file is the python module at a module path ("app/schemas")
+fn is_module_file(file: &str, module: &str) -> bool {
+    [format!("{}.py", module), format!("{}/__init__.py", module)]
+        .iter()
+        .any(|c| file == c || file.ends_with(&format!("/{}", c)))
+}
+
+// the names a file imports, from its Import node
+fn file_import_bindings(graph: &dyn GraphEdit, file: &str) -> HashMap<String, String> {
+    let Some(imports) = graph
+        .find_nodes_by_file_ends_with(NodeType::Import, file)
+        .into_iter()
+        .find(|i| i.file == file)
+    else {
+        return HashMap::new();
+    };
+    let mut parser = Parser::new();
+    if parser.set_language(&tree_sitter_python::LANGUAGE.into()).is_err() {
+        return HashMap::new();
+    }
+    match parser.parse(&imports.body, None) {
+        Some(tree) => import_bindings(tree.root_node(), &imports.body, file),
+        None => HashMap::new(),
+    }
+}
+
+// the (file, router variable) an include_router/register_blueprint argument refers to
+fn resolve_router(
+    arg: TreeNode,
+    code: &str,
+    file: &str,
+    bindings: &HashMap<String, String>,
+    paths: &[&str],
+) -> Option<(String, String)> {
+    match arg.kind() {
+        "identifier" => {
+            let name = text(arg, code);
+            match bindings.get(&name) {
+                // from app.routes import router
+                Some(target) => {
+                    let (module, var) = target.rsplit_once('/')?;
+                    let module_file = module_file(module, file, paths)?;
+                    Some((module_file.to_string(), var.to_string()))
+                }
+                None => Some((file.to_string(), name)),
+            }
+        }
+        // users.router, with `from app.routers import users`
+        "attribute" => {
+            let object = arg.child_by_field_name("object")?;
+            let var = text(arg.child_by_field_name("attribute")?, code);
+            let target = bindings.get(&text(object, code))?;
+            let module_file = module_file(target, file, paths)?;
+            Some((module_file.to_string(), var))
+        }
+        _ => None,
+    }
+}
+
+// the methods a view or viewset gets from its DRF base classes and mixins
+fn inherited_methods(kind: &ViewKind, class_body: &str) -> Vec<&'static str> {
+    let header = class_body.lines().next().unwrap_or_default();
+    let bases = header
+        .split_once('(')
+        .map(|(_, b)| b.trim_end_matches([')', ':', ' ']))
+        .unwrap_or_default()
+        .split(',')
+        .map(|b| b.trim().rsplit('.').next().unwrap_or_default().to_string())
+        .collect::<Vec<_>>();
+    let mut res = Vec::new();
+    for base in &bases {
+        let provided: &[&str] = match (kind, base.as_str()) {
+            (ViewKind::ViewSet, "ModelViewSet") => &[
+                "list",
+                "create",
+                "retrieve",
+                "update",
+                "partial_update",
+                "destroy",
+            ],
+            (ViewKind::ViewSet, "ReadOnlyModelViewSet") => &["list", "retrieve"],
+            (ViewKind::ViewSet, "ListModelMixin") => &["list"],
+            (ViewKind::ViewSet, "CreateModelMixin") => &["create"],
+            (ViewKind::ViewSet, "RetrieveModelMixin") => &["retrieve"],
+            (ViewKind::ViewSet, "UpdateModelMixin") => &["update", "partial_update"],
+            (ViewKind::ViewSet, "DestroyModelMixin") => &["destroy"],
+            // generics like ListCreateAPIView or RetrieveUpdateDestroyAPIView
+            (ViewKind::View, b) if b.ends_with("APIView") && b != "APIView" => {
+                let mut methods = Vec::new();
+                if b.starts_with("List") || b.starts_with("Retrieve") {
+                    methods.push("get");
+                }
+                if b.contains("Create") {
+                    methods.push("post");
+                }
+                if b.contains("Update") {
+                    methods.extend(["put", "patch"]);
+                }
+                if b.contains("Destroy") {
+                    methods.push("delete");
+                }
+                res.extend(methods);
+                continue;
+            }
+            _ => &[],
+        };
+        res.extend(provided);
+    }
+    res
+}
+
+// the @action methods of a viewset class
+pub fn viewset_actions(code: &str, class_name: &str) -> Vec<ViewSetAction> {
+    let mut res = Vec::new();
+    let mut parser = Parser::new();
+    if parser.set_language(&tree_sitter_python::LANGUAGE.into()).is_err() {
+        return res;
+    }
+    let Some(tree) = parser.parse(code, None) else {
+        return res;
+    };
+    let mut stack = vec![tree.root_node()];
+    let mut class_body = None;
+    while let Some(node) = stack.pop() {
+        if node.kind() == "class_definition"
+            && node.child_by_field_name("name").map(|n| text(n, code)).as_deref() == Some(class_name)
+        {
+            class_body = node.child_by_field_name("body");
+            break;
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+    let Some(body) = class_body else {
+        return res;
+    };
+    let mut cursor = body.walk();
+    for def in body.named_children(&mut cursor) {
+        if def.kind() != "decorated_definition" {
+            continue;
+        }
+        let Some(name) = def
+            .child_by_field_name("definition")
+            .and_then(|d| d.child_by_field_name("name"))
+            .map(|n| text(n, code))
+        else {
+            continue;
+        };
+        let mut c = def.walk();
+        let action = def.named_children(&mut c).find_map(|d| {
+            let call = d.named_child(0).filter(|c| c.kind() == "call")?;
+            (d.kind() == "decorator" && call_name(call, code).as_deref() == Some("action")).then_some(call)
+        });
+        let Some(action) = action else {
+            continue;
+        };
+        let detail = keyword_arg(action, &["detail"], code).map(|d| text(d, code)).as_deref() == Some("True");
+        let verbs = keyword_arg(action, &["methods"], code)
+            .map(|list| {
+                let mut c = list.walk();
+                list.named_children(&mut c)
+                    .filter_map(|v| string_value(v, code))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| vec!["get".to_string()]);
+        let url_path = keyword_arg(action, &["url_path"], code)
+            .and_then(|u| string_value(u, code))
+            .unwrap_or_else(|| name.clone());
+        res.push(ViewSetAction {
+            name,
+            detail,
+            verbs,
+            url_path,
+        });
+    }
+    res
+}
+
+// what kind of model a data model class is, from its decorators and bases
+fn model_kind(body: &str) -> Option<&'static str> {
+    let decorators = body
+        .lines()
+        .map(|l| l.trim())
+        .take_while(|l| !l.starts_with("class "))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let header = body
+        .lines()
+        .map(|l| l.trim())
+        .find(|l| l.starts_with("class "))?;
+    let bases = header
+        .split_once('(')
+        .map(|(_, b)| b.split(')').next().unwrap_or_default())
+        .unwrap_or_default()
+        .split(',')
+        .map(|b| b.trim())
+        .collect::<Vec<_>>();
+    let has_base = |names: &[&str]| {
+        bases
+            .iter()
+            .any(|b| names.contains(b) || names.contains(&b.rsplit('.').next().unwrap_or(b)))
+    };
+    let kind = if decorators.contains("dataclass") {
+        "dataclass"
+    } else if decorators.starts_with('@') {
+        "attrs"
+    } else if has_base(&["BaseModel"]) {
+        "pydantic"
+    } else if has_base(&["SQLModel"]) {
+        "sqlmodel"
+    } else if has_base(&["models.Model"]) || body.contains("= models.") {
+        "django"
+    } else if has_base(&["Base", "DeclarativeBase", "db.Model"]) {
+        "sqlalchemy"
+    } else {
+        return None;
+    };
+    Some(kind)
+}
+
+// the typed fields of a model class, like "id:int" or "name:String"
+pub fn model_fields(body: &str) -> Vec<String> {
+    let mut parser = Parser::new();
+    if parser.set_language(&tree_sitter_python::LANGUAGE.into()).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(body, None) else {
+        return Vec::new();
+    };
+    let mut stack = vec![tree.root_node()];
+    let mut class_body = None;
+    while let Some(node) = stack.pop() {
+        if node.kind() == "class_definition" {
+            class_body = node.child_by_field_name("body");
+            break;
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+    let Some(class_body) = class_body else {
+        return Vec::new();
+    };
+    let mut fields = Vec::new();
+    let mut cursor = class_body.walk();
+    for stmt in class_body.named_children(&mut cursor) {
+        let Some(assignment) = stmt
+            .named_child(0)
+            .filter(|_| stmt.kind() == "expression_statement")
+            .filter(|a| a.kind() == "assignment")
+        else {
+            continue;
+        };
+        let Some(name) = assignment
+            .child_by_field_name("left")
+            .filter(|l| l.kind() == "identifier")
+            .map(|l| text(l, body))
+        else {
+            continue;
+        };
+        if name.starts_with("__") || name == "model_config" {
+            continue;
+        }
+        let right = assignment.child_by_field_name("right");
+        let field_type = match assignment.child_by_field_name("type") {
+            Some(t) => {
+                let t = text(t, body);
+                // SQLAlchemy 2.0: id: Mapped[int] = mapped_column()
+                match t.strip_prefix("Mapped[").and_then(|t| t.strip_suffix(']')) {
+                    Some(inner) => Some(inner.to_string()),
+                    None => Some(t),
+                }
+            }
+            None => match right.filter(|r| r.kind() == "call") {
+                Some(call) => column_type(call, body),
+                // plain class attributes are not fields
+                None => continue,
+            },
+        };
+        match field_type {
+            Some(t) if !t.is_empty() => fields.push(format!("{}:{}", name, t)),
+            _ => fields.push(name),
+        }
+    }
+    fields
+}
+
+// Column(Integer), Column(String(50)), models.CharField() or attr.ib(type=int)
+fn column_type(call: TreeNode, code: &str) -> Option<String> {
+    let name = call_name(call, code)?;
+    match name.as_str() {
+        "Column" | "mapped_column" => {
+            let arg = positional_args(call)
+                .into_iter()
+                .find(|a| !matches!(a.kind(), "string"))?;
+            match arg.kind() {
+                "call" => call_name(arg, code),
+                _ => last_name(arg, code),
+            }
+        }
+        "ib" | "attrib" | "field" => keyword_arg(call, &["type"], code).map(|t| text(t, code)),
+        _ => Some(name),
+    }
+}
+
+// the types named in a function's parameter and return annotations
+pub fn annotation_types(func_body: &str) -> Vec<String> {
+    let mut parser = Parser::new();
+    if parser.set_language(&tree_sitter_python::LANGUAGE.into()).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(func_body, None) else {
+        return Vec::new();
+    };
+    let mut stack = vec![tree.root_node()];
+    let mut func = None;
+    while let Some(node) = stack.pop() {
+        if node.kind() == "function_definition" {
+            func = Some(node);
+            break;
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+    let Some(func) = func else {
+        return Vec::new();
+    };
+    let mut annotations = Vec::new();
+    if let Some(params) = func.child_by_field_name("parameters") {
+        let mut cursor = params.walk();
+        for param in params.named_children(&mut cursor) {
+            if matches!(param.kind(), "typed_parameter" | "typed_default_parameter") {
+                annotations.extend(param.child_by_field_name("type"));
+            }
+        }
+    }
+    annotations.extend(func.child_by_field_name("return_type"));
+
+    let mut types = Vec::new();
+    // walk in source order
+    let mut stack = annotations;
+    stack.reverse();
+    while let Some(node) = stack.pop() {
+        let name = match node.kind() {
+            "identifier" => Some(text(node, func_body)),
+            // schemas.User
+            "attribute" => last_name(node, func_body),
+            // "User" forward references
+            "string" => string_value(node, func_body),
+            _ => {
+                let mut cursor = node.walk();
+                let children = node.named_children(&mut cursor).collect::<Vec<_>>();
+                stack.extend(children.into_iter().rev());
+                None
+            }
+        };
+        let name = name.filter(|n| parse::is_capitalized(n) && !NOT_MODEL_TYPES.contains(&n.as_str()));
+        if let Some(name) = name {
+            if !types.contains(&name) {
+                types.push(name);
+            }
+        }
+    }
+    types
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(files: &[(&str, &str)]) -> Vec<(String, String)> {
+        files
+            .iter()
+            .map(|(f, c)| (f.to_string(), c.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_django_include_prefixes() {
+        let files = files(&[
+            (
+                "site/project/urls.py",
+                r#"from django.urls import include, path, re_path
+urlpatterns = [
+    path('api/', include('blog.urls')),
+    re_path(r'^v2/', include(('blog.urls', 'blog'), namespace='v2')),
+]
+"#,
+            ),
+            (
+                "site/blog/urls.py",
+                r#"from django.urls import include, path
+from rest_framework.routers import DefaultRouter
+from . import views
+
+router = DefaultRouter()
+router.register(r'posts', views.PostViewSet, basename='post')
+
+urlpatterns = [
+    path('authors/', views.AuthorList.as_view()),
+    path('', include(router.urls)),
+]
+"#,
+            ),
+        ]);
+        let routes = PythonRoutes::new(&files);
+        assert_eq!(routes.prefixes("site/project/urls.py", URLPATTERNS), vec![""]);
+        assert_eq!(routes.prefixes("site/blog/urls.py", URLPATTERNS), vec!["api/", "v2/"]);
+        assert_eq!(routes.prefixes("site/blog/urls.py", "router"), vec!["api/", "v2/"]);
+        assert_eq!(routes.views.len(), 2);
+        assert_eq!(routes.views[0].class, "PostViewSet");
+        assert_eq!(routes.views[0].kind, ViewKind::ViewSet);
+        assert_eq!(routes.views[0].path, "posts");
+        assert_eq!(routes.views[1].class, "AuthorList");
+        assert_eq!(routes.views[1].kind, ViewKind::View);
+    }
+
+    #[test]
+    fn test_fastapi_router_prefixes() {
+        let files = files(&[
+            (
+                "svc/app/main.py",
+                r#"from fastapi import FastAPI
+from app.api import api_router
+
+app = FastAPI()
+app.include_router(api_router, prefix="/api")
+"#,
+            ),
+            (
+                "svc/app/api.py",
+                r#"from fastapi import APIRouter
+from app.routers import users
+
+api_router = APIRouter(prefix="/v1")
+api_router.include_router(users.router, prefix="/users")
+"#,
+            ),
+            (
+                "svc/app/routers/users.py",
+                r#"from fastapi import APIRouter
+router = APIRouter(tags=["users"])
+
+@router.get("/{id}")
+def get_user(id: int):
+    pass
+"#,
+            ),
+        ]);
+        let routes = PythonRoutes::new(&files);
+        assert_eq!(routes.prefixes("svc/app/api.py", "api_router"), vec!["/api"]);
+        assert_eq!(
+            routes.prefixes("svc/app/routers/users.py", "router"),
+            vec!["/api/v1/users"]
+        );
+        let mut endpoint = NodeData::name_file("/{id}", "svc/app/routers/users.py");
+        endpoint.body = "@router.get(\"/{id}\")".to_string();
+        let mounted = routes.mount("svc/app/routers/users.py", vec![(endpoint, None)]);
+        assert_eq!(mounted[0].0.name, "/api/v1/users/{id}");
+    }
+
+    #[test]
+    fn test_viewset_actions() {
+        let code = r#"class PostViewSet(viewsets.ModelViewSet):
+    queryset = Post.objects.all()
+
+    @action(detail=True, methods=['post'], url_path='publish-now')
+    def publish(self, request, pk=None):
+        pass
+
+    @action(detail=False)
+    def recent(self, request):
+        pass
+"#;
+        let actions = viewset_actions(code, "PostViewSet");
+        assert_eq!(actions.len(), 2);
+        assert_eq!(actions[0].url_path, "publish-now");
+        assert!(actions[0].detail);
+        assert_eq!(actions[0].verbs, vec!["post"]);
+        assert_eq!(actions[1].verbs, vec!["get"]);
+        assert!(!actions[1].detail);
+        assert_eq!(
+            inherited_methods(&ViewKind::ViewSet, code),
+            vec!["list", "create", "retrieve", "update", "partial_update", "destroy"]
+        );
+        assert_eq!(
+            inherited_methods(&ViewKind::View, "class PostList(generics.ListCreateAPIView):"),
+            vec!["get", "post"]
+        );
+    }
+
+    #[test]
+    fn test_model_fields() {
+        let sqlalchemy = r#"class User(Base):
+    __tablename__ = "users"
+    id = Column(Integer, primary_key=True)
+    name = Column("full_name", String(50))
+    team_id: Mapped[int] = mapped_column(ForeignKey("teams.id"))
+    active = True
+
+    def __repr__(self):
+        return self.name
+"#;
+        assert_eq!(model_kind(sqlalchemy), Some("sqlalchemy"));
+        assert_eq!(model_fields(sqlalchemy), vec!["id:Integer", "name:String", "team_id:int"]);
+
+        let django = r#"class Post(models.Model):
+    title = models.CharField(max_length=100)
+    author = models.ForeignKey(User, on_delete=models.CASCADE)
+"#;
+        assert_eq!(model_kind(django), Some("django"));
+        assert_eq!(model_fields(django), vec!["title:CharField", "author:ForeignKey"]);
+
+        let attrs = r#"@attr.s
+class Point:
+    x = attr.ib(type=int)
+    y: float = 0.0
+"#;
+        assert_eq!(model_kind(attrs), Some("attrs"));
+        assert_eq!(model_fields(attrs), vec!["x:int", "y:float"]);
+
+        let dataclass = "@dataclass(frozen=True)\nclass Tag:\n    name: str\n";
+        assert_eq!(model_kind(dataclass), Some("dataclass"));
+        assert_eq!(model_fields(dataclass), vec!["name:str"]);
+
+        let unknown = "class Handler(Model):\n    name = 'x'\n";
+        assert_eq!(model_kind(unknown), None);
+    }
+
+    #[test]
+    fn test_annotation_types() {
+        let code = r#"def create(db: Session, user: schemas.UserCreate, tags: List["Tag"] = None) -> Optional[User]:
+    pass
+"#;
+        assert_eq!(annotation_types(code), vec!["UserCreate", "Tag", "User"]);
+    }
 }
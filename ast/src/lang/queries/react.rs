@@ -37,57 +37,7 @@ impl Stack for ReactTs {
         ))
     }
     fn classify_test(&self, name: &str, file: &str, body: &str) -> NodeType {
-        // 1. Path based (strongest signal)
-        let f = file.replace('\\', "/");
-        let fname = f.rsplit('/').next().unwrap_or(&f).to_lowercase();
-        let is_e2e_dir = f.contains("/tests/e2e/") || f.contains("/test/e2e") || f.contains("/e2e/");
-        if is_e2e_dir
-            || f.contains("/__e2e__/")
-            || f.contains("/e2e/")
-            || f.contains(".e2e.")
-            || fname.starts_with("e2e.")
-            || fname.starts_with("e2e_")
-            || fname.starts_with("e2e-")
-            || fname.contains("e2e.test")
-            || fname.contains("e2e.spec")
-            || fname.contains("e2e")
-        {
-            return NodeType::E2eTest;
-        }
-        if f.contains("/integration/") || f.contains(".int.") || f.contains(".integration."){ 
-            return NodeType::IntegrationTest;
-        }
-        if f.contains("/unit/") || f.contains(".unit.") {
-            return NodeType::UnitTest;
-        }
-
-        let lower_name = name.to_lowercase();
-        // 2. Explicit tokens in test name
-        if lower_name.contains("e2e") {
-            return NodeType::E2eTest;
-        }
-        if lower_name.contains("integration") {
-            return NodeType::IntegrationTest;
-        }
-
-        // 3. Body heuristics (tighter): network => integration; real browser automation => e2e
-        let body_l = body.to_lowercase();
-            let has_playwright_import = body_l.contains("@playwright/test");
-            let has_browser_actions = body_l.contains("page.goto(") || body_l.contains("page.click(") || body_l.contains("page.evaluate(");
-            let has_cypress = body_l.contains("cy.") || body_l.contains("cypress");
-            let has_puppeteer = body_l.contains("puppeteer") || body_l.contains("browser.newpage");
-            if (has_playwright_import && has_browser_actions) || has_cypress || has_puppeteer {
-            return NodeType::E2eTest;
-        }
-
-       const NETWORK_MARKERS: [&str; 11] = [
-            "fetch(", "axios.", "axios(", "supertest(", "request(", "new request(",
-            "/api/", "http://", "https://", "globalthis.fetch", "cy.request("
-        ];
-        if NETWORK_MARKERS.iter().any(|m| body_l.contains(m)) {
-            return NodeType::IntegrationTest;
-        }
-        NodeType::UnitTest
+        classify_js_test(name, file, body)
     }
     fn is_lib_file(&self, file_name: &str) -> bool {
         file_name.contains("node_modules/")
@@ -867,6 +817,62 @@ fn e2e_test_query(&self) -> Option<String> {
         }
     }
 }
+
+// shared by the javascript stacks (react, typescript)
+pub fn classify_js_test(name: &str, file: &str, body: &str) -> NodeType {
+    // 1. Path based (strongest signal)
+    let f = file.replace('\\', "/");
+    let fname = f.rsplit('/').next().unwrap_or(&f).to_lowercase();
+    let is_e2e_dir = f.contains("/tests/e2e/") || f.contains("/test/e2e") || f.contains("/e2e/");
+    if is_e2e_dir
+        || f.contains("/__e2e__/")
+        || f.contains("/e2e/")
+        || f.contains(".e2e.")
+        || fname.starts_with("e2e.")
+        || fname.starts_with("e2e_")
+        || fname.starts_with("e2e-")
+        || fname.contains("e2e.test")
+        || fname.contains("e2e.spec")
+        || fname.contains("e2e")
+    {
+        return NodeType::E2eTest;
+    }
+    if f.contains("/integration/") || f.contains(".int.") || f.contains(".integration.") {
+        return NodeType::IntegrationTest;
+    }
+    if f.contains("/unit/") || f.contains(".unit.") {
+        return NodeType::UnitTest;
+    }
+
+    let lower_name = name.to_lowercase();
+    // 2. Explicit tokens in test name
+    if lower_name.contains("e2e") {
+        return NodeType::E2eTest;
+    }
+    if lower_name.contains("integration") {
+        return NodeType::IntegrationTest;
+    }
+
+    // 3. Body heuristics (tighter): network => integration; real browser automation => e2e
+    let body_l = body.to_lowercase();
+    let has_playwright_import = body_l.contains("@playwright/test");
+    let has_browser_actions = body_l.contains("page.goto(") || body_l.contains("page.click(") || body_l.contains("page.evaluate(");
+    let has_cypress = body_l.contains("cy.") || body_l.contains("cypress");
+    let has_puppeteer = body_l.contains("puppeteer") || body_l.contains("browser.newpage");
+    if (has_playwright_import && has_browser_actions) || has_cypress || has_puppeteer {
+        return NodeType::E2eTest;
+    }
+
+    const NETWORK_MARKERS: [&str; 11] = [
+        "fetch(", "axios.", "axios(", "supertest(", "request(", "new request(",
+        "/api/", "http://", "https://", "globalthis.fetch", "cy.request("
+    ];
+    if NETWORK_MARKERS.iter().any(|m| body_l.contains(m)) {
+        return NodeType::IntegrationTest;
+    }
+    NodeType::UnitTest
+}
+
 pub fn endpoint_name_from_file(file: &str) -> String {
     let path = file.replace('\\', "/");
     let route_path = if let Some(idx) = path.find("/api/") {
@@ -1,6 +1,7 @@
 use super::super::*;
 use super::consts::*;
 use crate::builder::get_page_name;
+use crate::lang::jobs;
 use crate::lang::parse::trim_quotes;
 use crate::lang::queries::rails_routes;
 use convert_case::{Case, Casing};
@@ -551,6 +552,10 @@ impl Stack for Ruby {
     fn direct_class_calls(&self) -> bool {
         true
     }
+    fn skip_call(&self, called: &str, operand: Option<&str>) -> bool {
+        // add_jobs links these to the job's perform
+        operand.is_some_and(|o| jobs::is_ruby_enqueue(called, o))
+    }
     fn convert_association_to_name(&self, name: &str) -> String {
         let target_class = inflection_rs::inflection::singularize(name);
         target_class.to_case(Case::Pascal)
@@ -625,6 +630,17 @@ impl Stack for Ruby {
         }
         results
     }
+    fn add_jobs(&self, graph: &mut dyn GraphEdit, files: &[(String, String)], root: &str) {
+        let files = files
+            .iter()
+            .filter(|(f, _)| f.ends_with(".rb"))
+            .collect::<Vec<_>>();
+        let found = files
+            .iter()
+            .flat_map(|(filename, code)| jobs::ruby_jobs(code, filename))
+            .collect();
+        jobs::add_jobs(graph, &files, root, found, Vec::new(), jobs::ruby_enqueues);
+    }
 }
 
 fn remove_all_extensions(path: &Path) -> String {
@@ -1,7 +1,7 @@
 use super::super::*;
 use super::consts::*;
 use shared::error::{Context, Result};
-use tree_sitter::{Language, Parser, Query, Tree};
+use tree_sitter::{Language, Node as TreeNode, Parser, Query, Tree};
 pub struct Rust(Language);
 
 impl Rust {
@@ -162,7 +162,7 @@ impl Stack for Rust {
               parameters: (parameters) @{ARGUMENTS}
               return_type: (type_identifier)? @{RETURN_TYPES}
               body: (block)? @function.body) @{FUNCTION_DEFINITION}
-              
+
             (function_signature_item
               name: (identifier) @{FUNCTION_NAME}
               parameters: (parameters) @{ARGUMENTS}
@@ -179,6 +179,30 @@ impl Stack for Rust {
             "#
         )
     }
+    fn test_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            (
+              (attribute_item
+                (attribute
+                  [
+                    (identifier) @test-attr
+                    (scoped_identifier
+                      name: (identifier) @test-attr)
+                  ]
+                )
+              )
+              .
+              (attribute_item)*
+              .
+              (function_item
+                name: (identifier) @{FUNCTION_NAME}
+              ) @{FUNCTION_DEFINITION}
+              (#match? @test-attr "^(test|rstest)$")
+            )
+            "#
+        ))
+    }
     fn comment_query(&self) -> Option<String> {
         Some(format!(r#"
             [
@@ -276,6 +300,35 @@ impl Stack for Rust {
         ]
     }
 
+    fn request_finder(&self) -> Option<String> {
+        // requests built in tests to drive a router:
+        // Request::builder().method("POST").uri("/path"), TestRequest::get().uri("/path"), Request::get("/path")
+        Some(format!(
+            r#"
+            (call_expression
+                function: (field_expression
+                    field: (field_identifier) @uri (#eq? @uri "uri")
+                )
+                arguments: (arguments
+                    .
+                    (string_literal) @{ENDPOINT}
+                )
+            ) @{ROUTE} @{REQUEST_CALL}
+
+            (call_expression
+                function: (scoped_identifier
+                    path: (identifier) @request (#eq? @request "Request")
+                    name: (identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^(get|post|put|patch|delete)$")
+                )
+                arguments: (arguments
+                    .
+                    (string_literal) @{ENDPOINT}
+                )
+            ) @{ROUTE}
+            "#
+        ))
+    }
+
     fn data_model_query(&self) -> Option<String> {
         Some(format!(
             r#"
@@ -305,6 +358,15 @@ impl Stack for Rust {
         }
 
         if let Some(call_text) = call {
+            // Request::builder().method("POST") or .method(Method::POST)
+            for verb in ["GET", "POST", "PUT", "PATCH", "DELETE"] {
+                if call_text.contains(&format!("method(\"{}\")", verb))
+                    || call_text.contains(&format!("Method::{}", verb))
+                {
+                    endpoint.add_verb(verb);
+                    return;
+                }
+            }
             if call_text.contains(".get(") || call_text.contains("get(") {
                 endpoint.add_verb("GET");
                 return;
@@ -351,4 +413,62 @@ impl Stack for Rust {
     fn filter_by_implements(&self) -> bool {
         true
     }
+
+    fn is_test_file(&self, filename: &str) -> bool {
+        // integration tests live in the crate's tests/ directory
+        filename.contains("/tests/") || filename.starts_with("tests/")
+    }
+
+    fn has_inline_tests(&self, code: &str) -> bool {
+        code.contains("#[cfg(test)]")
+    }
+
+    fn is_test(&self, func_name: &str, _func_file: &str) -> bool {
+        // attributed tests are found by the test query, this only catches the rest
+        func_name.starts_with("test_")
+    }
+
+    fn is_test_definition(&self, node: &TreeNode, code: &str) -> bool {
+        // #[test], #[tokio::test], #[actix_web::test], #[rstest] ...
+        let mut prev = node.prev_named_sibling();
+        while let Some(attr) = prev {
+            if attr.kind() != "attribute_item" {
+                break;
+            }
+            let text = attr.utf8_text(code.as_bytes()).unwrap_or_default();
+            let path = text
+                .trim_start_matches("#[")
+                .split(|c| c == '(' || c == ']')
+                .next()
+                .unwrap_or_default();
+            if matches!(path.rsplit("::").next(), Some("test" | "rstest")) {
+                return true;
+            }
+            prev = attr.prev_named_sibling();
+        }
+        false
+    }
+
+    fn links_tests_by_request(&self) -> bool {
+        true
+    }
+
+    fn classify_test(&self, _name: &str, file: &str, body: &str) -> NodeType {
+        if self.is_test_file(file) {
+            return NodeType::IntegrationTest;
+        }
+        // tests that send requests through an axum or actix router
+        let drives_router = [
+            "oneshot(",
+            "Request::builder(",
+            "TestRequest::",
+            "init_service(",
+            "TestServer::",
+        ];
+        if drives_router.iter().any(|marker| body.contains(marker)) {
+            NodeType::IntegrationTest
+        } else {
+            NodeType::UnitTest
+        }
+    }
 }
@@ -1,7 +1,8 @@
 use super::super::*;
 use super::consts::*;
 use shared::error::{Context, Result};
-use tree_sitter::{Language, Node as TreeNode, Parser, Query, Tree};
+use lsp::strip_tmp;
+use tree_sitter::{Language, Node as TreeNode, Parser, Query, QueryCursor, Tree};
 
 pub struct Svelte(Language);
 
@@ -151,4 +152,284 @@ impl Stack for Svelte {
     fn is_test(&self, func_name: &str, _func_file: &str) -> bool {
         func_name.starts_with("test")
     }
+
+    fn use_extra_page_finder(&self) -> bool {
+        true
+    }
+    fn is_extra_page(&self, file_name: &str) -> bool {
+        if file_name.contains("/node_modules/") || file_name.contains("/.svelte-kit/") {
+            return false;
+        }
+        route_from_path(file_name).is_some()
+            && (file_name.ends_with("/+page.svelte") || file_name.ends_with("/+layout.svelte"))
+    }
+    fn extra_page_finder(
+        &self,
+        file_path: &str,
+        _find_fn: &dyn Fn(&str, &str) -> Option<NodeData>,
+        _find_fns_in: &dyn Fn(&str) -> Vec<NodeData>,
+    ) -> Option<(NodeData, Option<Edge>)> {
+        let filename = strip_tmp(std::path::Path::new(file_path))
+            .display()
+            .to_string();
+        let mut page = NodeData::name_file(&route_from_path(&filename)?, &filename);
+        if filename.ends_with("/+layout.svelte") {
+            page.meta.insert("layout".to_string(), "true".to_string());
+        }
+        Some((page, None))
+    }
+
+    // the svelte grammar does not see into the +server / +page / +layout modules,
+    // so their functions, the GET / POST handlers and the load functions are added here
+    fn add_extra_endpoints(&self, graph: &mut dyn GraphEdit, files: &[(String, String)]) -> usize {
+        let mut endpoints = Vec::new();
+        let mut calls = Vec::new();
+        for (filename, code) in files {
+            if !is_route_module(filename) {
+                continue;
+            }
+            let Some(route) = route_from_path(filename) else {
+                continue;
+            };
+            let page = route_component(filename).and_then(|component| {
+                graph
+                    .find_nodes_by_file_ends_with(NodeType::Page, &component)
+                    .into_iter()
+                    .find(|p| p.file == component)
+            });
+            for rf in route_functions(code, filename) {
+                let func = rf.func;
+                graph.add_node_with_parent(NodeType::Function, func.clone(), NodeType::File, filename);
+                calls.extend(rf.calls.into_iter().map(|c| (func.clone(), c)));
+                if !rf.exported {
+                    continue;
+                }
+                if is_endpoint_handler(filename, &func.name) {
+                    let mut endpoint = NodeData::name_file(&route, filename);
+                    endpoint.body = func.body.clone();
+                    endpoint.start = func.start;
+                    endpoint.end = func.end;
+                    endpoint.add_verb(&func.name);
+                    endpoint
+                        .meta
+                        .insert("handler".to_string(), func.name.clone());
+                    let edge = Edge::handler(&endpoint, &func);
+                    endpoints.push((endpoint, Some(edge)));
+                } else if func.name == "load" {
+                    if let Some(page) = &page {
+                        graph.add_edge(Edge::calls(NodeType::Page, page, NodeType::Function, &func));
+                    }
+                }
+            }
+        }
+        // the functions of the same module first, then a function no other file shares the name of
+        for (func, name) in calls {
+            let targets = graph.find_nodes_by_name(NodeType::Function, &name);
+            let target = match targets.iter().find(|t| t.file == func.file) {
+                Some(t) => Some(t),
+                None if targets.len() == 1 => targets.first(),
+                None => None,
+            };
+            if let Some(target) = target {
+                graph.add_edge(Edge::calls(NodeType::Function, &func, NodeType::Function, target));
+            }
+        }
+        let count = endpoints.len();
+        graph.add_endpoints(endpoints);
+        count
+    }
+}
+
+const ROUTES_DIR: &str = "src/routes/";
+const ENDPOINT_VERBS: [&str; 7] = ["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS", "HEAD"];
+
+// src/routes/blog/[slug]/+page.svelte -> /blog/[slug]. (group) directories
+// are not part of the url
+pub fn route_from_path(file: &str) -> Option<String> {
+    let idx = file.find(ROUTES_DIR)?;
+    let after = &file[idx + ROUTES_DIR.len()..];
+    let (dirs, name) = after.rsplit_once('/').unwrap_or(("", after));
+    if !name.starts_with('+') {
+        return None;
+    }
+    let segments: Vec<&str> = dirs
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .filter(|s| !(s.starts_with('(') && s.ends_with(')')))
+        .collect();
+    Some(format!("/{}", segments.join("/")))
+}
+
+// +server, +page and +layout modules (and their .server variants)
+pub fn is_route_module(file: &str) -> bool {
+    let Some(name) = file.rsplit('/').next() else {
+        return false;
+    };
+    let is_script = name.ends_with(".ts") || name.ends_with(".js");
+    let is_route = ["+server.", "+page.", "+layout."]
+        .iter()
+        .any(|prefix| name.starts_with(prefix));
+    is_script && is_route && route_from_path(file).is_some()
+}
+
+pub fn is_endpoint_handler(file: &str, func_name: &str) -> bool {
+    let name = file.rsplit('/').next().unwrap_or_default();
+    name.starts_with("+server.") && ENDPOINT_VERBS.contains(&func_name)
+}
+
+// the component a +page.ts or +layout.server.js module loads data for
+pub fn route_component(file: &str) -> Option<String> {
+    let (dir, name) = file.rsplit_once('/')?;
+    let kind = if name.starts_with("+page.") {
+        "+page"
+    } else if name.starts_with("+layout.") {
+        "+layout"
+    } else {
+        return None;
+    };
+    Some(format!("{}/{}.svelte", dir, kind))
+}
+
+pub struct RouteFunction {
+    pub func: NodeData,
+    pub exported: bool,
+    // the names of the functions it calls
+    pub calls: Vec<String>,
+}
+
+// the top level functions of a route module, like load, GET or their helpers
+pub fn route_functions(code: &str, file: &str) -> Vec<RouteFunction> {
+    let language: Language = tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into();
+    let function = r#"[
+            (function_declaration
+                name: (identifier) @name
+            )
+            (lexical_declaration
+                (variable_declarator
+                    name: (identifier) @name
+                    value: [(arrow_function) (function_expression)]
+                )
+            )
+        ]"#;
+    let query_str = format!(
+        r#"
+    (program (export_statement declaration: {function}) @export)
+    (program {function} @function)
+    "#
+    );
+    let calls_str = r#"
+    (call_expression
+        function: [
+            (identifier) @callee
+            (member_expression property: (property_identifier) @callee)
+        ]
+    )
+    "#;
+    let mut res = Vec::new();
+    let (Ok(query), Ok(calls_query)) = (
+        Query::new(&language, &query_str),
+        Query::new(&language, calls_str),
+    ) else {
+        return res;
+    };
+    let mut parser = Parser::new();
+    if parser.set_language(&language).is_err() {
+        return res;
+    }
+    let Some(tree) = parser.parse(code, None) else {
+        return res;
+    };
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(&query, tree.root_node(), code.as_bytes());
+    while let Some(m) = matches.next() {
+        let mut rf = RouteFunction {
+            func: NodeData::in_file(file),
+            exported: false,
+            calls: Vec::new(),
+        };
+        for cap in m.captures.iter() {
+            let text = cap.node.utf8_text(code.as_bytes()).unwrap_or_default();
+            let capture = query.capture_names()[cap.index as usize];
+            if capture == "name" {
+                rf.func.name = text.to_string();
+                continue;
+            }
+            rf.exported = capture == "export";
+            rf.func.body = text.to_string();
+            rf.func.start = cap.node.start_position().row;
+            rf.func.end = cap.node.end_position().row;
+            let mut calls_cursor = QueryCursor::new();
+            let mut calls = calls_cursor.matches(&calls_query, cap.node, code.as_bytes());
+            while let Some(call) = calls.next() {
+                for callee in call.captures.iter() {
+                    let name = callee.node.utf8_text(code.as_bytes()).unwrap_or_default();
+                    if !rf.calls.iter().any(|c| c == name) {
+                        rf.calls.push(name.to_string());
+                    }
+                }
+            }
+        }
+        res.push(rf);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_from_path() {
+        let route = |f: &str| route_from_path(f);
+        assert_eq!(route("app/src/routes/+page.svelte"), Some("/".to_string()));
+        assert_eq!(
+            route("app/src/routes/(marketing)/about/+page.svelte"),
+            Some("/about".to_string())
+        );
+        assert_eq!(
+            route("app/src/routes/api/posts/[id]/+server.ts"),
+            Some("/api/posts/[id]".to_string())
+        );
+        assert_eq!(route("app/src/routes/index.ts"), None);
+        assert_eq!(route("app/src/lib/+page.svelte"), None);
+        assert!(is_route_module("app/src/routes/blog/[slug]/+page.server.ts"));
+        assert!(!is_route_module("app/src/routes/blog/[slug]/+page.svelte"));
+        assert_eq!(
+            route_component("app/src/routes/blog/+layout.server.js"),
+            Some("app/src/routes/blog/+layout.svelte".to_string())
+        );
+        assert_eq!(route_component("app/src/routes/api/+server.ts"), None);
+    }
+
+    #[test]
+    fn test_route_functions() {
+        let code = r#"import { json } from '@sveltejs/kit';
+import type { RequestHandler } from './$types';
+
+export const prerender = false;
+
+export async function GET({ params }) {
+    return json({ id: params.id, post: helper() });
+}
+
+export const DELETE: RequestHandler = async ({ params }) => {
+    return new Response(null, { status: 204 });
+};
+
+function helper() {}
+"#;
+        let funcs = route_functions(code, "src/routes/api/posts/[id]/+server.ts");
+        let names: Vec<(&str, bool)> = funcs
+            .iter()
+            .map(|f| (f.func.name.as_str(), f.exported))
+            .collect();
+        assert_eq!(
+            names,
+            vec![("GET", true), ("DELETE", true), ("helper", false)]
+        );
+        assert_eq!((funcs[0].func.start, funcs[0].func.end), (5, 7));
+        assert_eq!(funcs[0].calls, vec!["json", "helper"]);
+        assert!(is_endpoint_handler(&funcs[1].func.file, &funcs[1].func.name));
+        assert!(!is_endpoint_handler("src/routes/+page.ts", "load"));
+    }
 }
@@ -1,5 +1,6 @@
 use super::super::*;
 use super::consts::*;
+use super::react::classify_js_test;
 use shared::error::{Context, Result};
 use tree_sitter::{Language, Parser, Query, Tree};
 
@@ -133,18 +134,133 @@ impl Stack for TypeScript {
         )
     }
 
+    fn instance_definition_query(&self) -> Option<String> {
+        // constructor injected providers: constructor(private readonly usersService: UsersService)
+        Some(format!(
+            r#"(method_definition
+                name: (property_identifier) @constructor (#eq? @constructor "constructor")
+                parameters: (formal_parameters
+                    (required_parameter
+                        (accessibility_modifier)
+                        pattern: (identifier) @{INSTANCE_NAME}
+                        type: (type_annotation
+                            (type_identifier) @{CLASS_NAME}
+                        )
+                    ) @{INSTANCE}
+                )
+            )"#
+        ))
+    }
+
     fn function_definition_query(&self) -> String {
+        // methods of nestjs controllers and providers come first,
+        // so the parent type is known before the function definition.
+        // apollo resolver maps ({ Query: { user: () => ... } }) come last
+        let nest_decorator = r#"decorator: (decorator
+                    (call_expression
+                        function: (identifier) @nest (#match? @nest "^(Controller|Injectable)$")
+                    )
+                )"#;
+        let nest_members = format!(
+            r#"name: (type_identifier) @{PARENT_TYPE}
+                    body: (class_body
+                        (method_definition
+                            name: (property_identifier) @{FUNCTION_NAME} (#not-eq? @{FUNCTION_NAME} "constructor")
+                            parameters: (formal_parameters)? @{ARGUMENTS}
+                            return_type: (type_annotation)? @{RETURN_TYPES}
+                        ) @{FUNCTION_DEFINITION}
+                    )"#
+        );
         format!(
             r#"
+            (export_statement
+                {nest_decorator}
+                declaration: (class_declaration
+                    {nest_members}
+                )
+            )
+            (class_declaration
+                {nest_decorator}
+                {nest_members}
+            )
             (function_declaration
                 name: (identifier) @{FUNCTION_NAME}
                 parameters : (formal_parameters)? @{ARGUMENTS}
                 return_type: (type_annotation)? @{RETURN_TYPES}
             ) @{FUNCTION_DEFINITION}
+            (pair
+                key: (property_identifier) @resolvers (#match? @resolvers "^(Query|Mutation|Subscription)$")
+                value: (object
+                    [
+                        (pair
+                            key: (property_identifier) @{FUNCTION_NAME}
+                            value: [
+                                (arrow_function
+                                    parameters: (formal_parameters)? @{ARGUMENTS}
+                                )
+                                (function_expression
+                                    parameters: (formal_parameters) @{ARGUMENTS}
+                                )
+                            ]
+                        )
+                        (method_definition
+                            name: (property_identifier) @{FUNCTION_NAME}
+                            parameters: (formal_parameters) @{ARGUMENTS}
+                        )
+                    ] @{FUNCTION_DEFINITION}
+                )
+            )
             "#
         )
     }
 
+    fn test_query(&self) -> Option<String> {
+        // describe blocks, and it/test cases that are not inside one (jest, mocha, vitest)
+        Some(format!(
+            r#"[
+                    (call_expression
+                        function: (identifier) @desc (#eq? @desc "describe")
+                        arguments: (arguments [ (string) (template_string) ] @{FUNCTION_NAME})
+                    )
+                    (call_expression
+                        function: (member_expression
+                            object: (identifier) @desc2 (#eq? @desc2 "describe")
+                            property: (property_identifier) @mod (#match? @mod "^(only|skip|todo)$")
+                        )
+                        arguments: (arguments [ (string) (template_string) ] @{FUNCTION_NAME})
+                    )
+                ] @{FUNCTION_DEFINITION}
+
+                (program
+                    (expression_statement
+                        (call_expression
+                            function: (identifier) @test (#match? @test "^(it|test)$")
+                            arguments: (arguments [ (string) (template_string) ] @{FUNCTION_NAME})
+                        ) @{FUNCTION_DEFINITION}
+                    )
+                )"#
+        ))
+    }
+
+    fn e2e_test_query(&self) -> Option<String> {
+        // playwright's test.describe. describe blocks are already matched by the test query,
+        // and classify_js_test tells the e2e ones apart by their file and body
+        Some(format!(
+            r#"
+                (call_expression
+                    function: (member_expression
+                        object: (identifier) @pwtest (#eq? @pwtest "test")
+                        property: (property_identifier) @method (#eq? @method "describe")
+                    )
+                    arguments: (arguments
+                        [ (string) (template_string) ] @{E2E_TEST_NAME}
+                        (arrow_function)
+                    )
+                ) @{E2E_TEST}
+            "#
+        ))
+    }
+
     fn comment_query(&self) -> Option<String> {
         Some(format!(r#"(comment) @{FUNCTION_COMMENT}"#))
     }
@@ -164,13 +280,50 @@ impl Stack for TypeScript {
                 )
                     arguments: (arguments) @{ARGUMENTS}
             )@{FUNCTION_CALL}
+
+            (call_expression
+                function: (member_expression
+                    object: (member_expression
+                        object: (this)
+                        property: (property_identifier) @{OPERAND}
+                    )
+                    property: (property_identifier) @{FUNCTION_NAME}
+                )
+                arguments: (arguments) @{ARGUMENTS}
+            )@{FUNCTION_CALL}
             "#
         )
     }
 
     fn endpoint_finders(&self) -> Vec<String> {
-        vec![format!(
-            r#"(call_expression
+        // nestjs: @Controller('users') on the class, @Get(':id') on the handler method
+        let nest_routes = format!(
+            r#"body: (class_body
+                    (decorator
+                        (call_expression
+                            function: (identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^(Get|Post|Put|Patch|Delete)$")
+                            arguments: (arguments . (string)? @{ENDPOINT})
+                        )
+                    ) @{ROUTE}
+                    .
+                    (decorator)*
+                    .
+                    (method_definition
+                        name: (property_identifier) @{HANDLER}
+                    )
+                )"#
+        );
+        let controller = format!(
+            r#"decorator: (decorator
+                    (call_expression
+                        function: (identifier) @controller (#eq? @controller "Controller")
+                        arguments: (arguments . (string)? @{ENDPOINT_PREFIX})
+                    )
+                )"#
+        );
+        vec![
+            format!(
+                r#"(call_expression
                 function: (member_expression
                     object: (identifier)
                     property: (property_identifier) @{ENDPOINT_VERB} (#match? @{ENDPOINT_VERB} "^get$|^post$|^put$|^delete$")
@@ -181,8 +334,40 @@ impl Stack for TypeScript {
                 )
                 ) @{ROUTE}
             "#
-        )]
+            ),
+            format!(
+                r#"(export_statement
+                {controller}
+                declaration: (class_declaration
+                    {nest_routes}
+                )
+            )"#
+            ),
+            format!(
+                r#"(class_declaration
+                {controller}
+                {nest_routes}
+            )"#
+            ),
+        ]
     }
+    fn request_finder(&self) -> Option<String> {
+        // supertest: request(app).get('/items')
+        Some(format!(
+            r#"
+                (call_expression
+                    function: (member_expression
+                        object: (call_expression
+                            function: (identifier) @lib (#match? @lib "^(request|supertest)$")
+                        )
+                        property: (property_identifier) @{REQUEST_CALL} (#match? @{REQUEST_CALL} "^(get|post|put|delete|patch)$")
+                    )
+                    arguments: (arguments . [ (string) (template_string) ] @{ENDPOINT})
+                ) @{ROUTE}
+            "#
+        ))
+    }
+
     fn add_endpoint_verb(&self, inst: &mut NodeData, call: &Option<String>) {
         if let Some(c) = call {
             let verb = match c.as_str() {
@@ -190,6 +375,7 @@ impl Stack for TypeScript {
                 "post" => "POST",
                 "put" => "PUT",
                 "delete" => "DELETE",
+                "patch" => "PATCH",
                 _ => "",
             };
 
@@ -199,6 +385,35 @@ impl Stack for TypeScript {
         }
     }
 
+    fn update_endpoint(&self, nd: &mut NodeData, _call: &Option<String>) {
+        // nestjs paths are written without the leading slash: @Controller('users')
+        if nd.meta.get("handler").is_some() && !nd.name.starts_with('/') {
+            nd.name = format!("/{}", nd.name);
+        }
+    }
+
+    fn find_function_parent(
+        &self,
+        node: TreeNode,
+        _code: &str,
+        file: &str,
+        func_name: &str,
+        find_class: &dyn Fn(&str) -> Option<NodeData>,
+        parent_type: Option<&str>,
+    ) -> Result<Option<Operand>> {
+        let Some(parent_type) = parent_type else {
+            return Ok(None);
+        };
+        Ok(find_class(parent_type).map(|class| Operand {
+            source: NodeKeys::new(&class.name, &class.file, class.start),
+            target: NodeKeys::new(func_name, file, node.start_position().row),
+        }))
+    }
+    // nestjs providers are injected as constructor params, so calls go through this.service
+    fn use_operand_finder(&self) -> bool {
+        true
+    }
+
     /*
     POSSIBLE QUERY FOR DATA MODEL that picks up interfaces without methods -- needs work
     (interface_declaration
@@ -312,4 +527,22 @@ impl Stack for TypeScript {
 
         path
     }
+
+    fn is_test_file(&self, file_name: &str) -> bool {
+        file_name.contains("__tests__")
+            || file_name.ends_with(".test.ts")
+            || file_name.ends_with(".test.js")
+            || file_name.ends_with(".spec.ts")
+            || file_name.ends_with(".spec.js")
+            || file_name.ends_with(".e2e.ts")
+            || file_name.ends_with(".e2e.js")
+    }
+
+    fn is_test(&self, _func_name: &str, func_file: &str) -> bool {
+        self.is_test_file(func_file)
+    }
+
+    fn classify_test(&self, name: &str, file: &str, body: &str) -> NodeType {
+        classify_js_test(name, file, body)
+    }
 }
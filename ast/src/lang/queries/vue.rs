@@ -0,0 +1,399 @@
+use super::super::*;
+use super::consts::*;
+use shared::error::{Context, Result};
+use tree_sitter::{Language, Parser, Query, Tree};
+
+pub struct Vue(Language);
+
+impl Vue {
+    pub fn new() -> Self {
+        Vue(tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into())
+    }
+}
+
+impl Stack for Vue {
+    fn q(&self, q: &str, _nt: &NodeType) -> Query {
+        Query::new(&self.0, q).unwrap()
+    }
+
+    fn parse(&self, code: &str, _nt: &NodeType) -> Result<Tree> {
+        let mut parser = Parser::new();
+        parser.set_language(&self.0)?;
+        match script_blocks(code) {
+            Some(script) => parser.parse(&script, None).context("failed to parse"),
+            None => parser.parse(code, None).context("failed to parse"),
+        }
+    }
+
+    fn lib_query(&self) -> Option<String> {
+        Some(format!(
+            r#"(pair
+                key: (string (_) @dependency_type) (#match? @dependency_type "^(dependencies|devDependencies)$")
+                value: (object
+                    (pair
+                    key: (string (_) @{LIBRARY_NAME}) (#match? @{LIBRARY_NAME} "^[@a-zA-Z]")
+                    value: (string (_) @{LIBRARY_VERSION}) (#match? @{LIBRARY_VERSION} "^[\\^~]?\\d|\\*")
+                    ) @{LIBRARY}
+                )
+                )"#
+        ))
+    }
+
+    fn is_lib_file(&self, file_name: &str) -> bool {
+        file_name.contains("node_modules/")
+    }
+
+    fn is_component(&self, func_name: &str) -> bool {
+        func_name
+            .chars()
+            .next()
+            .map(|c| c.is_uppercase())
+            .unwrap_or(false)
+    }
+
+    fn component_name_from_file(&self, file: &str) -> Option<String> {
+        // UserCard.vue and user-card.vue are both registered as UserCard
+        let stem = file.rsplit('/').next()?.strip_suffix(".vue")?;
+        let name = stem
+            .split(|c| c == '-' || c == '_' || c == '.')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                    None => String::new(),
+                }
+            })
+            .collect::<String>();
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    }
+
+    fn skip_unnamed_functions(&self) -> bool {
+        // the whole-program match is only a component in .vue files
+        true
+    }
+
+    fn imports_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            (import_statement
+                (import_clause
+                    (identifier)? @{IMPORTS_NAME}
+                    (named_imports
+                        (import_specifier
+                            name:(identifier) @{IMPORTS_NAME}
+                        )
+                    )?
+
+                )?
+                source: (string) @{IMPORTS_FROM}
+            )@{IMPORTS}
+            (export_statement
+                (export_clause
+                    (export_specifier
+                        name: (identifier)@{IMPORTS_NAME}
+                    )
+                )
+                source: (string) @{IMPORTS_FROM}
+            )@{IMPORTS}
+            "#,
+        ))
+    }
+
+    fn variables_query(&self) -> Option<String> {
+        let types = "(string)(template_string)(number)(object)(array)(true)(false)(new_expression)";
+        Some(format!(
+            r#"(program
+                    (export_statement
+                        (lexical_declaration
+                            (variable_declarator
+                                name: (identifier) @{VARIABLE_NAME}
+                                type: (_)? @{VARIABLE_TYPE}
+                                value: [{types}]+ @{VARIABLE_VALUE}
+                            )
+                        )
+                    )?@{VARIABLE_DECLARATION}
+                )
+                (program
+                        (lexical_declaration
+                            (variable_declarator
+                                name: (identifier) @{VARIABLE_NAME}
+                                type: (_)? @{VARIABLE_TYPE}
+                                value: [{types}]+ @{VARIABLE_VALUE}
+                            )
+                        )@{VARIABLE_DECLARATION}
+                )"#,
+        ))
+    }
+
+    fn class_definition_query(&self) -> String {
+        format!(
+            r#"
+            (class_declaration
+                name: (type_identifier) @{CLASS_NAME}
+            ) @{CLASS_DEFINITION}
+            "#
+        )
+    }
+
+    fn function_definition_query(&self) -> String {
+        // the script of a single-file component is the component itself,
+        // it has no name node so it is named after its file. Captures are read in
+        // the order they first appear here, so it goes last: the named patterns
+        // must capture the name before the definition
+        format!(
+            r#"
+            [
+                (function_declaration
+                    name: (identifier) @{FUNCTION_NAME}
+                    parameters: (formal_parameters)? @{ARGUMENTS}
+                    return_type: (type_annotation)? @{RETURN_TYPES}
+                ) @{FUNCTION_DEFINITION}
+
+                (program
+                    (lexical_declaration
+                        (variable_declarator
+                            name: (identifier) @{FUNCTION_NAME}
+                            value: [
+                                (arrow_function
+                                    parameters: (formal_parameters)? @{ARGUMENTS}
+                                    return_type: (type_annotation)? @{RETURN_TYPES}
+                                )
+                                (function_expression
+                                    parameters: (formal_parameters)? @{ARGUMENTS}
+                                    return_type: (type_annotation)? @{RETURN_TYPES}
+                                )
+                            ]
+                        )
+                    ) @{FUNCTION_DEFINITION}
+                )
+
+                (program
+                    (export_statement
+                        (lexical_declaration
+                            (variable_declarator
+                                name: (identifier) @{FUNCTION_NAME}
+                                value: (arrow_function
+                                    parameters: (formal_parameters)? @{ARGUMENTS}
+                                    return_type: (type_annotation)? @{RETURN_TYPES}
+                                )
+                            )
+                        )
+                    ) @{FUNCTION_DEFINITION}
+                )
+
+                (pair
+                    key: (property_identifier) @option (#match? @option "^(methods|computed|watch)$")
+                    value: (object
+                        (method_definition
+                            name: (property_identifier) @{FUNCTION_NAME}
+                            parameters: (formal_parameters)? @{ARGUMENTS}
+                            return_type: (type_annotation)? @{RETURN_TYPES}
+                        ) @{FUNCTION_DEFINITION}
+                    )
+                )
+
+                (program) @{FUNCTION_DEFINITION}
+            ]
+            "#
+        )
+    }
+
+    fn comment_query(&self) -> Option<String> {
+        Some(format!(r#"(comment) @{FUNCTION_COMMENT}"#))
+    }
+
+    fn function_call_query(&self) -> String {
+        format!(
+            r#"
+            (call_expression
+                function: [
+                    (identifier) @{FUNCTION_NAME}
+                    (member_expression
+                        object: [
+                            (identifier)
+                            (this)
+                        ] @{OPERAND}
+                        property: (property_identifier) @{FUNCTION_NAME}
+                    )
+                ]
+                arguments: (arguments) @{ARGUMENTS}
+            ) @{FUNCTION_CALL}
+            "#
+        )
+    }
+
+    fn request_finder(&self) -> Option<String> {
+        Some(format!(
+            r#"
+                ;; Matches: fetch('/api/...')
+                (call_expression
+                    function: (identifier) @{REQUEST_CALL} (#eq? @{REQUEST_CALL} "fetch")
+                    arguments: (arguments [ (string) (template_string) ] @{ENDPOINT})
+                ) @{ROUTE}
+
+                ;; Matches: axios.get('/api/...'), api.post('/api/...') etc.
+                (call_expression
+                    function: (member_expression
+                        object: (identifier) @lib
+                        property: (property_identifier) @{REQUEST_CALL} (#match? @{REQUEST_CALL} "^(get|post|put|delete|patch)$")
+                    )
+                    arguments: (arguments [ (string) (template_string) ] @{ENDPOINT})
+                ) @{ROUTE}
+
+                ;; Matches: axios({{ url: '/api/...' }})
+                (call_expression
+                    function: (identifier) @lib (#eq? @lib "axios")
+                    arguments: (arguments
+                        (object
+                            (pair
+                                key: (property_identifier) @url_key (#eq? @url_key "url")
+                                value: [ (string) (template_string) ] @{ENDPOINT}
+                            )
+                        )
+                    )
+                ) @{ROUTE}
+            "#
+        ))
+    }
+
+    fn add_endpoint_verb(&self, inst: &mut NodeData, call: &Option<String>) {
+        if inst.meta.get("verb").is_none() {
+            if let Some(call) = call {
+                match call.as_str() {
+                    "get" => inst.add_verb("GET"),
+                    "post" => inst.add_verb("POST"),
+                    "put" => inst.add_verb("PUT"),
+                    "patch" => inst.add_verb("PATCH"),
+                    "delete" => inst.add_verb("DELETE"),
+                    "fetch" => {
+                        inst.body.find("GET").map(|_| inst.add_verb("GET"));
+                        inst.body.find("POST").map(|_| inst.add_verb("POST"));
+                        inst.body.find("PUT").map(|_| inst.add_verb("PUT"));
+                        inst.body.find("DELETE").map(|_| inst.add_verb("DELETE"));
+                    }
+                    _ => (),
+                }
+            }
+        }
+        if inst.meta.get("verb").is_none() {
+            inst.add_verb("GET");
+        }
+    }
+
+    fn is_router_file(&self, file_name: &str, code: &str) -> bool {
+        !file_name.ends_with(".vue") && code.contains("vue-router")
+    }
+
+    fn page_query(&self) -> Option<String> {
+        // vue-router route records, with eager or lazily imported components
+        Some(format!(
+            r#"
+            (object
+                (pair
+                    key: (property_identifier) @path-key (#eq? @path-key "path")
+                    value: (string) @{PAGE_PATHS}
+                )
+                (pair
+                    key: (property_identifier) @component-key (#eq? @component-key "component")
+                    value: [
+                        (identifier) @{PAGE_COMPONENT}
+                        (arrow_function
+                            body: (call_expression
+                                function: (import)
+                                arguments: (arguments
+                                    (string
+                                        (string_fragment) @{PAGE_COMPONENT}
+                                    )
+                                )
+                            )
+                        )
+                    ]
+                )
+            ) @{PAGE}
+            "#
+        ))
+    }
+
+    fn data_model_query(&self) -> Option<String> {
+        Some(format!(
+            r#"
+            [
+                (interface_declaration
+                    name: (type_identifier) @{STRUCT_NAME}
+                )
+                (type_alias_declaration
+                    name: (type_identifier) @{STRUCT_NAME}
+                )
+                (enum_declaration
+                    name: (identifier) @{STRUCT_NAME}
+                )
+            ] @{STRUCT}
+            "#
+        ))
+    }
+
+    fn data_model_within_query(&self) -> Option<String> {
+        Some(format!(
+            r#"(
+                (type_identifier) @{STRUCT_NAME} (#match? @{STRUCT_NAME} "^[A-Z].*")
+            )"#
+        ))
+    }
+
+    fn resolve_import_path(&self, import_path: &str, _current_file: &str) -> String {
+        let path = import_path
+            .trim()
+            .trim_matches(|c| c == '"' || c == '\'' || c == '`');
+        path.trim_start_matches("./").to_string()
+    }
+
+    fn is_test_file(&self, file_name: &str) -> bool {
+        file_name.contains("__tests__")
+            || file_name.ends_with(".spec.ts")
+            || file_name.ends_with(".spec.js")
+            || file_name.ends_with(".test.ts")
+            || file_name.ends_with(".test.js")
+    }
+
+    fn is_test(&self, _func_name: &str, func_file: &str) -> bool {
+        self.is_test_file(func_file)
+    }
+}
+
+/// Blanks out everything but the <script> and <script setup> blocks of a
+/// single-file component, keeping byte offsets and line numbers intact.
+/// Returns None for plain .ts/.js files.
+fn script_blocks(code: &str) -> Option<String> {
+    let is_sfc = code.lines().any(|line| {
+        let line = line.trim_start();
+        line.starts_with("<template") || line.starts_with("<script")
+    });
+    if !is_sfc {
+        return None;
+    }
+    let bytes = code.as_bytes();
+    let mut out: Vec<u8> = bytes
+        .iter()
+        .map(|b| if *b == b'\n' { b'\n' } else { b' ' })
+        .collect();
+    let mut rest = 0;
+    while let Some(open) = code[rest..].find("<script") {
+        let open = rest + open;
+        let Some(tag_end) = code[open..].find('>') else {
+            break;
+        };
+        let start = open + tag_end + 1;
+        let Some(close) = code[start..].find("</script>") else {
+            break;
+        };
+        let end = start + close;
+        out[start..end].copy_from_slice(&bytes[start..end]);
+        rest = end + "</script>".len();
+    }
+    String::from_utf8(out).ok()
+}
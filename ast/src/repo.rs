@@ -1,6 +1,7 @@
 pub use crate::builder::progress::StatusUpdate;
+use crate::builder::shared::process_shared_files;
 use crate::lang::graphs::Graph;
-use crate::lang::{linker, ArrayGraph, BTreeMapGraph, Lang};
+use crate::lang::{linker, openapi, queries::bash, ArrayGraph, BTreeMapGraph, Lang};
 #[cfg(feature = "neo4j")]
 use crate::builder::streaming::{GraphStreamingUploader, drain_deltas};
 #[cfg(feature = "neo4j")]
@@ -10,6 +11,7 @@ use ignore::WalkBuilder;
 use lsp::language::{Language, PROGRAMMING_LANGUAGES};
 use lsp::{git::git_clone, spawn_analyzer, strip_tmp, CmdSender};
 use shared::{Context, Error, Result};
+use std::collections::HashSet;
 use std::str::FromStr;
 use std::{fs, path::PathBuf};
 use tokio::sync::broadcast::Sender;
@@ -17,6 +19,8 @@ use tracing::{info, warn};
 use walkdir::{DirEntry, WalkDir};
 
 const CONF_FILE_PATH: &str = ".ast.json";
+// package files that make their directory the root of a project
+const PROJECT_FILES: [&str; 4] = ["go.mod", "package.json", "Cargo.toml", "pyproject.toml"];
 
 pub async fn clone_repo(
     url: &str,
@@ -50,6 +54,15 @@ pub struct Repo {
     pub files_filter: Vec<String>,
     pub revs: Vec<String>,
     pub status_tx: Option<Sender<StatusUpdate>>,
+    pub project: Option<Project>, // set when the repo is one project of a monorepo
+}
+
+// A sub-project of a monorepo, found by its package file
+#[derive(Debug, Clone)]
+pub struct Project {
+    pub repo_root: PathBuf,
+    // roots of the projects of the same language below this one
+    pub nested: Vec<PathBuf>,
 }
 
 pub struct Repos(pub Vec<Repo>);
@@ -80,10 +93,14 @@ impl Repos {
             let _ = neo.connect().await; 
             Some((neo, GraphStreamingUploader::new()))
         } else { None };
+        let mut filez: Vec<(String, String)> = Vec::new();
+        let mut seen = HashSet::new();
         for repo in &self.0 {
             info!("building graph for {:?}", repo);
-            let subgraph = repo.build_graph_inner().await?;
+            let (subgraph, files) = repo.build_repo_graph(false).await?;
             graph.extend_graph(subgraph);
+            // every repo reads the shared files, so keep one copy of each
+            filez.extend(files.into_iter().filter(|(f, _)| seen.insert(f.clone())));
             #[cfg(feature = "neo4j")]
             if let Some((neo, uploader)) = &mut streaming {
                 let (dn,de) = drain_deltas();
@@ -93,6 +110,9 @@ impl Repos {
             }
         }
 
+        info!("processing shared files");
+        process_shared_files(&mut graph, &filez);
+
         if let Some(first_repo) = &self.0.get(0) {
             first_repo.send_status_update("linking_graphs", 14);
         }
@@ -100,6 +120,8 @@ impl Repos {
         linker::link_e2e_tests(&mut graph)?;
         info!("linking api nodes");
         linker::link_api_nodes(&mut graph)?;
+        info!("linking openapi endpoints");
+        linker::link_openapi_endpoints(&mut graph)?;
         #[cfg(feature = "neo4j")]
         if let Some((neo, uploader)) = &mut streaming {
             let (dn,de) = drain_deltas();
@@ -157,6 +179,7 @@ impl Repo {
             files_filter,
             revs,
             status_tx: None,
+            project: None,
         })
     }
     pub async fn new_clone_multi_detect(
@@ -219,83 +242,73 @@ impl Repo {
         revs: Vec<String>,
         use_lsp: Option<bool>,
     ) -> Result<Repos> {
-        // First, collect all detected languages
-        let mut detected_langs: Vec<Language> = Vec::new();
-        for l in PROGRAMMING_LANGUAGES {
-            if let Ok(only_lang) = std::env::var("ONLY_LANG") {
-                if only_lang != l.to_string() {
-                    continue;
+        let repo_root = PathBuf::from(root);
+        let files = walk_all_files(&repo_root).map_err(|e| {
+            Error::Custom(format!("Failed to walk files at {}: {}", root, e))
+        })?;
+        let roots = project_roots(&repo_root, &files);
+        let buckets = bucket_files(files, &roots);
+        // Detect languages per project, deepest first, so each project can
+        // leave out the nested projects of the same language
+        let mut projects: Vec<(PathBuf, Vec<Language>)> = Vec::new();
+        for dir in roots {
+            let detected = detect_languages(&dir, &repo_root, &projects, &buckets);
+            projects.push((dir, detected));
+        }
+        let is_monorepo = projects
+            .iter()
+            .any(|(dir, langs)| dir != &repo_root && !langs.is_empty());
+
+        // Then, set up each repository with LSP, from the top down
+        let mut repos: Vec<Repo> = Vec::new();
+        for (dir, detected_langs) in projects.iter().rev() {
+            // Filter out overridden languages
+            let mut overridden_langs: Vec<Language> = Vec::new();
+            for lang in detected_langs {
+                for overridden in lang.overrides() {
+                    overridden_langs.push(overridden);
                 }
             }
-            let conf = Config {
-                exts: stringy(l.exts()),
-                skip_dirs: stringy(l.skip_dirs()),
-                ..Default::default()
-            };
-            let source_files = walk_files(&root.into(), &conf)
-                .map_err(|e| Error::Custom(format!("Failed to walk files at {}: {}", root, e)))?;
-            let has_pkg_file = source_files.iter().any(|f| {
-                let fname = f.display().to_string();
-                if l.pkg_files().is_empty() {
-                    return true;
+            let filtered_langs: Vec<&Language> = detected_langs
+                .iter()
+                .filter(|lang| !overridden_langs.contains(lang))
+                .collect();
+            let dir_str = dir.display().to_string();
+            for l in filtered_langs {
+                let thelang = Lang::from_language(l.clone());
+                // Run post-clone commands
+                for cmd in thelang.kind.post_clone_cmd() {
+                    Self::run_cmd(&cmd, &dir_str).map_err(|e| {
+                        Error::Custom(format!("Failed to cmd {} in {}: {}", cmd, dir_str, e))
+                    })?;
                 }
-                let found_pkg_file = l
-                    .pkg_files()
-                    .iter()
-                    .any(|pkg_file| fname.ends_with(pkg_file));
-                found_pkg_file
-            });
-            if has_pkg_file {
-                // Don't add duplicate languages
-                if !detected_langs.iter().any(|lang| lang == &l) {
-                    detected_langs.push(l);
-                }
-            }
-        }
-        // Filter out overridden languages
-        let mut overridden_langs: Vec<Language> = Vec::new();
-        for lang in &detected_langs {
-            for overridden in lang.overrides() {
-                overridden_langs.push(overridden);
+                // Start LSP server
+                let lsp_enabled = use_lsp.unwrap_or_else(|| thelang.kind.default_do_lsp());
+                let lsp_tx = Self::start_lsp(&dir_str, &thelang, lsp_enabled)
+                    .map_err(|e| Error::Custom(format!("Failed to start LSP: {}", e)))?;
+                let project = is_monorepo.then(|| Project {
+                    repo_root: repo_root.clone(),
+                    nested: nested_projects(dir, l, &projects),
+                });
+                // Add to repositories
+                repos.push(Repo {
+                    url: url.clone().map(|u| u.into()).unwrap_or_default(),
+                    root: dir.clone(),
+                    lang: thelang,
+                    lsp_tx,
+                    files_filter: files_filter.clone(),
+                    revs: revs.clone(),
+                    status_tx: None,
+                    project,
+                });
             }
         }
-        let filtered_langs: Vec<Language> = detected_langs
-            .into_iter()
-            .filter(|lang| !overridden_langs.contains(lang))
-            .collect();
-
-        if filtered_langs.is_empty() {
+        if repos.is_empty() {
             return Err(Error::Custom(format!(
                 "Language is not supported yet: {}",
                 root
             )));
         }
-        // Then, set up each repository with LSP
-        let mut repos: Vec<Repo> = Vec::new();
-        for l in filtered_langs {
-            let thelang = Lang::from_language(l);
-            // Run post-clone commands
-            for cmd in thelang.kind.post_clone_cmd() {
-                Self::run_cmd(&cmd, &root).map_err(|e| {
-                    Error::Custom(format!("Failed to cmd {} in {}: {}", cmd, root, e))
-                })?;
-            }
-            // Start LSP server
-            let lsp_enabled = use_lsp.unwrap_or_else(|| thelang.kind.default_do_lsp());
-            let lsp_tx = Self::start_lsp(&root, &thelang, lsp_enabled)
-                .map_err(|e| Error::Custom(format!("Failed to start LSP: {}", e)))?;
-            // Add to repositories
-            repos.push(Repo {
-                url: url.clone().map(|u| u.into()).unwrap_or_default(),
-                root: root.into(),
-                lang: thelang,
-                lsp_tx,
-                files_filter: files_filter.clone(),
-                revs: revs.clone(),
-                status_tx: None,
-            });
-        }
-        println!("REPOS!!! {:?}", repos);
         Ok(Repos(repos))
     }
     pub async fn new_clone_to_tmp(
@@ -328,6 +341,7 @@ impl Repo {
             files_filter,
             revs,
             status_tx: None,
+            project: None,
         })
     }
     fn run_cmd(cmd: &str, root: &str) -> Result<()> {
@@ -388,13 +402,15 @@ impl Repo {
     pub fn collect(&self) -> Result<Vec<PathBuf>> {
         let conf = self.merge_config_with_lang();
         info!("CONFIG: {:?}", conf);
-        let source_files = walk_files(&self.root, &conf)?;
+        let mut source_files = walk_files(&self.root, &conf)?;
+        source_files.retain(|f| !self.in_nested_project(f));
         Ok(source_files)
     }
     pub fn collect_dirs_with_tmp(&self) -> Result<Vec<PathBuf>> {
         let conf = self.merge_config_with_lang();
         println!("==>>ROOT: {:?}", self.root);
-        let dirs = walk_dirs(&self.root, &conf)?;
+        let mut dirs = walk_dirs(&self.root, &conf)?;
+        dirs.retain(|d| !self.in_nested_project(d));
         Ok(dirs)
     }
     fn read_config_file(&self) -> Option<AstConfig> {
@@ -414,7 +430,8 @@ impl Repo {
         &self,
         yes_extra_page: impl Fn(&str) -> bool,
     ) -> Result<Vec<String>> {
-        let source_files = walk_files_arbitrary(&self.root, yes_extra_page)?;
+        let mut source_files = walk_files_arbitrary(&self.root, yes_extra_page)?;
+        source_files.retain(|f| !self.in_nested_project(std::path::Path::new(f)));
         Ok(source_files)
     }
     pub fn get_last_revisions(path: &str, count: usize) -> Result<Vec<String>> {
@@ -458,7 +475,7 @@ impl Repo {
             match result {
                 Ok(entry) => {
                     let path = entry.path();
-                    if path.is_file() {
+                    if path.is_file() && !self.in_nested_project(path) {
                         let relative_path = strip_tmp(path).display().to_string();
 
                         if self.should_not_include(path, &relative_path) {
@@ -474,6 +491,13 @@ impl Repo {
         }
         Ok(all_files)
     }
+    // files of a nested project belong to that project's Repo
+    fn in_nested_project(&self, path: &std::path::Path) -> bool {
+        self.project
+            .as_ref()
+            .map(|p| p.nested.iter().any(|n| path.starts_with(n)))
+            .unwrap_or(false)
+    }
     fn should_not_include(&self, path: &std::path::Path, relative_path: &str) -> bool {
         let conf = self.merge_config_with_lang();
         let fname = path.display().to_string();
@@ -493,6 +517,10 @@ impl Repo {
             }
         }
 
+        if openapi::is_openapi_path(relative_path) {
+            return false;
+        }
+
         if self.lang.kind.is_package_file(relative_path) {
             return false;
         }
@@ -503,6 +531,11 @@ impl Repo {
             }
         }
 
+        // package.json scripts and make targets can run the shell scripts
+        if self.lang.kind == Language::Bash && bash::is_script_runner_file(relative_path) {
+            return false;
+        }
+
         for other_lang in PROGRAMMING_LANGUAGES {
             if other_lang == self.lang.kind {
                 continue;
@@ -526,6 +559,178 @@ impl Repo {
     }
 }
 
+// a repo is only split into projects when its root declares a workspace.
+// Otherwise the package files below the root are just part of one project
+fn is_workspace(root: &std::path::Path) -> bool {
+    if root.join("pnpm-workspace.yaml").is_file() || root.join("go.work").is_file() {
+        return true;
+    }
+    let cargo_workspace = fs::read_to_string(root.join("Cargo.toml"))
+        .map(|s| s.contains("[workspace]"))
+        .unwrap_or(false);
+    let npm_workspaces = fs::read_to_string(root.join("package.json"))
+        .ok()
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        .map(|v| v.get("workspaces").is_some())
+        .unwrap_or(false);
+    cargo_workspace || npm_workspaces
+}
+
+// the root, and in a workspace every directory with a project package file,
+// deepest first. The root is always a candidate, for the code outside of the
+// sub-projects
+fn project_roots(root: &PathBuf, files: &[PathBuf]) -> Vec<PathBuf> {
+    let mut roots = vec![root.clone()];
+    if is_workspace(root) {
+        for dir in files.iter().filter_map(|f| f.parent()) {
+            let dir = dir.to_path_buf();
+            if dir != *root && !roots.contains(&dir) && !project_files(&dir).is_empty() {
+                roots.push(dir);
+            }
+        }
+    }
+    roots.sort_by(|a, b| {
+        b.components()
+            .count()
+            .cmp(&a.components().count())
+            .then(a.cmp(b))
+    });
+    roots
+}
+
+// each file goes to the deepest project root above it
+fn bucket_files(files: Vec<PathBuf>, roots: &[PathBuf]) -> Vec<(PathBuf, Vec<PathBuf>)> {
+    let mut buckets: Vec<(PathBuf, Vec<PathBuf>)> =
+        roots.iter().map(|r| (r.clone(), Vec::new())).collect();
+    for file in files {
+        if let Some((_, bucket)) = buckets.iter_mut().find(|(r, _)| file.starts_with(r)) {
+            bucket.push(file);
+        }
+    }
+    buckets
+}
+
+// the project package files in a directory. A virtual Cargo workspace only
+// lists its members, which are projects of their own
+pub fn project_files(dir: &std::path::Path) -> Vec<&'static str> {
+    PROJECT_FILES
+        .into_iter()
+        .filter(|f| {
+            let path = dir.join(f);
+            path.is_file() && !(*f == "Cargo.toml" && is_virtual_manifest(&path))
+        })
+        .collect()
+}
+
+fn is_virtual_manifest(path: &std::path::Path) -> bool {
+    fs::read_to_string(path)
+        .map(|s| s.contains("[workspace]") && !s.contains("[package]"))
+        .unwrap_or(false)
+}
+
+// the projects of a language below a directory
+fn nested_projects(
+    dir: &PathBuf,
+    lang: &Language,
+    projects: &[(PathBuf, Vec<Language>)],
+) -> Vec<PathBuf> {
+    projects
+        .iter()
+        .filter(|(p, langs)| p != dir && p.starts_with(dir) && langs.contains(lang))
+        .map(|(p, _)| p.clone())
+        .collect()
+}
+
+// the languages of the project at dir (before overrides), leaving out the
+// already detected projects below it
+fn detect_languages(
+    dir: &PathBuf,
+    repo_root: &PathBuf,
+    projects: &[(PathBuf, Vec<Language>)],
+    buckets: &[(PathBuf, Vec<PathBuf>)],
+) -> Vec<Language> {
+    let mut detected_langs: Vec<Language> = Vec::new();
+    for l in PROGRAMMING_LANGUAGES {
+        if let Ok(only_lang) = std::env::var("ONLY_LANG") {
+            if only_lang != l.to_string() {
+                continue;
+            }
+        }
+        let has_ext = |f: &PathBuf| {
+            f.extension()
+                .and_then(|e| e.to_str())
+                .map(|e| l.exts().contains(&e))
+                .unwrap_or(false)
+        };
+        let nested = nested_projects(dir, &l, projects);
+        let source_files: Vec<&PathBuf> = buckets
+            .iter()
+            .filter(|(r, _)| r.starts_with(dir) && !nested.iter().any(|n| r.starts_with(n)))
+            .flat_map(|(_, files)| files)
+            .filter(|f| is_pkg_file(f) || has_ext(f))
+            .collect();
+        let has_pkg_file = source_files.iter().any(|f| {
+            let fname = f.display().to_string();
+            if l.pkg_files().is_empty() {
+                return true;
+            }
+            let found_pkg_file = l
+                .pkg_files()
+                .iter()
+                .any(|pkg_file| fname.ends_with(pkg_file));
+            found_pkg_file && !(fname.ends_with("Cargo.toml") && is_virtual_manifest(f))
+        }) || (l == Language::Python && dir.join("pyproject.toml").is_file());
+        // C and C++ share build files and headers, so also require a source file
+        let has_source_file = match l {
+            Language::C | Language::Cpp => source_files.iter().any(|f| {
+                f.extension()
+                    .and_then(|e| e.to_str())
+                    .map(|e| e != "h" && l.exts().contains(&e))
+                    .unwrap_or(false)
+            }),
+            // Vue shares package.json and ts/js with the other frontends
+            Language::Vue => source_files
+                .iter()
+                .any(|f| f.extension().map(|e| e == "vue").unwrap_or(false)),
+            // shell scripts have no package file
+            Language::Bash => source_files.iter().any(|f| has_ext(f)),
+            // a sub-project needs source files of its own
+            _ if dir != repo_root => source_files.iter().any(|f| has_ext(f)),
+            _ => true,
+        };
+        if has_pkg_file && has_source_file {
+            // Don't add duplicate languages
+            if !detected_langs.iter().any(|lang| lang == &l) {
+                detected_langs.push(l);
+            }
+        }
+    }
+    detected_langs
+}
+
+fn is_pkg_file(path: &std::path::Path) -> bool {
+    let fname = path.display().to_string();
+    PROGRAMMING_LANGUAGES
+        .iter()
+        .any(|l| l.pkg_files().iter().any(|pkg_file| fname.ends_with(pkg_file)))
+}
+
+// every file of the repo, in one walk
+fn walk_all_files(dir: &PathBuf) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(dir)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|e| !skip_dir(e, &Vec::new()))
+    {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            files.push(entry.path().to_path_buf());
+        }
+    }
+    Ok(files)
+}
+
 fn walk_dirs(dir: &PathBuf, conf: &Config) -> Result<Vec<PathBuf>> {
     let mut dirs = Vec::new();
     for entry in WalkDir::new(dir)
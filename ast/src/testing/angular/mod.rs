@@ -321,7 +321,7 @@ import {{ AppComponent }} from './app/app.component';"#
 
     let renders_edges_count = graph.count_edges_of_type(EdgeType::Renders);
     edges += renders_edges_count;
-    assert_eq!(renders_edges_count, 7, "Expected 7 RENDERS edge");
+    assert_eq!(renders_edges_count, 9, "Expected 9 RENDERS edge");
 
     assert!(
         graph.has_edge(
@@ -385,7 +385,7 @@ import {{ AppComponent }} from './app/app.component';"#
 
     let pages = graph.find_nodes_by_type(NodeType::Page);
     nodes += pages.len();
-    assert_eq!(pages.len(), 11, "Expected 11 pages");
+    assert_eq!(pages.len(), 14, "Expected 14 pages");
 
     let index_page_nodes = graph.find_nodes_by_file_ends_with(NodeType::Page, "src/index.html");
     assert_eq!(
@@ -434,6 +434,29 @@ import {{ AppComponent }} from './app/app.component';"#
         "Expected app.component.html to render people-list.component.html"
     );
 
+    let route_page = |path: &str| {
+        pages
+            .iter()
+            .find(|p| p.name == path && p.file.ends_with("src/app/app.routes.ts"))
+            .map(|p| Node::new(NodeType::Page, p.clone()))
+            .unwrap_or_else(|| panic!("{} route page not found", path))
+    };
+    let root_route = route_page("/");
+    assert_eq!(
+        root_route.node_data.meta.get("redirect_to"),
+        Some(&"/add-person".to_string())
+    );
+    let people_route = route_page("/people");
+    let add_person_route = route_page("/add-person");
+    assert!(
+        graph.has_edge(&people_route, &people_list_component_class, EdgeType::Renders),
+        "Expected /people route to render PeopleListComponent"
+    );
+    assert!(
+        graph.has_edge(&add_person_route, &add_person_component_class, EdgeType::Renders),
+        "Expected /add-person route to render AddPersonComponent"
+    );
+
     let vars = graph.find_nodes_by_type(NodeType::Var);
     assert_eq!(vars.len(), 4, "Expected 4 variables");
 
@@ -0,0 +1,103 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::error::Result;
+use std::str::FromStr;
+
+pub async fn test_angular_router_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/angular_router",
+        Lang::from_str("angular").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let pages = graph.find_nodes_by_type(NodeType::Page);
+    let route_pages = pages
+        .iter()
+        .filter(|p| p.file.ends_with(".routes.ts"))
+        .collect::<Vec<_>>();
+    assert_eq!(route_pages.len(), 6, "Expected 6 route pages");
+    let page = |path: &str, file: &str| {
+        route_pages
+            .iter()
+            .find(|p| p.name == path && p.file.ends_with(file))
+            .map(|p| Node::new(NodeType::Page, (*p).clone()))
+            .unwrap_or_else(|| panic!("{} page not found in {}", path, file))
+    };
+    let home = page("/", "app/app.routes.ts");
+    let account = page("/account", "app/app.routes.ts");
+    let settings = page("/account/settings", "app/app.routes.ts");
+    let admin = page("/admin", "app/app.routes.ts");
+    let admin_index = page("/admin", "admin/admin.routes.ts");
+    let admin_user = page("/admin/users/:id", "admin/admin.routes.ts");
+    assert_eq!(settings.node_data.start, 11);
+
+    let class = |name: &str| {
+        graph
+            .find_nodes_by_name(NodeType::Class, name)
+            .into_iter()
+            .next()
+            .map(|n| Node::new(NodeType::Class, n))
+            .unwrap_or_else(|| panic!("{} class not found", name))
+    };
+    assert!(graph.has_edge(&home, &class("HomeComponent"), EdgeType::Renders));
+    assert!(
+        graph.has_edge(&settings, &class("SettingsComponent"), EdgeType::Renders),
+        "Expected the lazy loaded SettingsComponent to be rendered"
+    );
+    assert!(graph.has_edge(&admin_index, &class("AdminComponent"), EdgeType::Renders));
+    assert!(graph.has_edge(&admin_user, &class("UserComponent"), EdgeType::Renders));
+    assert!(
+        graph.has_edge(&admin, &admin_index, EdgeType::Renders),
+        "Expected /admin to load the admin routes"
+    );
+    assert!(graph.has_edge(&admin, &admin_user, EdgeType::Renders));
+
+    let function = |name: &str, file: &str| {
+        graph
+            .find_nodes_by_name(NodeType::Function, name)
+            .into_iter()
+            .find(|f| f.file.ends_with(file))
+            .map(|n| Node::new(NodeType::Function, n))
+            .unwrap_or_else(|| panic!("{} function not found in {}", name, file))
+    };
+    let auth_guard = function("authGuard", "auth/auth.guard.ts");
+    let can_match = function("canMatch", "auth/admin.guard.ts");
+    let user_resolver = function("userResolver", "admin/user.resolver.ts");
+    assert!(
+        graph.has_edge(&account, &auth_guard, EdgeType::Calls),
+        "Expected /account to call the authGuard"
+    );
+    assert!(
+        graph.has_edge(&admin, &can_match, EdgeType::Calls),
+        "Expected /admin to call AdminGuard.canMatch"
+    );
+    assert!(
+        graph.has_edge(&admin_user, &user_resolver, EdgeType::Calls),
+        "Expected /admin/users/:id to call the userResolver"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_angular_router() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_angular_router_generic::<ArrayGraph>().await.unwrap();
+    test_angular_router_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_angular_router_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
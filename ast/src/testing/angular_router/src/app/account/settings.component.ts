@@ -0,0 +1,7 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'app-settings',
+  template: '<h1>Settings</h1>',
+})
+export class SettingsComponent {}
@@ -0,0 +1,9 @@
+import { Routes } from '@angular/router';
+import { AdminComponent } from './admin.component';
+import { UserComponent } from './user.component';
+import { userResolver } from './user.resolver';
+
+export const ADMIN_ROUTES: Routes = [
+  { path: '', component: AdminComponent },
+  { path: 'users/:id', component: UserComponent, resolve: { user: userResolver } },
+];
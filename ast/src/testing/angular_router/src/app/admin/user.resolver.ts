@@ -0,0 +1,5 @@
+import { ResolveFn } from '@angular/router';
+
+export const userResolver: ResolveFn<string | null> = (route) => {
+  return route.paramMap.get('id');
+};
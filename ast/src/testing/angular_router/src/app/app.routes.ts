@@ -0,0 +1,23 @@
+import { Routes } from '@angular/router';
+import { HomeComponent } from './home/home.component';
+import { authGuard } from './auth/auth.guard';
+import { AdminGuard } from './auth/admin.guard';
+
+export const routes: Routes = [
+  { path: '', component: HomeComponent },
+  {
+    path: 'account',
+    canActivate: [authGuard],
+    children: [
+      {
+        path: 'settings',
+        loadComponent: () => import('./account/settings.component').then((m) => m.SettingsComponent),
+      },
+    ],
+  },
+  {
+    path: 'admin',
+    canMatch: [AdminGuard],
+    loadChildren: () => import('./admin/admin.routes').then((m) => m.ADMIN_ROUTES),
+  },
+];
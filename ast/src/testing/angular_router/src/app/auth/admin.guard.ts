@@ -0,0 +1,9 @@
+import { Injectable } from '@angular/core';
+import { CanMatch } from '@angular/router';
+
+@Injectable({ providedIn: 'root' })
+export class AdminGuard implements CanMatch {
+  canMatch(): boolean {
+    return localStorage.getItem('role') === 'admin';
+  }
+}
@@ -0,0 +1,5 @@
+import { CanActivateFn } from '@angular/router';
+
+export const authGuard: CanActivateFn = (route, state) => {
+  return localStorage.getItem('token') !== null;
+};
@@ -0,0 +1,118 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_bash_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/bash",
+        Lang::from_str("bash").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let file = |path: &str| {
+        graph
+            .find_nodes_by_file_ends_with(NodeType::File, path)
+            .into_iter()
+            .find(|f| f.file.ends_with(&format!("/{}", path)))
+            .map(|n| Node::new(NodeType::File, n))
+            .unwrap_or_else(|| panic!("{} file not found", path))
+    };
+    let deploy_sh = file("scripts/deploy.sh");
+    let push_sh = file("scripts/push.sh");
+    let notify_sh = file("scripts/notify.sh");
+    let common_sh = file("scripts/lib/common.sh");
+    file("package.json");
+    file("Makefile");
+
+    let vars = graph.find_nodes_by_type(NodeType::Var);
+    assert_eq!(vars.len(), 3, "Expected 3 variables");
+    assert!(vars.iter().any(|v| v.name == "IMAGE_NAME"));
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 8, "Expected 8 functions");
+    let function = |name: &str, path: &str| {
+        functions
+            .iter()
+            .find(|f| f.name == name && f.file.ends_with(path))
+            .map(|n| Node::new(NodeType::Function, n.clone()))
+            .unwrap_or_else(|| panic!("{} function not found in {}", name, path))
+    };
+    let log = function("log", "scripts/lib/common.sh");
+    let die = function("die", "scripts/lib/common.sh");
+    let build_image = function("build_image", "scripts/deploy.sh");
+    let push_image = function("push_image", "scripts/deploy.sh");
+    let main = function("main", "scripts/deploy.sh");
+    assert_eq!(build_image.node_data.start, 8);
+    assert_eq!(log.node_data.docs, Some("prints a message with the time".to_string()));
+
+    let imports = graph.count_edges_of_type(EdgeType::Imports);
+    assert_eq!(imports, 2, "Expected 2 import edges");
+    assert!(
+        graph.has_edge(&deploy_sh, &common_sh, EdgeType::Imports),
+        "Expected deploy.sh to source lib/common.sh"
+    );
+    assert!(
+        graph.has_edge(&push_sh, &common_sh, EdgeType::Imports),
+        "Expected push.sh to source lib/common.sh"
+    );
+
+    let calls = graph.count_edges_of_type(EdgeType::Calls);
+    assert_eq!(calls, 10, "Expected 10 calls edges");
+    assert!(graph.has_edge(&build_image, &log, EdgeType::Calls));
+    assert!(graph.has_edge(&push_image, &log, EdgeType::Calls));
+    assert!(graph.has_edge(&die, &log, EdgeType::Calls));
+    assert!(graph.has_edge(&main, &build_image, EdgeType::Calls));
+    assert!(graph.has_edge(&main, &push_image, EdgeType::Calls));
+    assert!(
+        graph.has_edge(&push_image, &push_sh, EdgeType::Calls),
+        "Expected push_image to run push.sh"
+    );
+    assert!(
+        graph.has_edge(&main, &notify_sh, EdgeType::Calls),
+        "Expected main to run notify.sh"
+    );
+
+    let npm_deploy = function("deploy", "package.json");
+    assert_eq!(
+        npm_deploy.node_data.meta.get("script_runner"),
+        Some(&"npm".to_string())
+    );
+    assert_eq!(npm_deploy.node_data.body, "./scripts/deploy.sh");
+    assert!(graph.has_edge(&npm_deploy, &deploy_sh, EdgeType::Calls));
+
+    let make_deploy = function("deploy", "Makefile");
+    let make_push = function("push", "Makefile");
+    assert_eq!(
+        make_push.node_data.meta.get("script_runner"),
+        Some(&"make".to_string())
+    );
+    assert_eq!((make_push.node_data.start, make_push.node_data.end), (7, 8));
+    assert!(graph.has_edge(&make_deploy, &deploy_sh, EdgeType::Calls));
+    assert!(graph.has_edge(&make_push, &push_sh, EdgeType::Calls));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_bash() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_bash_generic::<ArrayGraph>().await.unwrap();
+    test_bash_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_bash_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+source "$DIR/lib/common.sh"
+
+IMAGE_NAME="${IMAGE_NAME:-stakgraph}"
+
+build_image() {
+  log "building $IMAGE_NAME:$1"
+  docker build -t "$IMAGE_NAME:$1" .
+}
+
+push_image() {
+  log "pushing $IMAGE_NAME:$1"
+  "$DIR/push.sh" "$IMAGE_NAME:$1"
+}
+
+main() {
+  local tag="${1:-latest}"
+  build_image "$tag"
+  push_image "$tag"
+  bash "$DIR/notify.sh" "deployed $tag"
+}
+
+main "$@"
@@ -0,0 +1,11 @@
+#!/usr/bin/env bash
+
+# prints a message with the time
+log() {
+  echo "[$(date +%H:%M:%S)] $*"
+}
+
+die() {
+  log "error: $*" >&2
+  exit 1
+}
@@ -0,0 +1,2 @@
+#!/usr/bin/env bash
+curl -s -X POST -d "text=$1" "${SLACK_WEBHOOK_URL:?}"
@@ -0,0 +1,6 @@
+#!/usr/bin/env bash
+DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+. "$DIR/lib/common.sh"
+
+[ -n "${1:-}" ] || die "usage: push.sh IMAGE"
+docker push "$1"
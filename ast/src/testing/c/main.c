@@ -0,0 +1,15 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include "store.h"
+#include "server.h"
+
+#define DEFAULT_PORT 8080
+
+Store store;
+
+int main(int argc, char **argv) {
+    int port = argc > 1 ? atoi(argv[1]) : DEFAULT_PORT;
+    store_init(&store);
+    store_add(&store, "Alice", "alice@example.com");
+    return serve(&store, port);
+}
@@ -0,0 +1,179 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::error::Result;
+use std::str::FromStr;
+
+pub async fn test_c_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/c",
+        Lang::from_str("c").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let language_nodes = graph.find_nodes_by_name(NodeType::Language, "c");
+    assert_eq!(language_nodes.len(), 1, "Expected 1 language node");
+    assert_eq!(
+        language_nodes[0].name, "c",
+        "Language node name should be 'c'"
+    );
+
+    let files = graph.find_nodes_by_type(NodeType::File);
+    assert_eq!(files.len(), 6, "Expected 6 files");
+    assert!(
+        files.iter().any(|f| f.name == "Makefile"),
+        "Expected Makefile to be included"
+    );
+
+    let imports = graph.find_nodes_by_type(NodeType::Import);
+    assert_eq!(imports.len(), 5, "Expected 5 imports");
+    let main_import = imports
+        .iter()
+        .find(|i| i.file == "src/testing/c/main.c")
+        .expect("main.c import not found");
+    assert!(
+        main_import.body.contains("#include \"store.h\"")
+            && main_import.body.contains("#include \"server.h\""),
+        "main.c import body is incorrect"
+    );
+
+    let data_models = graph.find_nodes_by_type(NodeType::DataModel);
+    assert_eq!(data_models.len(), 3, "Expected 3 data models");
+    for name in ["Person", "Store", "StoreStatus"] {
+        assert!(
+            data_models
+                .iter()
+                .any(|dm| dm.name == name && dm.file == "src/testing/c/store.h"),
+            "Expected {} data model in store.h",
+            name
+        );
+    }
+
+    let classes = graph.find_nodes_by_type(NodeType::Class);
+    assert_eq!(classes.len(), 1, "Expected 1 class");
+    assert_eq!(classes[0].name, "Person", "Class name should be 'Person'");
+
+    let variables = graph.find_nodes_by_type(NodeType::Var);
+    assert_eq!(variables.len(), 4, "Expected 4 variables");
+    for name in ["store", "server_name", "request_count", "next_id"] {
+        assert!(
+            variables.iter().any(|v| v.name == name),
+            "Expected global variable {}",
+            name
+        );
+    }
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 8, "Expected 8 functions");
+    assert!(
+        !functions.iter().any(|f| f.file.ends_with(".h")),
+        "Prototypes in headers should not be functions"
+    );
+
+    let store_add = functions
+        .iter()
+        .find(|f| f.name == "store_add")
+        .expect("store_add function not found");
+    assert_eq!(
+        store_add.docs,
+        Some("Adds a person to the store and assigns it a fresh id".to_string()),
+        "store_add docs are incorrect"
+    );
+
+    let calls = graph.count_edges_of_type(EdgeType::Calls);
+    assert_eq!(calls, 8, "Expected 8 calls edges");
+
+    let main_fn = graph
+        .find_nodes_by_name(NodeType::Function, "main")
+        .into_iter()
+        .find(|n| n.file == "src/testing/c/main.c")
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("main function not found in main.c");
+
+    let serve_fn = graph
+        .find_nodes_by_name(NodeType::Function, "serve")
+        .into_iter()
+        .find(|n| n.file == "src/testing/c/server.c")
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("serve function not found in server.c");
+
+    let handle_request_fn = graph
+        .find_nodes_by_name(NodeType::Function, "handle_request")
+        .into_iter()
+        .find(|n| n.file == "src/testing/c/server.c")
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("handle_request function not found in server.c");
+
+    let store_get_fn = graph
+        .find_nodes_by_name(NodeType::Function, "store_get")
+        .into_iter()
+        .find(|n| n.file == "src/testing/c/store.c")
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("store_get function not found in store.c");
+
+    let store_add_fn = Node::new(NodeType::Function, store_add.clone());
+
+    assert!(
+        graph.has_edge(&main_fn, &serve_fn, EdgeType::Calls),
+        "Expected 'main' to call 'serve'"
+    );
+    assert!(
+        graph.has_edge(&main_fn, &store_add_fn, EdgeType::Calls),
+        "Expected 'main' to call 'store_add'"
+    );
+    assert!(
+        graph.has_edge(&serve_fn, &handle_request_fn, EdgeType::Calls),
+        "Expected 'serve' to call 'handle_request'"
+    );
+    assert!(
+        graph.has_edge(&handle_request_fn, &store_get_fn, EdgeType::Calls),
+        "Expected 'handle_request' to call 'store_get'"
+    );
+
+    let store_dm = graph
+        .find_nodes_by_name(NodeType::DataModel, "Store")
+        .into_iter()
+        .find(|n| n.file == "src/testing/c/store.h")
+        .map(|n| Node::new(NodeType::DataModel, n))
+        .expect("Store data model not found in store.h");
+
+    let store_h_file = graph
+        .find_nodes_by_name(NodeType::File, "store.h")
+        .into_iter()
+        .find(|n| n.file == "src/testing/c/store.h")
+        .map(|n| Node::new(NodeType::File, n))
+        .expect("store.h file node not found");
+
+    assert!(
+        graph.has_edge(&store_h_file, &store_dm, EdgeType::Contains),
+        "Expected 'store.h' to contain 'Store' data model"
+    );
+    assert!(
+        graph.has_edge(&store_add_fn, &store_dm, EdgeType::Contains),
+        "Expected 'store_add' to use 'Store' data model"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_c() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_c_generic::<ArrayGraph>().await.unwrap();
+    test_c_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_c_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
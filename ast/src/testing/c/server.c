@@ -0,0 +1,37 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include "server.h"
+
+const char *server_name = "peopled";
+int request_count = 0;
+
+static int write_person(const struct Person *p, char *out, size_t out_len) {
+    return snprintf(out, out_len, "{\"id\":%d,\"name\":\"%s\"}", p->id, p->name);
+}
+
+int handle_request(Store *store, const char *method, const char *path, char *out, size_t out_len) {
+    request_count++;
+    if (strcmp(method, "GET") == 0 && strncmp(path, "/person/", 8) == 0) {
+        int id = atoi(path + 8);
+        struct Person *p = store_get(store, id);
+        if (p == NULL) {
+            return 404;
+        }
+        write_person(p, out, out_len);
+        return 200;
+    }
+    if (strcmp(method, "POST") == 0 && strcmp(path, "/person") == 0) {
+        if (store_add(store, "anonymous", "anon@example.com") != STORE_OK) {
+            return 507;
+        }
+        return 201;
+    }
+    return 404;
+}
+
+int serve(Store *store, int port) {
+    char out[256];
+    printf("%s listening on %d\n", server_name, port);
+    return handle_request(store, "GET", "/person/1", out, sizeof(out));
+}
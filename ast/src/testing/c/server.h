@@ -0,0 +1,9 @@
+#ifndef SERVER_H
+#define SERVER_H
+
+#include "store.h"
+
+int handle_request(Store *store, const char *method, const char *path, char *out, size_t out_len);
+int serve(Store *store, int port);
+
+#endif
@@ -0,0 +1,35 @@
+#include <string.h>
+#include "store.h"
+
+static int next_id = 1;
+
+static void copy_field(char *dst, const char *src, size_t len) {
+    strncpy(dst, src, len - 1);
+    dst[len - 1] = '\0';
+}
+
+void store_init(Store *store) {
+    memset(store, 0, sizeof(Store));
+    next_id = 1;
+}
+
+// Adds a person to the store and assigns it a fresh id
+StoreStatus store_add(Store *store, const char *name, const char *email) {
+    if (store->count >= MAX_PEOPLE) {
+        return STORE_FULL;
+    }
+    struct Person *p = &store->people[store->count++];
+    p->id = next_id++;
+    copy_field(p->name, name, sizeof(p->name));
+    copy_field(p->email, email, sizeof(p->email));
+    return STORE_OK;
+}
+
+struct Person *store_get(Store *store, int id) {
+    for (size_t i = 0; i < store->count; i++) {
+        if (store->people[i].id == id) {
+            return &store->people[i];
+        }
+    }
+    return NULL;
+}
@@ -0,0 +1,29 @@
+#ifndef STORE_H
+#define STORE_H
+
+#include <stddef.h>
+
+#define MAX_PEOPLE 128
+
+struct Person {
+    int id;
+    char name[64];
+    char email[128];
+};
+
+typedef struct {
+    struct Person people[MAX_PEOPLE];
+    size_t count;
+} Store;
+
+typedef enum {
+    STORE_OK,
+    STORE_FULL,
+    STORE_NOT_FOUND
+} StoreStatus;
+
+void store_init(Store *store);
+StoreStatus store_add(Store *store, const char *name, const char *email);
+struct Person *store_get(Store *store, int id);
+
+#endif
@@ -0,0 +1,50 @@
+using Microsoft.AspNetCore.Mvc;
+using TodoApi.Models;
+using TodoApi.Services;
+
+namespace TodoApi.Controllers;
+
+[ApiController]
+[Route("api/[controller]")]
+public class TodosController : ControllerBase
+{
+    private readonly ITodoService _service;
+
+    public TodosController(ITodoService service)
+    {
+        _service = service;
+    }
+
+    // Lists every todo item
+    [HttpGet]
+    public async Task<ActionResult<IEnumerable<TodoItem>>> GetAll()
+    {
+        var items = await _service.ListAsync();
+        return Ok(items);
+    }
+
+    [HttpGet("{id}")]
+    public async Task<ActionResult<TodoItem>> GetById(int id)
+    {
+        var item = await _service.FindAsync(id);
+        if (item == null)
+        {
+            return NotFound();
+        }
+        return Ok(item);
+    }
+
+    [HttpPost]
+    public async Task<ActionResult<TodoItem>> Create(TodoItem item)
+    {
+        var created = await _service.CreateAsync(item);
+        return CreatedAtAction(nameof(GetById), new { id = created.Id }, created);
+    }
+
+    [HttpDelete("{id}")]
+    public async Task<IActionResult> Delete(int id)
+    {
+        await _service.DeleteAsync(id);
+        return NoContent();
+    }
+}
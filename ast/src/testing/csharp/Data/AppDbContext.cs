@@ -0,0 +1,14 @@
+using Microsoft.EntityFrameworkCore;
+using TodoApi.Models;
+
+namespace TodoApi.Data;
+
+public class AppDbContext : DbContext
+{
+    public AppDbContext(DbContextOptions<AppDbContext> options) : base(options)
+    {
+    }
+
+    public DbSet<TodoItem> Todos { get; set; } = null!;
+    public DbSet<User> Users { get; set; } = null!;
+}
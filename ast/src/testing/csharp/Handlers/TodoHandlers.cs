@@ -0,0 +1,25 @@
+using Microsoft.EntityFrameworkCore;
+using TodoApi.Data;
+
+namespace TodoApi.Handlers;
+
+public static class TodoHandlers
+{
+    public static async Task<IResult> Complete(int id, AppDbContext db)
+    {
+        var item = await db.Todos.FindAsync(id);
+        if (item is null)
+        {
+            return Results.NotFound();
+        }
+        item.IsComplete = true;
+        await db.SaveChangesAsync();
+        return Results.NoContent();
+    }
+
+    public static async Task<IResult> ClearCompleted(AppDbContext db)
+    {
+        await db.Todos.Where(t => t.IsComplete).ExecuteDeleteAsync();
+        return Results.NoContent();
+    }
+}
@@ -0,0 +1,10 @@
+namespace TodoApi.Models;
+
+public class TodoItem
+{
+    public int Id { get; set; }
+    public string Title { get; set; } = string.Empty;
+    public bool IsComplete { get; set; }
+    public int OwnerId { get; set; }
+    public User? Owner { get; set; }
+}
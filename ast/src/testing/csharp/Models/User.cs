@@ -0,0 +1,8 @@
+namespace TodoApi.Models;
+
+public class User
+{
+    public int Id { get; set; }
+    public string Email { get; set; } = string.Empty;
+    public List<TodoItem> Todos { get; set; } = new();
+}
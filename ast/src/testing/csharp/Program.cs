@@ -0,0 +1,19 @@
+using Microsoft.EntityFrameworkCore;
+using TodoApi.Data;
+using TodoApi.Handlers;
+using TodoApi.Services;
+
+var builder = WebApplication.CreateBuilder(args);
+
+builder.Services.AddDbContext<AppDbContext>(opt => opt.UseSqlite("Data Source=todos.db"));
+builder.Services.AddScoped<ITodoService, TodoService>();
+builder.Services.AddControllers();
+
+var app = builder.Build();
+
+app.MapControllers();
+app.MapGet("/health", () => Results.Ok("healthy"));
+app.MapPost("/todos/{id}/complete", TodoHandlers.Complete);
+app.MapDelete("/todos/completed", TodoHandlers.ClearCompleted);
+
+app.Run();
@@ -0,0 +1,11 @@
+using TodoApi.Models;
+
+namespace TodoApi.Services;
+
+public interface ITodoService
+{
+    Task<List<TodoItem>> ListAsync();
+    Task<TodoItem?> FindAsync(int id);
+    Task<TodoItem> CreateAsync(TodoItem item);
+    Task DeleteAsync(int id);
+}
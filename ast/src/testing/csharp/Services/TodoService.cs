@@ -0,0 +1,42 @@
+using Microsoft.EntityFrameworkCore;
+using TodoApi.Data;
+using TodoApi.Models;
+
+namespace TodoApi.Services;
+
+public class TodoService : ITodoService
+{
+    private readonly AppDbContext _db;
+
+    public TodoService(AppDbContext db)
+    {
+        _db = db;
+    }
+
+    public Task<List<TodoItem>> ListAsync()
+    {
+        return _db.Todos.ToListAsync();
+    }
+
+    public async Task<TodoItem?> FindAsync(int id)
+    {
+        return await _db.Todos.FindAsync(id);
+    }
+
+    public async Task<TodoItem> CreateAsync(TodoItem item)
+    {
+        _db.Todos.Add(item);
+        await _db.SaveChangesAsync();
+        return item;
+    }
+
+    public async Task DeleteAsync(int id)
+    {
+        var item = await FindAsync(id);
+        if (item != null)
+        {
+            _db.Todos.Remove(item);
+            await _db.SaveChangesAsync();
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::error::Result;
+use std::str::FromStr;
+
+pub async fn test_csharp_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/csharp",
+        Lang::from_str("csharp").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let language_nodes = graph.find_nodes_by_name(NodeType::Language, "csharp");
+    assert_eq!(language_nodes.len(), 1, "Expected 1 language node");
+
+    let files = graph.find_nodes_by_type(NodeType::File);
+    assert_eq!(files.len(), 9, "Expected 9 files");
+    assert!(
+        files.iter().any(|f| f.name == "TodoApi.csproj"),
+        "Expected TodoApi.csproj to be included"
+    );
+
+    let imports = graph.find_nodes_by_type(NodeType::Import);
+    assert_eq!(imports.len(), 6, "Expected 6 import sections");
+
+    let classes = graph.find_nodes_by_type(NodeType::Class);
+    assert_eq!(classes.len(), 6, "Expected 6 classes");
+
+    let traits = graph.find_nodes_by_type(NodeType::Trait);
+    assert_eq!(traits.len(), 1, "Expected 1 trait");
+    assert_eq!(traits[0].name, "ITodoService", "Trait name is incorrect");
+
+    let implements = graph.count_edges_of_type(EdgeType::Implements);
+    assert_eq!(implements, 1, "Expected 1 implements edge");
+
+    let todo_service = graph
+        .find_nodes_by_name(NodeType::Class, "TodoService")
+        .into_iter()
+        .find(|n| n.file == "src/testing/csharp/Services/TodoService.cs")
+        .map(|n| Node::new(NodeType::Class, n))
+        .expect("TodoService class not found");
+    let itodo_service = Node::new(NodeType::Trait, traits[0].clone());
+    assert!(
+        graph.has_edge(&todo_service, &itodo_service, EdgeType::Implements),
+        "Expected TodoService to implement ITodoService"
+    );
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 10, "Expected 10 functions");
+    assert!(
+        !functions
+            .iter()
+            .any(|f| f.file.ends_with("ITodoService.cs")),
+        "Interface members should not be functions"
+    );
+
+    let data_models = graph.find_nodes_by_type(NodeType::DataModel);
+    assert_eq!(data_models.len(), 2, "Expected 2 data models");
+    for (name, field) in [("TodoItem", "IsComplete"), ("User", "Email")] {
+        let dm = data_models
+            .iter()
+            .find(|dm| dm.name == name)
+            .unwrap_or_else(|| panic!("Expected DbSet<{}> data model", name));
+        assert_eq!(
+            dm.file,
+            format!("src/testing/csharp/Models/{}.cs", name),
+            "Expected the {} data model to be its entity class",
+            name
+        );
+        assert!(
+            dm.body.contains(field),
+            "Expected the {} data model to have the {} field",
+            name,
+            field
+        );
+    }
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 7, "Expected 7 endpoints");
+
+    let expected = [
+        ("/api/Todos", "GET", "TodosController.cs"),
+        ("/api/Todos/{id}", "GET", "TodosController.cs"),
+        ("/api/Todos", "POST", "TodosController.cs"),
+        ("/api/Todos/{id}", "DELETE", "TodosController.cs"),
+        ("/health", "GET", "Program.cs"),
+        ("/todos/{id}/complete", "POST", "Program.cs"),
+        ("/todos/completed", "DELETE", "Program.cs"),
+    ];
+    for (path, verb, file) in expected {
+        assert!(
+            endpoints.iter().any(|e| e.name == path
+                && e.meta.get("verb") == Some(&verb.to_string())
+                && e.file.ends_with(file)),
+            "Expected {} {} endpoint in {}",
+            verb,
+            path,
+            file
+        );
+    }
+
+    let handlers = graph.count_edges_of_type(EdgeType::Handler);
+    assert_eq!(handlers, 6, "Expected 6 handler edges");
+
+    let get_by_id_endpoint = graph
+        .find_nodes_by_name(NodeType::Endpoint, "/api/Todos/{id}")
+        .into_iter()
+        .find(|n| n.meta.get("verb") == Some(&"GET".to_string()))
+        .map(|n| Node::new(NodeType::Endpoint, n))
+        .expect("GET /api/Todos/{id} endpoint not found");
+    let get_by_id_fn = graph
+        .find_nodes_by_name(NodeType::Function, "GetById")
+        .into_iter()
+        .find(|n| n.file == "src/testing/csharp/Controllers/TodosController.cs")
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("GetById function not found");
+    assert!(
+        graph.has_edge(&get_by_id_endpoint, &get_by_id_fn, EdgeType::Handler),
+        "Expected GET /api/Todos/{{id}} to be handled by GetById"
+    );
+
+    let complete_endpoint = graph
+        .find_nodes_by_name(NodeType::Endpoint, "/todos/{id}/complete")
+        .into_iter()
+        .find(|n| n.file == "src/testing/csharp/Program.cs")
+        .map(|n| Node::new(NodeType::Endpoint, n))
+        .expect("POST /todos/{id}/complete endpoint not found");
+    let complete_fn = graph
+        .find_nodes_by_name(NodeType::Function, "Complete")
+        .into_iter()
+        .find(|n| n.file == "src/testing/csharp/Handlers/TodoHandlers.cs")
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("Complete function not found");
+    assert!(
+        graph.has_edge(&complete_endpoint, &complete_fn, EdgeType::Handler),
+        "Expected POST /todos/{{id}}/complete to be handled by TodoHandlers.Complete"
+    );
+
+    let get_all_fn = graph
+        .find_nodes_by_name(NodeType::Function, "GetAll")
+        .into_iter()
+        .find(|n| n.file == "src/testing/csharp/Controllers/TodosController.cs")
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("GetAll function not found");
+    let list_async_fn = graph
+        .find_nodes_by_name(NodeType::Function, "ListAsync")
+        .into_iter()
+        .find(|n| n.file == "src/testing/csharp/Services/TodoService.cs")
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("ListAsync function not found");
+    assert!(
+        graph.has_edge(&get_all_fn, &list_async_fn, EdgeType::Calls),
+        "Expected GetAll to call ListAsync"
+    );
+
+    let todos_controller = graph
+        .find_nodes_by_name(NodeType::Class, "TodosController")
+        .into_iter()
+        .next()
+        .map(|n| Node::new(NodeType::Class, n))
+        .expect("TodosController class not found");
+    assert!(
+        graph.has_edge(&todos_controller, &get_all_fn, EdgeType::Operand),
+        "Expected TodosController to own GetAll"
+    );
+
+    let todo_item_dm = data_models
+        .iter()
+        .find(|dm| dm.name == "TodoItem")
+        .map(|dm| Node::new(NodeType::DataModel, dm.clone()))
+        .unwrap();
+    assert!(
+        graph.has_edge(&get_by_id_fn, &todo_item_dm, EdgeType::Contains),
+        "Expected GetById to use the TodoItem data model"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_csharp() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_csharp_generic::<ArrayGraph>().await.unwrap();
+    test_csharp_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_csharp_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,10 @@
+import time
+
+
+def run_jobs():
+    while True:
+        time.sleep(5)
+
+
+if __name__ == "__main__":
+    run_jobs()
@@ -0,0 +1,111 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_docker_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/docker",
+        Lang::from_str("python").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let services = graph.find_nodes_by_type(NodeType::Service);
+    assert_eq!(services.len(), 5, "Expected 5 services");
+
+    let service = |name: &str| {
+        services
+            .iter()
+            .find(|s| s.name == name)
+            .map(|n| Node::new(NodeType::Service, n.clone()))
+            .unwrap_or_else(|| panic!("{} service not found", name))
+    };
+    let api = service("api");
+    let worker = service("worker");
+    let db = service("db");
+    let cache = service("cache");
+    let tools = service("tools");
+
+    assert_eq!(api.node_data.file, "src/testing/docker/docker-compose.yml");
+    assert_eq!(api.node_data.meta.get("ports"), Some(&"8000:8000".to_string()));
+    assert_eq!(api.node_data.meta.get("expose"), Some(&"8000".to_string()));
+    assert_eq!(
+        api.node_data.meta.get("environment"),
+        Some(&"DATABASE_URL,REDIS_URL".to_string())
+    );
+    assert_eq!(
+        api.node_data.meta.get("build_context"),
+        Some(&"src/testing/docker/api".to_string())
+    );
+    assert_eq!(db.node_data.meta.get("image"), Some(&"postgres:16".to_string()));
+    assert_eq!(tools.node_data.file, "src/testing/docker/tools/Dockerfile");
+
+    let depends_on = graph.count_edges_of_type(EdgeType::DependsOn);
+    assert_eq!(depends_on, 3, "Expected 3 depends_on edges");
+    assert!(graph.has_edge(&api, &db, EdgeType::DependsOn));
+    assert!(graph.has_edge(&api, &cache, EdgeType::DependsOn));
+    assert!(graph.has_edge(&worker, &db, EdgeType::DependsOn));
+
+    let directory = |path: &str| {
+        graph
+            .find_nodes_by_type(NodeType::Directory)
+            .into_iter()
+            .find(|d| d.file == path)
+            .map(|n| Node::new(NodeType::Directory, n))
+            .unwrap_or_else(|| panic!("{} directory not found", path))
+    };
+    let builds = graph.count_edges_of_type(EdgeType::Builds);
+    assert_eq!(builds, 3, "Expected 3 builds edges");
+    let api_dir = directory("src/testing/docker/api");
+    assert!(graph.has_edge(&api, &api_dir, EdgeType::Builds));
+    assert!(graph.has_edge(&worker, &api_dir, EdgeType::Builds));
+    assert!(graph.has_edge(&tools, &directory("src/testing/docker/tools"), EdgeType::Builds));
+
+    let file = |path: &str| {
+        graph
+            .find_nodes_by_file_ends_with(NodeType::File, path)
+            .into_iter()
+            .next()
+            .map(|n| Node::new(NodeType::File, n))
+            .unwrap_or_else(|| panic!("{} file not found", path))
+    };
+    let runs = graph.count_edges_of_type(EdgeType::Runs);
+    assert_eq!(runs, 3, "Expected 3 runs edges");
+    assert!(
+        graph.has_edge(&api, &file("api/app/main.py"), EdgeType::Runs),
+        "Expected api to run app/main.py"
+    );
+    assert!(
+        graph.has_edge(&worker, &file("api/app/worker.py"), EdgeType::Runs),
+        "Expected worker to run app/worker.py"
+    );
+    assert!(
+        graph.has_edge(&tools, &file("tools/seed.py"), EdgeType::Runs),
+        "Expected tools to run seed.py"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_docker() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_docker_generic::<ArrayGraph>().await.unwrap();
+    test_docker_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_docker_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,5 @@
+def seed():
+    print("seeding")
+
+
+seed()
@@ -43,7 +43,22 @@ pub async fn test_go_generic<G: Graph>() -> Result<()> {
 
     let libraries = graph.find_nodes_by_type(NodeType::Library);
     nodes_count += libraries.len();
-    assert_eq!(libraries.len(), 4, "Expected 4 library nodes");
+    // 4 from go.mod, and the other 17 modules of go.sum
+    assert_eq!(libraries.len(), 21, "Expected 21 library nodes");
+    let chi = libraries
+        .iter()
+        .find(|l| l.name == "github.com/go-chi/chi")
+        .expect("chi library not found");
+    assert_eq!(chi.file, "src/testing/go/go.mod");
+    assert_eq!(chi.meta.get("resolved_version"), Some(&"v1.5.5".to_string()));
+    assert_eq!(chi.meta.get("direct"), Some(&"true".to_string()));
+    let pgx = libraries
+        .iter()
+        .find(|l| l.name == "github.com/jackc/pgx/v5")
+        .expect("pgx library not found");
+    assert_eq!(pgx.file, "src/testing/go/go.sum");
+    assert_eq!(pgx.meta.get("version"), Some(&"v5.5.5".to_string()));
+    assert_eq!(pgx.meta.get("direct"), Some(&"false".to_string()));
 
     let files = graph.find_nodes_by_type(NodeType::File);
     nodes_count += files.len();
@@ -272,7 +287,7 @@ pub async fn test_go_generic<G: Graph>() -> Result<()> {
 
     let contains = graph.count_edges_of_type(EdgeType::Contains);
     edges_count += contains;
-    assert_eq!(contains, 50, "Expected 50 contains edges");
+    assert_eq!(contains, 67, "Expected 67 contains edges");
 
     let variables = graph.find_nodes_by_type(NodeType::Var);
     nodes_count += variables.len();
@@ -0,0 +1,11 @@
+package main
+
+import "net/http"
+
+func ChiListBooks(w http.ResponseWriter, r *http.Request) {
+	w.WriteHeader(http.StatusOK)
+}
+
+func ChiPatchBook(w http.ResponseWriter, r *http.Request) {
+	w.WriteHeader(http.StatusNoContent)
+}
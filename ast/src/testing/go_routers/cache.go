@@ -0,0 +1,24 @@
+package main
+
+type Cache struct {
+	items map[string]string
+}
+
+func (c *Cache) Get(key string, load func() string) string {
+	if v, ok := c.items[key]; ok {
+		return v
+	}
+	v := load()
+	c.items[key] = v
+	return v
+}
+
+func warmCache() {
+	c := &Cache{items: map[string]string{}}
+	// not a router, so not an endpoint
+	c.Get("/users", LoadUsers)
+}
+
+func LoadUsers() string {
+	return "[]"
+}
@@ -0,0 +1,12 @@
+package main
+
+import "github.com/go-chi/chi/v5"
+
+func chiRouter() chi.Router {
+	r := chi.NewRouter()
+	r.Route("/books", func(r chi.Router) {
+		r.Get("/", ChiListBooks)
+		r.Patch("/{id}", ChiPatchBook)
+	})
+	return r
+}
@@ -0,0 +1,23 @@
+package main
+
+import (
+	"net/http"
+
+	"github.com/labstack/echo/v4"
+)
+
+func echoRouter() *echo.Echo {
+	e := echo.New()
+	api := e.Group("/api")
+	api.POST("/orders", EchoCreateOrder)
+	e.PUT("/orders/:id", EchoUpdateOrder)
+	return e
+}
+
+func EchoCreateOrder(c echo.Context) error {
+	return c.NoContent(http.StatusCreated)
+}
+
+func EchoUpdateOrder(c echo.Context) error {
+	return c.NoContent(http.StatusOK)
+}
@@ -0,0 +1,19 @@
+package main
+
+import "github.com/gofiber/fiber/v2"
+
+func fiberApp() *fiber.App {
+	app := fiber.New()
+	users := app.Group("/users")
+	users.Get("/", FiberListUsers)
+	app.Patch("/profile/:id", FiberPatchProfile)
+	return app
+}
+
+func FiberListUsers(c *fiber.Ctx) error {
+	return c.JSON([]string{})
+}
+
+func FiberPatchProfile(c *fiber.Ctx) error {
+	return c.SendStatus(fiber.StatusNoContent)
+}
@@ -0,0 +1,38 @@
+package main
+
+import (
+	"net/http"
+
+	"github.com/gin-gonic/gin"
+)
+
+func ginRouter() *gin.Engine {
+	r := gin.Default()
+	r.GET("/health", GinHealth)
+
+	v1 := r.Group("/v1")
+	{
+		v1.GET("/items/:id", GinGetItem)
+		v1.PATCH("/items/:id", GinPatchItem)
+
+		admin := v1.Group("/admin")
+		admin.DELETE("/items/:id", GinDeleteItem)
+	}
+	return r
+}
+
+func GinHealth(c *gin.Context) {
+	c.String(http.StatusOK, "ok")
+}
+
+func GinGetItem(c *gin.Context) {
+	c.JSON(http.StatusOK, gin.H{"id": c.Param("id")})
+}
+
+func GinPatchItem(c *gin.Context) {
+	c.Status(http.StatusNoContent)
+}
+
+func GinDeleteItem(c *gin.Context) {
+	c.Status(http.StatusNoContent)
+}
@@ -0,0 +1,100 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_go_routers_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/go_routers",
+        Lang::from_str("go").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let libraries = graph.find_nodes_by_type(NodeType::Library);
+    assert_eq!(libraries.len(), 5, "Expected 5 libraries");
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 25, "Expected 25 functions");
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 16, "Expected 16 endpoints");
+
+    let expected = [
+        ("GET", "/health", "gin.go", "GinHealth"),
+        ("GET", "/v1/items/:id", "gin.go", "GinGetItem"),
+        ("PATCH", "/v1/items/:id", "gin.go", "GinPatchItem"),
+        ("DELETE", "/v1/admin/items/:id", "gin.go", "GinDeleteItem"),
+        ("POST", "/api/orders", "echo.go", "EchoCreateOrder"),
+        ("PUT", "/orders/:id", "echo.go", "EchoUpdateOrder"),
+        ("GET", "/users", "fiber.go", "FiberListUsers"),
+        ("PATCH", "/profile/:id", "fiber.go", "FiberPatchProfile"),
+        ("POST", "/accounts", "mux.go", "MuxCreateAccount"),
+        ("PUT", "/accounts", "mux.go", "MuxCreateAccount"),
+        ("GET", "/v2/accounts/{id}", "mux.go", "MuxGetAccount"),
+        ("GET", "/notes/{id}", "nethttp.go", "GetNote"),
+        ("DELETE", "/notes/{id}", "nethttp.go", "DeleteNote"),
+        ("ANY", "/static/", "nethttp.go", "ServeStatic"),
+        ("GET", "/books", "chi.go", "ChiListBooks"),
+        ("PATCH", "/books/{id}", "chi.go", "ChiPatchBook"),
+    ];
+    for (verb, path, file, handler) in expected {
+        let endpoint = endpoints
+            .iter()
+            .find(|e| e.name == path && e.meta.get("verb") == Some(&verb.to_string()))
+            .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+            .unwrap_or_else(|| panic!("{} {} endpoint not found", verb, path));
+        assert_eq!(
+            endpoint.node_data.file,
+            format!("src/testing/go_routers/{}", file),
+            "{} {} endpoint file is incorrect",
+            verb,
+            path
+        );
+        let handler_fn = graph
+            .find_nodes_by_name(NodeType::Function, handler)
+            .into_iter()
+            .next()
+            .map(|n| Node::new(NodeType::Function, n))
+            .unwrap_or_else(|| panic!("{} function not found", handler));
+        assert!(
+            graph.has_edge(&endpoint, &handler_fn, EdgeType::Handler),
+            "Expected {} {} to be handled by {}",
+            verb,
+            path,
+            handler
+        );
+    }
+
+    assert!(
+        !endpoints.iter().any(|e| e.name == "/users" && e.file.ends_with("cache.go")),
+        "Get on a value that is not a router should not be an endpoint"
+    );
+
+    let handler_edges = graph.count_edges_of_type(EdgeType::Handler);
+    assert_eq!(handler_edges, 16, "Expected 16 handler edges");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_go_routers() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_go_routers_generic::<ArrayGraph>().await.unwrap();
+    test_go_routers_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_go_routers_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,24 @@
+package main
+
+import (
+	"net/http"
+
+	"github.com/gorilla/mux"
+)
+
+func muxRouter() *mux.Router {
+	r := mux.NewRouter()
+	r.HandleFunc("/accounts", MuxCreateAccount).Methods(http.MethodPost, "PUT")
+
+	v2 := r.PathPrefix("/v2").Subrouter()
+	v2.HandleFunc("/accounts/{id}", MuxGetAccount).Methods("GET")
+	return r
+}
+
+func MuxCreateAccount(w http.ResponseWriter, r *http.Request) {
+	w.WriteHeader(http.StatusCreated)
+}
+
+func MuxGetAccount(w http.ResponseWriter, r *http.Request) {
+	w.WriteHeader(http.StatusOK)
+}
@@ -0,0 +1,24 @@
+package main
+
+import "net/http"
+
+func stdMux() *http.ServeMux {
+	mux := http.NewServeMux()
+	mux.HandleFunc("GET /notes/{id}", GetNote)
+	mux.HandleFunc("DELETE /notes/{id}", DeleteNote)
+	// no method in the pattern, so it serves them all
+	mux.HandleFunc("/static/", ServeStatic)
+	return mux
+}
+
+func GetNote(w http.ResponseWriter, r *http.Request) {
+	w.Write([]byte(r.PathValue("id")))
+}
+
+func DeleteNote(w http.ResponseWriter, r *http.Request) {
+	w.WriteHeader(http.StatusNoContent)
+}
+
+func ServeStatic(w http.ResponseWriter, r *http.Request) {
+	http.ServeFile(w, r, "static/index.html")
+}
@@ -0,0 +1,150 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::linker::link_api_nodes;
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_graphql_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/graphql",
+        Lang::from_str("ts").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let mut graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 7, "Expected 7 functions");
+
+    let data_models = graph.find_nodes_by_type(NodeType::DataModel);
+    assert_eq!(data_models.len(), 3, "Expected 3 data models");
+    for (name, file) in [
+        ("User", "schema.graphql"),
+        ("NewUser", "schema.graphql"),
+        ("Post", "src/server/posts.ts"),
+    ] {
+        assert!(
+            data_models
+                .iter()
+                .any(|dm| dm.name == name && dm.file == format!("src/testing/graphql/{}", file)),
+            "{} data model not found in {}",
+            name,
+            file
+        );
+    }
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 4, "Expected 4 endpoints");
+
+    let expected = [
+        ("Query.user", "schema.graphql", "user"),
+        ("Query.users", "schema.graphql", "users"),
+        ("Mutation.createUser", "schema.graphql", "createUser"),
+        ("Query.posts", "src/server/posts.ts", "posts"),
+    ];
+    for (name, file, handler) in expected {
+        let endpoint = endpoints
+            .iter()
+            .find(|e| e.name == name)
+            .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+            .unwrap_or_else(|| panic!("{} endpoint not found", name));
+        assert_eq!(
+            endpoint.node_data.file,
+            format!("src/testing/graphql/{}", file),
+            "{} endpoint file is incorrect",
+            name
+        );
+        assert_eq!(
+            endpoint.node_data.meta.get("verb"),
+            Some(&"GRAPHQL".to_string()),
+            "{} endpoint verb is incorrect",
+            name
+        );
+        let handler_fn = graph
+            .find_nodes_by_name(NodeType::Function, handler)
+            .into_iter()
+            .next()
+            .map(|n| Node::new(NodeType::Function, n))
+            .unwrap_or_else(|| panic!("{} resolver not found", handler));
+        assert!(
+            handler_fn.node_data.file.contains("src/server/"),
+            "{} resolver should be in the server",
+            handler
+        );
+        assert!(
+            graph.has_edge(&endpoint, &handler_fn, EdgeType::Handler),
+            "Expected {} to be resolved by {}",
+            name,
+            handler
+        );
+    }
+
+    let requests = graph.find_nodes_by_type(NodeType::Request);
+    assert_eq!(requests.len(), 3, "Expected 3 requests");
+
+    link_api_nodes(&mut graph)?;
+
+    let expected = [
+        ("loadUser", "Query.user", "GetUser"),
+        ("addUser", "Mutation.createUser", "CreateUser"),
+        ("fetchUsers", "Query.users", "ListUsers"),
+    ];
+    for (caller, name, operation) in expected {
+        let request = requests
+            .iter()
+            .find(|r| r.name == name)
+            .map(|n| Node::new(NodeType::Request, n.clone()))
+            .unwrap_or_else(|| panic!("{} request not found", name));
+        assert_eq!(
+            request.node_data.meta.get("operation"),
+            Some(&operation.to_string()),
+            "{} request operation is incorrect",
+            name
+        );
+        let caller_fn = graph
+            .find_nodes_by_name(NodeType::Function, caller)
+            .into_iter()
+            .next()
+            .map(|n| Node::new(NodeType::Function, n))
+            .unwrap_or_else(|| panic!("{} function not found", caller));
+        assert!(
+            graph.has_edge(&caller_fn, &request, EdgeType::Calls),
+            "Expected {} to call {}",
+            caller,
+            name
+        );
+        let endpoint = endpoints
+            .iter()
+            .find(|e| e.name == name)
+            .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+            .unwrap();
+        assert!(
+            graph.has_edge(&request, &endpoint, EdgeType::Calls),
+            "Expected {} request to be linked to its endpoint",
+            name
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_graphql() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_graphql_generic::<ArrayGraph>().await.unwrap();
+    test_graphql_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_graphql_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,27 @@
+import { gql } from "@apollo/client";
+
+export const GET_USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      id
+      name
+    }
+  }
+`;
+
+export const LIST_USERS = gql`
+  query ListUsers {
+    users {
+      id
+      name
+    }
+  }
+`;
+
+export const CREATE_USER = gql`
+  mutation CreateUser($input: NewUser!) {
+    createUser(input: $input) {
+      id
+    }
+  }
+`;
@@ -0,0 +1,17 @@
+import { ApolloClient, useMutation, useQuery } from "@apollo/client";
+import { CREATE_USER, GET_USER, LIST_USERS } from "./queries";
+
+export function loadUser(id: string) {
+  const { data, loading } = useQuery(GET_USER, { variables: { id } });
+  return loading ? null : data.user;
+}
+
+export function addUser() {
+  const [createUser] = useMutation(CREATE_USER);
+  return createUser;
+}
+
+export async function fetchUsers(client: ApolloClient<object>) {
+  const { data } = await client.query({ query: LIST_USERS });
+  return data.users;
+}
@@ -0,0 +1,18 @@
+import gql from "graphql-tag";
+
+export const postTypeDefs = gql`
+  type Post {
+    id: ID!
+    title: String!
+  }
+
+  extend type Query {
+    posts: [Post!]!
+  }
+`;
+
+export const postResolvers = {
+  Query: {
+    posts: () => [],
+  },
+};
@@ -0,0 +1,17 @@
+const users = [{ id: "1", name: "Ada", email: "ada@example.com" }];
+
+export const resolvers = {
+  Query: {
+    user: (_: unknown, { id }: { id: string }) => users.find((u) => u.id === id),
+    users() {
+      return users;
+    },
+  },
+  Mutation: {
+    createUser: (_: unknown, { input }: { input: { name: string; email?: string } }) => {
+      const user = { id: String(users.length + 1), email: "", ...input };
+      users.push(user);
+      return user;
+    },
+  },
+};
@@ -0,0 +1,59 @@
+use crate::lang::graphs::{Edge, EdgeType, Graph, Node, NodeType};
+use crate::lang::NodeData;
+use shared::Result;
+
+fn node(name: &str, file: &str, start: usize) -> NodeData {
+    let mut nd = NodeData::name_file(name, file);
+    nd.start = start;
+    nd
+}
+
+// two files with the same basename, and a function of the same name in each
+pub async fn test_has_edge_generic<G: Graph>() -> Result<()> {
+    let mut graph = G::default();
+    let (file_a, file_b) = ("src/a/mod.rs", "src/b/mod.rs");
+    graph.add_node(NodeType::File, node("mod.rs", file_a, 0));
+    graph.add_node(NodeType::File, node("mod.rs", file_b, 0));
+
+    let run_a = node("run", file_a, 1);
+    let run_b = node("run", file_b, 1);
+    let helper = node("helper", file_b, 5);
+    let funcs = [&run_a, &run_b, &helper]
+        .into_iter()
+        .map(|f| (f.clone(), None, Vec::new(), Vec::new(), None, Vec::new()))
+        .collect();
+    graph.add_functions(funcs);
+    graph.add_edge(Edge::calls(NodeType::Function, &run_a, NodeType::Function, &helper));
+
+    let file = |f: &str| Node::new(NodeType::File, node("mod.rs", f, 0));
+    let func = |nd: &NodeData| Node::new(NodeType::Function, nd.clone());
+
+    assert!(graph.has_edge(&file(file_b), &func(&run_b), EdgeType::Contains));
+    assert!(
+        !graph.has_edge(&file(file_a), &func(&run_b), EdgeType::Contains),
+        "run in src/b/mod.rs belongs to that file, not the first mod.rs"
+    );
+    assert!(graph.has_edge(&file(file_a), &func(&run_a), EdgeType::Contains));
+
+    assert!(graph.has_edge(&func(&run_a), &func(&helper), EdgeType::Calls));
+    assert!(!graph.has_edge(&func(&run_b), &func(&helper), EdgeType::Calls));
+    assert!(!graph.has_edge(&func(&helper), &func(&run_a), EdgeType::Calls));
+    assert!(!graph.has_edge(&func(&run_a), &func(&helper), EdgeType::Contains));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_has_edge() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_has_edge_generic::<ArrayGraph>().await.unwrap();
+    test_has_edge_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_has_edge_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -1,4 +1,5 @@
 pub mod compare_graphs;
+pub mod has_edge;
 
 #[cfg(feature = "fulltest")]
 pub mod demorepo_test;
@@ -0,0 +1,13 @@
+package main
+
+import (
+	"context"
+
+	"google.golang.org/grpc"
+	"grpcdemo/pb"
+)
+
+func FetchUser(conn *grpc.ClientConn, id string) (*pb.User, error) {
+	client := pb.NewUsersClient(conn)
+	return client.GetUser(context.Background(), &pb.GetUserRequest{Id: id})
+}
@@ -0,0 +1,19 @@
+package main
+
+import (
+	"log"
+	"net"
+
+	"google.golang.org/grpc"
+	"grpcdemo/pb"
+)
+
+func main() {
+	lis, err := net.Listen("tcp", ":50051")
+	if err != nil {
+		log.Fatal(err)
+	}
+	s := grpc.NewServer()
+	pb.RegisterUsersServer(s, &usersServer{store: map[string]*pb.User{}})
+	log.Fatal(s.Serve(lis))
+}
@@ -0,0 +1,123 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::linker::link_api_nodes;
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_grpc_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/grpc",
+        Lang::from_str("go").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let mut graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 2, "Expected 2 endpoints");
+
+    let expected = [
+        ("/users.v1.Users/GetUser", "GetUser", "User"),
+        ("/users.v1.Users/ListUsers", "ListUsers", "stream User"),
+    ];
+    for (path, handler, response) in expected {
+        let endpoint = endpoints
+            .iter()
+            .find(|e| e.name == path)
+            .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+            .unwrap_or_else(|| panic!("{} endpoint not found", path));
+        assert_eq!(
+            endpoint.node_data.file, "src/testing/grpc/proto/users.proto",
+            "{} endpoint file is incorrect",
+            path
+        );
+        assert_eq!(
+            endpoint.node_data.meta.get("verb"),
+            Some(&"GRPC".to_string()),
+            "{} endpoint verb is incorrect",
+            path
+        );
+        assert_eq!(
+            endpoint.node_data.meta.get("response"),
+            Some(&response.to_string()),
+            "{} endpoint response is incorrect",
+            path
+        );
+        let handler_fn = graph
+            .find_nodes_by_name(NodeType::Function, handler)
+            .into_iter()
+            .find(|f| f.file.ends_with("server.go"))
+            .map(|n| Node::new(NodeType::Function, n))
+            .unwrap_or_else(|| panic!("{} function not found", handler));
+        assert!(
+            graph.has_edge(&endpoint, &handler_fn, EdgeType::Handler),
+            "Expected {} to be handled by usersServer.{}",
+            path,
+            handler
+        );
+    }
+
+    let data_models = graph.find_nodes_by_type(NodeType::DataModel);
+    for message in ["User", "GetUserRequest", "ListUsersRequest"] {
+        assert!(
+            data_models
+                .iter()
+                .any(|dm| dm.name == message && dm.file.ends_with("proto/users.proto")),
+            "{} message data model not found",
+            message
+        );
+    }
+
+    let requests = graph.find_nodes_by_type(NodeType::Request);
+    assert_eq!(requests.len(), 1, "Expected 1 request");
+    let get_user_request = requests
+        .iter()
+        .find(|r| r.name == "/Users/GetUser")
+        .map(|n| Node::new(NodeType::Request, n.clone()))
+        .expect("/Users/GetUser request not found");
+    let fetch_user = graph
+        .find_nodes_by_name(NodeType::Function, "FetchUser")
+        .into_iter()
+        .next()
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("FetchUser function not found");
+    assert!(
+        graph.has_edge(&fetch_user, &get_user_request, EdgeType::Calls),
+        "Expected FetchUser to call /Users/GetUser"
+    );
+
+    link_api_nodes(&mut graph)?;
+
+    let get_user_endpoint = endpoints
+        .iter()
+        .find(|e| e.name == "/users.v1.Users/GetUser")
+        .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+        .unwrap();
+    assert!(
+        graph.has_edge(&get_user_request, &get_user_endpoint, EdgeType::Calls),
+        "Expected /Users/GetUser request to be linked to the GetUser rpc"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_grpc() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_grpc_generic::<ArrayGraph>().await.unwrap();
+    test_grpc_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_grpc_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,25 @@
+package main
+
+import (
+	"context"
+
+	"grpcdemo/pb"
+)
+
+type usersServer struct {
+	pb.UnimplementedUsersServer
+	store map[string]*pb.User
+}
+
+func (s *usersServer) GetUser(ctx context.Context, req *pb.GetUserRequest) (*pb.User, error) {
+	return s.store[req.Id], nil
+}
+
+func (s *usersServer) ListUsers(req *pb.ListUsersRequest, stream pb.Users_ListUsersServer) error {
+	for _, u := range s.store {
+		if err := stream.Send(u); err != nil {
+			return err
+		}
+	}
+	return nil
+}
@@ -0,0 +1,126 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_java_tests_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/java_tests",
+        Lang::from_str("java").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 6, "Expected 6 functions");
+    assert!(
+        functions
+            .iter()
+            .any(|f| f.name == "setUp" && f.file.ends_with("ItemServiceTest.java")),
+        "Expected @BeforeEach setUp to stay a function"
+    );
+    assert!(
+        functions
+            .iter()
+            .all(|f| f.name != "normalizesNames" && f.name != "returnsAnItem"),
+        "@Test methods should not be collected as functions"
+    );
+
+    let unit_tests = graph.find_nodes_by_type(NodeType::UnitTest);
+    assert_eq!(unit_tests.len(), 2, "Expected 2 unit tests");
+    let mut unit_names: Vec<&str> = unit_tests.iter().map(|t| t.name.as_str()).collect();
+    unit_names.sort();
+    assert_eq!(unit_names, vec!["normalizesNames", "rejectsBlankNames"]);
+
+    let integration_tests = graph.find_nodes_by_type(NodeType::IntegrationTest);
+    assert_eq!(integration_tests.len(), 3, "Expected 3 integration tests");
+    let mut integration_names: Vec<&str> =
+        integration_tests.iter().map(|t| t.name.as_str()).collect();
+    integration_names.sort();
+    assert_eq!(
+        integration_names,
+        vec!["createsAnItem", "createsAnItemOverHttp", "returnsAnItem"]
+    );
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 2, "Expected 2 endpoints");
+
+    let get_item_endpoint = endpoints
+        .iter()
+        .find(|e| e.name == "/items/{id}" && e.meta.get("verb") == Some(&"GET".to_string()))
+        .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+        .expect("GET /items/{id} endpoint not found");
+    let create_item_endpoint = endpoints
+        .iter()
+        .find(|e| e.name == "/items" && e.meta.get("verb") == Some(&"POST".to_string()))
+        .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+        .expect("POST /items endpoint not found");
+
+    let integration_test = |name: &str| {
+        integration_tests
+            .iter()
+            .find(|t| t.name == name)
+            .map(|n| Node::new(NodeType::IntegrationTest, n.clone()))
+            .expect("Integration test not found")
+    };
+
+    assert!(
+        graph.has_edge(
+            &integration_test("returnsAnItem"),
+            &get_item_endpoint,
+            EdgeType::Calls
+        ),
+        "Expected MockMvc get(\"/items/{{id}}\") to call GET /items/{{id}}"
+    );
+    assert!(
+        graph.has_edge(
+            &integration_test("createsAnItem"),
+            &create_item_endpoint,
+            EdgeType::Calls
+        ),
+        "Expected MockMvc post(\"/items\") to call POST /items"
+    );
+    assert!(
+        graph.has_edge(
+            &integration_test("createsAnItemOverHttp"),
+            &create_item_endpoint,
+            EdgeType::Calls
+        ),
+        "Expected RestAssured post(\"/items\") to call POST /items"
+    );
+    assert!(
+        !graph.has_edge(
+            &integration_test("createsAnItem"),
+            &get_item_endpoint,
+            EdgeType::Calls
+        ),
+        "POST /items should not be linked to GET /items/{{id}}"
+    );
+
+    let requests = graph.find_nodes_by_type(NodeType::Request);
+    assert_eq!(requests.len(), 0, "Test requests should not become Request nodes");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_java_tests() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_java_tests_generic::<ArrayGraph>().await.unwrap();
+    test_java_tests_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_java_tests_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
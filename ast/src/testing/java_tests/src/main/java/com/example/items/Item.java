@@ -0,0 +1,4 @@
+package com.example.items;
+
+public record Item(Long id, String name) {
+}
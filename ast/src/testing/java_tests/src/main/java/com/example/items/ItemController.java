@@ -0,0 +1,22 @@
+package com.example.items;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+public class ItemController {
+    private final ItemService service;
+
+    public ItemController(ItemService service) {
+        this.service = service;
+    }
+
+    @GetMapping("/items/{id}")
+    public Item getItem(@PathVariable Long id) {
+        return service.find(id);
+    }
+
+    @PostMapping("/items")
+    public Item createItem(@RequestBody Item item) {
+        return service.save(item);
+    }
+}
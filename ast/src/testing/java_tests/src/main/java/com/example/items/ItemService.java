@@ -0,0 +1,27 @@
+package com.example.items;
+
+import java.util.HashMap;
+import java.util.Map;
+import org.springframework.stereotype.Service;
+
+@Service
+public class ItemService {
+    private final Map<Long, Item> items = new HashMap<>();
+
+    public Item find(Long id) {
+        return items.get(id);
+    }
+
+    public Item save(Item item) {
+        Item saved = new Item((long) items.size() + 1, normalizeName(item.name()));
+        items.put(saved.id(), saved);
+        return saved;
+    }
+
+    public String normalizeName(String name) {
+        if (name == null || name.isBlank()) {
+            throw new IllegalArgumentException("name is required");
+        }
+        return name.trim().toLowerCase();
+    }
+}
@@ -0,0 +1,27 @@
+package com.example.items;
+
+import static io.restassured.RestAssured.given;
+
+import org.junit.jupiter.api.Test;
+import org.springframework.boot.test.context.SpringBootTest;
+import org.testcontainers.containers.PostgreSQLContainer;
+import org.testcontainers.junit.jupiter.Container;
+import org.testcontainers.junit.jupiter.Testcontainers;
+
+@SpringBootTest(webEnvironment = SpringBootTest.WebEnvironment.RANDOM_PORT)
+@Testcontainers
+class ItemApiIT {
+    @Container
+    static PostgreSQLContainer<?> postgres = new PostgreSQLContainer<>("postgres:16");
+
+    @Test
+    void createsAnItemOverHttp() {
+        given()
+            .contentType("application/json")
+            .body("{\"name\":\"desk\"}")
+        .when()
+            .post("/items")
+        .then()
+            .statusCode(200);
+    }
+}
@@ -0,0 +1,35 @@
+package com.example.items;
+
+import static org.springframework.test.web.servlet.request.MockMvcRequestBuilders.get;
+import static org.springframework.test.web.servlet.request.MockMvcRequestBuilders.post;
+import static org.springframework.test.web.servlet.result.MockMvcResultMatchers.status;
+
+import org.junit.jupiter.api.Test;
+import org.springframework.beans.factory.annotation.Autowired;
+import org.springframework.boot.test.autoconfigure.web.servlet.WebMvcTest;
+import org.springframework.boot.test.mock.mockito.MockBean;
+import org.springframework.http.MediaType;
+import org.springframework.test.web.servlet.MockMvc;
+
+@WebMvcTest(ItemController.class)
+class ItemControllerTest {
+    @Autowired
+    private MockMvc mockMvc;
+
+    @MockBean
+    private ItemService service;
+
+    @Test
+    void returnsAnItem() throws Exception {
+        mockMvc.perform(get("/items/{id}", 1L))
+            .andExpect(status().isOk());
+    }
+
+    @Test
+    void createsAnItem() throws Exception {
+        mockMvc.perform(post("/items")
+                .contentType(MediaType.APPLICATION_JSON)
+                .content("{\"name\":\"lamp\"}"))
+            .andExpect(status().isOk());
+    }
+}
@@ -0,0 +1,29 @@
+package com.example.items;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.params.ParameterizedTest;
+import org.junit.jupiter.params.provider.ValueSource;
+
+class ItemServiceTest {
+    private ItemService service;
+
+    @BeforeEach
+    void setUp() {
+        service = new ItemService();
+    }
+
+    @Test
+    void normalizesNames() {
+        assertEquals("lamp", service.normalizeName("  Lamp "));
+    }
+
+    @ParameterizedTest
+    @ValueSource(strings = {"", "   "})
+    void rejectsBlankNames(String name) {
+        assertThrows(IllegalArgumentException.class, () -> service.normalizeName(name));
+    }
+}
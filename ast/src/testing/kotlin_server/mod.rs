@@ -0,0 +1,121 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::linker::link_api_nodes;
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_kotlin_server_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/kotlin_server",
+        Lang::from_str("kotlin").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let mut graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 6, "Expected 3 ktor and 3 spring endpoints");
+    let find_endpoint = |name: &str, verb: &str| {
+        endpoints
+            .iter()
+            .find(|e| e.name == name && e.meta.get("verb").map(|v| v.as_str()) == Some(verb))
+            .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+            .unwrap_or_else(|| panic!("{} {} endpoint not found", verb, name))
+    };
+    let function = |name: &str, path: &str| {
+        graph
+            .find_nodes_by_name(NodeType::Function, name)
+            .into_iter()
+            .find(|f| f.file.ends_with(path))
+            .map(|n| Node::new(NodeType::Function, n))
+            .unwrap_or_else(|| panic!("{} function not found in {}", name, path))
+    };
+
+    let handlers = graph.count_edges_of_type(EdgeType::Handler);
+    assert_eq!(handlers, 6, "Expected every endpoint to have a handler");
+
+    let ktor = [
+        ("/api/health", "GET", "module", "Application.kt"),
+        ("/api/users/{id}", "GET", "userRoutes", "routes/UserRoutes.kt"),
+        ("/api/users", "POST", "userRoutes", "routes/UserRoutes.kt"),
+    ];
+    for (path, verb, handler, file) in ktor {
+        let endpoint = find_endpoint(path, verb);
+        assert!(
+            endpoint.node_data.file.ends_with(file),
+            "Expected {} {} to be declared in {}",
+            verb,
+            path,
+            file
+        );
+        let handler_fn = function(handler, file);
+        assert!(
+            graph.has_edge(&endpoint, &handler_fn, EdgeType::Handler),
+            "Expected {} {} to be handled by {}, which declares its route lambda",
+            verb,
+            path,
+            handler
+        );
+    }
+
+    let spring = [
+        ("/api/people/{id}", "GET", "getPerson"),
+        ("/api/people", "POST", "createPerson"),
+        ("/api/people/{id}", "DELETE", "deletePerson"),
+    ];
+    for (path, verb, handler) in spring {
+        let endpoint = find_endpoint(path, verb);
+        let handler_fn = function(handler, "controllers/PersonController.kt");
+        assert!(
+            graph.has_edge(&endpoint, &handler_fn, EdgeType::Handler),
+            "Expected {} {} to be handled by {}",
+            verb,
+            path,
+            handler
+        );
+    }
+
+    let requests = graph.find_nodes_by_type(NodeType::Request);
+    assert_eq!(requests.len(), 2, "Expected 2 requests");
+
+    link_api_nodes(&mut graph)?;
+
+    let expected = [("/api/health", "GET"), ("/api/people", "POST")];
+    for (path, verb) in expected {
+        let request = requests
+            .iter()
+            .find(|r| r.name == path)
+            .map(|n| Node::new(NodeType::Request, n.clone()))
+            .unwrap_or_else(|| panic!("{} request not found", path));
+        assert!(
+            graph.has_edge(&request, &find_endpoint(path, verb), EdgeType::Calls),
+            "Expected the {} request to be linked to the {} {} endpoint",
+            path,
+            verb,
+            path
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_kotlin_server() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_kotlin_server_generic::<ArrayGraph>().await.unwrap();
+    test_kotlin_server_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_kotlin_server_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
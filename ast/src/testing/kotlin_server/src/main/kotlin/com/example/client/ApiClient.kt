@@ -0,0 +1,33 @@
+package com.example.client
+
+import okhttp3.MediaType.Companion.toMediaType
+import okhttp3.OkHttpClient
+import okhttp3.Request
+import okhttp3.RequestBody
+
+class ApiClient {
+    private val client = OkHttpClient()
+
+    fun checkHealth(): Boolean {
+        val request = Request.Builder()
+            .url("http://localhost:8080/api/health")
+            .get()
+            .build()
+
+        client.newCall(request).execute().use { response ->
+            return response.isSuccessful
+        }
+    }
+
+    fun addPerson(json: String): Boolean {
+        val body = RequestBody.create("application/json".toMediaType(), json)
+        val request = Request.Builder()
+            .url("http://localhost:8080/api/people")
+            .post(body)
+            .build()
+
+        client.newCall(request).execute().use { response ->
+            return response.isSuccessful
+        }
+    }
+}
@@ -0,0 +1,27 @@
+package com.example.controllers
+
+import org.springframework.web.bind.annotation.*
+
+data class Person(val id: Long, val name: String)
+
+@RestController
+@RequestMapping("/api/people")
+class PersonController {
+    private val people = mutableMapOf<Long, Person>()
+
+    @GetMapping("/{id}")
+    fun getPerson(@PathVariable id: Long): Person? {
+        return people[id]
+    }
+
+    @PostMapping
+    fun createPerson(@RequestBody person: Person): Person {
+        people[person.id] = person
+        return person
+    }
+
+    @RequestMapping("/{id}", method = [RequestMethod.DELETE])
+    fun deletePerson(@PathVariable id: Long) {
+        people.remove(id)
+    }
+}
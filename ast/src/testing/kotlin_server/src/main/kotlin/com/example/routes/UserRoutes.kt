@@ -0,0 +1,31 @@
+package com.example.routes
+
+import io.ktor.http.*
+import io.ktor.server.application.*
+import io.ktor.server.request.*
+import io.ktor.server.response.*
+import io.ktor.server.routing.*
+
+data class User(val id: Long, val name: String)
+
+val users = mutableListOf(User(1, "alice"))
+
+fun findUser(id: Long?): User? = users.find { it.id == id }
+
+fun Route.userRoutes() {
+    route("/users") {
+        get("/{id}") {
+            val user = findUser(call.parameters["id"]?.toLongOrNull())
+            if (user == null) {
+                call.respond(HttpStatusCode.NotFound)
+            } else {
+                call.respond(user)
+            }
+        }
+        post {
+            val user = call.receive<User>()
+            users.add(user)
+            call.respond(HttpStatusCode.Created, user)
+        }
+    }
+}
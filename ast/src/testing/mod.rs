@@ -5,21 +5,43 @@ use std::str::FromStr;
 // use tracing_test::traced_test;
 
 pub mod angular;
+pub mod angular_router;
+pub mod bash;
+pub mod c;
 pub mod cpp;
+pub mod csharp;
+pub mod docker;
 pub mod go;
+pub mod go_routers;
+pub mod graphql;
 pub mod graphs;
+pub mod grpc;
 pub mod java;
+pub mod java_tests;
 pub mod kotlin;
+pub mod kotlin_server;
+pub mod monorepo;
+pub mod nestjs;
 pub mod nextjs;
+pub mod notebook;
+pub mod openapi;
+pub mod php;
 pub mod python;
+pub mod python_routers;
+pub mod python_tasks;
 pub mod react;
 pub mod ruby;
+pub mod ruby_jobs;
 pub mod rust_test;
+pub mod sql_schema;
 pub mod svelte;
+pub mod sveltekit;
 pub mod swift;
 pub mod test_backend;
 pub mod test_frontend;
 pub mod typescript;
+pub mod typescript_tests;
+pub mod vue;
 
 #[cfg(test)]
 fn pre_test() {
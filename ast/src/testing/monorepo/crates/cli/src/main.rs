@@ -0,0 +1,6 @@
+use core::new_job;
+
+fn main() {
+    let job = new_job(1, "nightly");
+    println!("{} {}", job.id, job.name);
+}
@@ -0,0 +1,11 @@
+pub struct Job {
+    pub id: u32,
+    pub name: String,
+}
+
+pub fn new_job(id: u32, name: &str) -> Job {
+    Job {
+        id,
+        name: name.to_string(),
+    }
+}
@@ -0,0 +1,148 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::repo::Repo;
+use lsp::Language;
+use shared::Result;
+use std::path::PathBuf;
+
+pub async fn test_monorepo_generic<G: Graph>() -> Result<()> {
+    let repos = Repo::new_multi_detect(
+        "src/testing/monorepo",
+        None,
+        Vec::new(),
+        Vec::new(),
+        Some(false),
+    )
+    .await?;
+
+    let mut projects: Vec<(String, Language)> = repos
+        .0
+        .iter()
+        .map(|r| (r.root.display().to_string(), r.lang.kind.clone()))
+        .collect();
+    projects.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        projects,
+        vec![
+            ("src/testing/monorepo/crates/cli".to_string(), Language::Rust),
+            ("src/testing/monorepo/crates/core".to_string(), Language::Rust),
+            ("src/testing/monorepo/services/api".to_string(), Language::Go),
+            ("src/testing/monorepo/services/worker".to_string(), Language::Go),
+            ("src/testing/monorepo/tools".to_string(), Language::Python),
+        ]
+    );
+    let api_repo = repos
+        .0
+        .iter()
+        .find(|r| r.root.ends_with("services/api"))
+        .unwrap();
+    let project = api_repo.project.as_ref().expect("api should be a sub-project");
+    assert_eq!(project.repo_root, PathBuf::from("src/testing/monorepo"));
+
+    // without a workspace marker, the go modules below services are one project
+    let services_repos = Repo::new_multi_detect(
+        "src/testing/monorepo/services",
+        None,
+        Vec::new(),
+        Vec::new(),
+        Some(false),
+    )
+    .await?;
+    assert_eq!(services_repos.0.len(), 1);
+    assert_eq!(
+        services_repos.0[0].root,
+        PathBuf::from("src/testing/monorepo/services")
+    );
+    assert!(services_repos.0[0].project.is_none());
+
+    let graph = repos.build_graphs_inner::<G>().await?;
+
+    graph.analysis();
+
+    let repositories = graph.find_nodes_by_type(NodeType::Repository);
+    assert_eq!(repositories.len(), 1, "Expected one Repository for all projects");
+    assert_eq!(repositories[0].name, "/monorepo");
+    assert_eq!(repositories[0].file, "src/testing/monorepo");
+    let repository = Node::new(NodeType::Repository, repositories[0].clone());
+
+    let languages = graph.find_nodes_by_type(NodeType::Language);
+    assert_eq!(languages.len(), 5, "Expected a Language per project");
+    assert!(languages
+        .iter()
+        .any(|l| l.name == "go" && l.file == "src/testing/monorepo/services/worker"));
+
+    let directory = |path: &str| {
+        graph
+            .find_nodes_by_type(NodeType::Directory)
+            .into_iter()
+            .find(|d| d.file == format!("src/testing/monorepo/{}", path))
+            .map(|n| Node::new(NodeType::Directory, n))
+            .unwrap_or_else(|| panic!("{} directory not found", path))
+    };
+    let services = directory("services");
+    let api = directory("services/api");
+    let worker = directory("services/worker");
+    let core = directory("crates/core");
+    let core_src = directory("crates/core/src");
+    let tools = directory("tools");
+    assert_eq!(api.node_data.meta.get("project"), Some(&"go.mod".to_string()));
+    assert_eq!(
+        core.node_data.meta.get("project"),
+        Some(&"Cargo.toml".to_string())
+    );
+    assert_eq!(
+        tools.node_data.meta.get("project"),
+        Some(&"pyproject.toml".to_string())
+    );
+    assert!(services.node_data.meta.get("project").is_none());
+    // the virtual workspace at the root is not a project
+    assert!(directory("crates").node_data.meta.get("project").is_none());
+
+    assert!(graph.has_edge(&repository, &services, EdgeType::Contains));
+    assert!(graph.has_edge(&services, &api, EdgeType::Contains));
+    assert!(graph.has_edge(&services, &worker, EdgeType::Contains));
+    assert!(graph.has_edge(&core, &core_src, EdgeType::Contains));
+
+    let file = |path: &str| {
+        let file = format!("src/testing/monorepo/{}", path);
+        graph
+            .find_nodes_by_file_ends_with(NodeType::File, &file)
+            .into_iter()
+            .find(|f| f.file == file)
+            .map(|n| Node::new(NodeType::File, n))
+            .unwrap_or_else(|| panic!("{} file not found", path))
+    };
+    let api_main = file("services/api/main.go");
+    let worker_mod = file("services/worker/go.mod");
+    let lib_rs = file("crates/core/src/lib.rs");
+    assert!(graph.has_edge(&api, &api_main, EdgeType::Contains));
+    assert!(graph.has_edge(&worker, &worker_mod, EdgeType::Contains));
+    assert!(graph.has_edge(&core_src, &lib_rs, EdgeType::Contains));
+
+    let main_files = graph
+        .find_nodes_by_type(NodeType::File)
+        .into_iter()
+        .filter(|f| f.name == "main.go")
+        .count();
+    assert_eq!(main_files, 2, "Expected each go file once");
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    let function_in = |name: &str, path: &str| {
+        functions
+            .iter()
+            .any(|f| f.name == name && f.file == format!("src/testing/monorepo/{}", path))
+    };
+    assert!(function_in("ListJobs", "services/api/main.go"));
+    assert!(function_in("RunJobs", "services/worker/main.go"));
+    assert!(function_in("new_job", "crates/core/src/lib.rs"));
+    assert!(function_in("summarize", "tools/report/summary.py"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_monorepo() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_monorepo_generic::<ArrayGraph>().await.unwrap();
+    test_monorepo_generic::<BTreeMapGraph>().await.unwrap();
+}
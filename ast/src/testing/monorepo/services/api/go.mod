@@ -0,0 +1,5 @@
+module example.com/monorepo/api
+
+go 1.22
+
+require github.com/go-chi/chi v1.5.5
@@ -0,0 +1,12 @@
+package main
+
+import "net/http"
+
+func main() {
+	http.HandleFunc("/jobs", ListJobs)
+	http.ListenAndServe(":8080", nil)
+}
+
+func ListJobs(w http.ResponseWriter, r *http.Request) {
+	w.Write([]byte("[]"))
+}
@@ -0,0 +1,11 @@
+package main
+
+import "fmt"
+
+func main() {
+	RunJobs()
+}
+
+func RunJobs() {
+	fmt.Println("running jobs")
+}
@@ -0,0 +1,6 @@
+def summarize(jobs):
+    return {"total": len(jobs)}
+
+
+def print_summary(jobs):
+    print(summarize(jobs))
@@ -0,0 +1,121 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_nestjs_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/nestjs",
+        Lang::from_str("ts").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let libraries = graph.find_nodes_by_type(NodeType::Library);
+    assert_eq!(libraries.len(), 4, "Expected 4 libraries");
+
+    let classes = graph.find_nodes_by_type(NodeType::Class);
+    assert_eq!(classes.len(), 4, "Expected 4 classes");
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 12, "Expected 12 controller and provider methods");
+    assert!(
+        !functions.iter().any(|f| f.name == "constructor"),
+        "Constructors should not be functions"
+    );
+
+    let operand_edges =
+        graph.find_nodes_with_edge_type(NodeType::Class, NodeType::Function, EdgeType::Operand);
+    assert_eq!(operand_edges.len(), 12, "Expected 12 methods");
+
+    let instances = graph.find_nodes_by_type(NodeType::Instance);
+    assert_eq!(instances.len(), 2, "Expected 2 injected providers");
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 6, "Expected 6 endpoints");
+
+    let method = |name: &str, file: &str| {
+        graph
+            .find_nodes_by_name(NodeType::Function, name)
+            .into_iter()
+            .find(|f| f.file.ends_with(file))
+            .map(|f| Node::new(NodeType::Function, f))
+            .unwrap_or_else(|| panic!("{} not found in {}", name, file))
+    };
+
+    let expected = [
+        ("GET", "/users", "findAll", "users.controller.ts"),
+        ("GET", "/users/:id", "findOne", "users.controller.ts"),
+        ("POST", "/users", "create", "users.controller.ts"),
+        ("GET", "/items/:id", "findOne", "items.controller.ts"),
+        ("PATCH", "/items/:id", "rename", "items.controller.ts"),
+        ("DELETE", "/items/:id", "remove", "items.controller.ts"),
+    ];
+    for (verb, path, handler, file) in expected {
+        let endpoint = endpoints
+            .iter()
+            .find(|e| e.name == path && e.meta.get("verb") == Some(&verb.to_string()))
+            .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+            .unwrap_or_else(|| panic!("{} {} endpoint not found", verb, path));
+        assert!(
+            graph.has_edge(&endpoint, &method(handler, file), EdgeType::Handler),
+            "Expected {} {} to be handled by {}",
+            verb,
+            path,
+            handler
+        );
+    }
+
+    let calls = graph.count_edges_of_type(EdgeType::Calls);
+    assert_eq!(calls, 6, "Expected 6 calls edges");
+
+    // both services have a findOne, the injected provider picks the right one
+    assert!(
+        graph.has_edge(
+            &method("findOne", "users.controller.ts"),
+            &method("findOne", "users.service.ts"),
+            EdgeType::Calls
+        ),
+        "Expected UsersController.findOne to call UsersService.findOne"
+    );
+    assert!(
+        graph.has_edge(
+            &method("findOne", "items.controller.ts"),
+            &method("findOne", "items.service.ts"),
+            EdgeType::Calls
+        ),
+        "Expected ItemsController.findOne to call ItemsService.findOne"
+    );
+    assert!(
+        graph.has_edge(
+            &method("rename", "items.controller.ts"),
+            &method("rename", "items.service.ts"),
+            EdgeType::Calls
+        ),
+        "Expected ItemsController.rename to call ItemsService.rename"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_nestjs() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_nestjs_generic::<ArrayGraph>().await.unwrap();
+    test_nestjs_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_nestjs_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,22 @@
+import { Body, Controller, Delete, Get, Param, Patch } from "@nestjs/common";
+import { ItemsService } from "./items.service";
+
+@Controller("/items")
+export class ItemsController {
+  constructor(private readonly itemsService: ItemsService) {}
+
+  @Get(":id")
+  findOne(@Param("id") id: string) {
+    return this.itemsService.findOne(id);
+  }
+
+  @Patch(":id")
+  rename(@Param("id") id: string, @Body("name") name: string) {
+    this.itemsService.rename(id, name);
+  }
+
+  @Delete(":id")
+  remove(@Param("id") id: string) {
+    return this.itemsService.remove(id);
+  }
+}
@@ -0,0 +1,18 @@
+import { Injectable } from "@nestjs/common";
+
+@Injectable()
+export class ItemsService {
+  private readonly items = new Map<string, string>();
+
+  findOne(id: string): string | undefined {
+    return this.items.get(id);
+  }
+
+  rename(id: string, name: string): void {
+    this.items.set(id, name);
+  }
+
+  remove(id: string): boolean {
+    return this.items.delete(id);
+  }
+}
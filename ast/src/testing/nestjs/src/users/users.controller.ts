@@ -0,0 +1,23 @@
+import { Body, Controller, Get, HttpCode, Param, Post } from "@nestjs/common";
+import { UsersService } from "./users.service";
+
+@Controller("users")
+export class UsersController {
+  constructor(private readonly usersService: UsersService) {}
+
+  @Get()
+  findAll() {
+    return this.usersService.findAll();
+  }
+
+  @Get(":id")
+  findOne(@Param("id") id: string) {
+    return this.usersService.findOne(id);
+  }
+
+  @Post()
+  @HttpCode(201)
+  create(@Body("name") name: string) {
+    return this.usersService.create(name);
+  }
+}
@@ -0,0 +1,25 @@
+import { Injectable } from "@nestjs/common";
+
+export interface User {
+  id: string;
+  name: string;
+}
+
+@Injectable()
+export class UsersService {
+  private readonly users: User[] = [];
+
+  findAll(): User[] {
+    return this.users;
+  }
+
+  findOne(id: string): User | undefined {
+    return this.users.find((user) => user.id === id);
+  }
+
+  create(name: string): User {
+    const user = { id: String(this.users.length + 1), name };
+    this.users.push(user);
+    return user;
+  }
+}
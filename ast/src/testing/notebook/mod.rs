@@ -0,0 +1,86 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_notebook_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/notebook",
+        Lang::from_str("python").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let notebook_file = "src/testing/notebook/notebooks/churn.ipynb";
+    let file = graph
+        .find_nodes_by_file_ends_with(NodeType::File, notebook_file)
+        .into_iter()
+        .next()
+        .expect("notebook file not found");
+    assert!(
+        file.body.starts_with("# %% [cell 1]\n#%matplotlib inline\nimport pandas as pd"),
+        "notebook file body should be the python source of its code cells"
+    );
+
+    let score = graph
+        .find_node_by_name_in_file(NodeType::Function, "score", notebook_file)
+        .expect("score function not found in notebook");
+    assert_eq!(score.start, 5, "score should start on line 5 of the notebook source");
+    assert_eq!(score.meta.get("cell"), Some(&"2".to_string()));
+    assert_eq!(score.meta.get("cell_line"), Some(&"0".to_string()));
+
+    let scores = graph
+        .find_node_by_name_in_file(NodeType::Var, "scores", notebook_file)
+        .expect("scores variable not found in notebook");
+    assert_eq!(scores.meta.get("cell"), Some(&"3".to_string()));
+    assert_eq!(scores.meta.get("cell_line"), Some(&"1".to_string()));
+
+    let build_features = graph
+        .find_node_by_name_in_file(
+            NodeType::Function,
+            "build_features",
+            "src/testing/notebook/features.py",
+        )
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("build_features function not found");
+    assert!(
+        graph.has_edge(
+            &Node::new(NodeType::File, file),
+            &build_features,
+            EdgeType::Imports
+        ),
+        "Expected the notebook to import build_features"
+    );
+    assert!(
+        graph.has_edge(
+            &Node::new(NodeType::Function, score),
+            &build_features,
+            EdgeType::Calls
+        ),
+        "Expected score to call build_features"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_notebook() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_notebook_generic::<ArrayGraph>().await.unwrap();
+    test_notebook_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_notebook_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,138 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::linker::link_openapi_endpoints;
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_openapi_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/openapi",
+        Lang::from_str("ts").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let mut graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let data_models = graph.find_nodes_by_type(NodeType::DataModel);
+    assert_eq!(data_models.len(), 2, "Expected 2 data models");
+    for name in ["Item", "NewItem"] {
+        assert!(
+            data_models
+                .iter()
+                .any(|dm| dm.name == name && dm.file == "src/testing/openapi/openapi.yaml"),
+            "{} schema data model not found",
+            name
+        );
+    }
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 8, "Expected 8 endpoints");
+
+    let spec_endpoints: Vec<_> = endpoints
+        .iter()
+        .filter(|e| e.meta.get("source") == Some(&"openapi".to_string()))
+        .collect();
+    assert_eq!(spec_endpoints.len(), 4, "Expected 4 spec endpoints");
+
+    let expected = [
+        ("GET", "/items", "listItems", Some("limit:query")),
+        ("POST", "/items", "createItem", None),
+        ("GET", "/items/{id}", "getItem", Some("id:path")),
+        ("GET", "/orders", "listOrders", None),
+    ];
+    for (verb, path, operation_id, parameters) in expected {
+        let endpoint = spec_endpoints
+            .iter()
+            .find(|e| e.name == path && e.meta.get("verb") == Some(&verb.to_string()))
+            .unwrap_or_else(|| panic!("spec endpoint {} {} not found", verb, path));
+        assert_eq!(
+            endpoint.meta.get("operation_id"),
+            Some(&operation_id.to_string()),
+            "{} {} operationId is incorrect",
+            verb,
+            path
+        );
+        assert_eq!(
+            endpoint.meta.get("parameters").map(|p| p.as_str()),
+            parameters,
+            "{} {} parameters are incorrect",
+            verb,
+            path
+        );
+    }
+
+    let schema_edges = graph.find_nodes_with_edge_type(
+        NodeType::Endpoint,
+        NodeType::DataModel,
+        EdgeType::Contains,
+    );
+    assert_eq!(schema_edges.len(), 4, "Expected 4 endpoint schema edges");
+    let create_item = spec_endpoints
+        .iter()
+        .find(|e| e.meta.get("operation_id") == Some(&"createItem".to_string()))
+        .map(|n| Node::new(NodeType::Endpoint, (*n).clone()))
+        .unwrap();
+    let new_item = data_models
+        .iter()
+        .find(|dm| dm.name == "NewItem")
+        .map(|n| Node::new(NodeType::DataModel, n.clone()))
+        .unwrap();
+    assert!(
+        graph.has_edge(&create_item, &new_item, EdgeType::Contains),
+        "Expected createItem to use the NewItem schema"
+    );
+
+    link_openapi_endpoints(&mut graph)?;
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    let flagged = |flag: &str| -> Vec<String> {
+        let mut names: Vec<String> = endpoints
+            .iter()
+            .filter(|e| e.meta.get(flag) == Some(&"true".to_string()))
+            .map(|e| format!("{} {}", e.meta.get("verb").unwrap(), e.name))
+            .collect();
+        names.sort();
+        names
+    };
+    assert_eq!(flagged("spec_only"), vec!["GET /orders"]);
+    assert_eq!(flagged("code_only"), vec!["DELETE /items/:id"]);
+
+    let get_item = endpoints
+        .iter()
+        .find(|e| e.name == "/items/:id" && e.meta.get("verb") == Some(&"GET".to_string()))
+        .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+        .expect("GET /items/:id endpoint not found");
+    let get_item_fn = graph
+        .find_nodes_by_name(NodeType::Function, "getItem")
+        .into_iter()
+        .next()
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("getItem function not found");
+    assert!(
+        graph.has_edge(&get_item, &get_item_fn, EdgeType::Handler),
+        "Expected GET /items/:id to be handled by getItem"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_openapi() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_openapi_generic::<ArrayGraph>().await.unwrap();
+    test_openapi_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_openapi_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
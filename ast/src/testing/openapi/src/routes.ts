@@ -0,0 +1,30 @@
+import { Request, Response } from "express";
+
+const items = new Map<string, { id: string; name: string }>();
+
+export function registerRoutes(app) {
+  app.get("/items", listItems);
+  app.post("/items", createItem);
+  app.get("/items/:id", getItem);
+  app.delete("/items/:id", deleteItem);
+}
+
+function listItems(req: Request, res: Response) {
+  const limit = Number(req.query.limit ?? items.size);
+  return res.json([...items.values()].slice(0, limit));
+}
+
+function createItem(req: Request, res: Response) {
+  const item = { id: String(items.size + 1), name: req.body.name };
+  items.set(item.id, item);
+  return res.status(201).json(item);
+}
+
+function getItem(req: Request, res: Response) {
+  return res.json(items.get(req.params.id));
+}
+
+function deleteItem(req: Request, res: Response) {
+  items.delete(req.params.id);
+  return res.status(204).end();
+}
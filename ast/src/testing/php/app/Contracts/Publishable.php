@@ -0,0 +1,8 @@
+<?php
+
+namespace App\Contracts;
+
+interface Publishable
+{
+    public function publish(): void;
+}
@@ -0,0 +1,37 @@
+<?php
+
+namespace App\Http\Controllers;
+
+use App\Models\Post;
+use App\Services\PostService;
+use Illuminate\Http\Request;
+
+class PostController extends Controller
+{
+    public function __construct(private PostService $posts)
+    {
+    }
+
+    // List the latest published posts
+    public function index()
+    {
+        return $this->posts->latest(20);
+    }
+
+    public function store(Request $request)
+    {
+        $post = $this->posts->publish($request->user(), $request->validated());
+        return response()->json($post, 201);
+    }
+
+    public function show(Post $post)
+    {
+        return $post->load('author');
+    }
+
+    public function destroy(Post $post)
+    {
+        $post->delete();
+        return response()->noContent();
+    }
+}
@@ -0,0 +1,20 @@
+<?php
+
+namespace App\Http\Controllers;
+
+use App\Models\User;
+use Illuminate\Http\Request;
+
+class UserController extends Controller
+{
+    public function show(User $user)
+    {
+        return $user->load('posts');
+    }
+
+    public function update(Request $request, User $user)
+    {
+        $user->update($request->only(['name', 'email']));
+        return $user;
+    }
+}
@@ -0,0 +1,27 @@
+<?php
+
+namespace App\Models;
+
+use App\Contracts\Publishable;
+use App\Traits\Sluggable;
+use Illuminate\Database\Eloquent\Model;
+use Illuminate\Database\Eloquent\Relations\BelongsTo;
+
+class Post extends Model implements Publishable
+{
+    use Sluggable;
+
+    protected $fillable = ['title', 'body', 'published_at'];
+
+    public function author(): BelongsTo
+    {
+        return $this->belongsTo(User::class, 'user_id');
+    }
+
+    public function publish(): void
+    {
+        $this->slug = $this->makeSlug($this->title);
+        $this->published_at = now();
+        $this->save();
+    }
+}
@@ -0,0 +1,22 @@
+<?php
+
+namespace App\Services;
+
+use App\Models\Post;
+use App\Models\User;
+
+class PostService
+{
+    public function latest(int $count)
+    {
+        return Post::query()->latest()->take($count)->get();
+    }
+
+    public function publish(User $author, array $data): Post
+    {
+        $post = new Post($data);
+        $post->author()->associate($author);
+        $post->publish();
+        return $post;
+    }
+}
@@ -0,0 +1,13 @@
+<?php
+
+namespace App\Traits;
+
+use Illuminate\Support\Str;
+
+trait Sluggable
+{
+    public function makeSlug(string $value): string
+    {
+        return Str::slug($value);
+    }
+}
@@ -0,0 +1,8 @@
+<?php
+
+const DEFAULT_EXCERPT_LENGTH = 120;
+
+function excerpt(string $body, int $length = DEFAULT_EXCERPT_LENGTH): string
+{
+    return mb_substr(strip_tags($body), 0, $length);
+}
@@ -0,0 +1,175 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::error::Result;
+use std::str::FromStr;
+
+pub async fn test_php_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/php",
+        Lang::from_str("php").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let language_nodes = graph.find_nodes_by_name(NodeType::Language, "php");
+    assert_eq!(language_nodes.len(), 1, "Expected 1 language node");
+
+    let files = graph.find_nodes_by_type(NodeType::File);
+    assert_eq!(files.len(), 11, "Expected 11 files");
+
+    let libraries = graph.find_nodes_by_type(NodeType::Library);
+    assert_eq!(libraries.len(), 4, "Expected 4 libraries");
+    let framework = libraries
+        .iter()
+        .find(|l| l.name == "laravel/framework")
+        .expect("laravel/framework library not found");
+    assert_eq!(
+        framework.meta.get("version"),
+        Some(&"^11.0".to_string()),
+        "laravel/framework version is incorrect"
+    );
+
+    let imports = graph.find_nodes_by_type(NodeType::Import);
+    assert_eq!(imports.len(), 7, "Expected 7 imports");
+
+    let variables = graph.find_nodes_by_type(NodeType::Var);
+    assert_eq!(variables.len(), 1, "Expected 1 variable");
+    assert_eq!(variables[0].name, "DEFAULT_EXCERPT_LENGTH");
+
+    let classes = graph.find_nodes_by_type(NodeType::Class);
+    assert_eq!(classes.len(), 6, "Expected 6 classes");
+
+    let traits = graph.find_nodes_by_type(NodeType::Trait);
+    assert_eq!(traits.len(), 2, "Expected 2 traits");
+
+    let implements = graph.count_edges_of_type(EdgeType::Implements);
+    assert_eq!(implements, 2, "Expected 2 implements edges");
+
+    let post_class = graph
+        .find_nodes_by_name(NodeType::Class, "Post")
+        .into_iter()
+        .find(|n| n.file == "src/testing/php/app/Models/Post.php")
+        .map(|n| Node::new(NodeType::Class, n))
+        .expect("Post class not found");
+    for name in ["Sluggable", "Publishable"] {
+        let tr = graph
+            .find_nodes_by_name(NodeType::Trait, name)
+            .into_iter()
+            .next()
+            .map(|n| Node::new(NodeType::Trait, n))
+            .expect("trait not found");
+        assert!(
+            graph.has_edge(&post_class, &tr, EdgeType::Implements),
+            "Expected Post to implement {}",
+            name
+        );
+    }
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 14, "Expected 14 functions");
+
+    let data_models = graph.find_nodes_by_type(NodeType::DataModel);
+    assert_eq!(data_models.len(), 2, "Expected 2 data models");
+    assert!(data_models.iter().any(|dm| dm.name == "Post"));
+    assert!(data_models.iter().any(|dm| dm.name == "User"));
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 7, "Expected 7 endpoints");
+
+    let handlers = graph.count_edges_of_type(EdgeType::Handler);
+    assert_eq!(handlers, 6, "Expected 6 handler edges");
+
+    let expected = [
+        ("/posts", "GET", "index", "PostController.php"),
+        ("/posts", "POST", "store", "PostController.php"),
+        ("/posts/{post}", "GET", "show", "PostController.php"),
+        ("/posts/{post}", "DELETE", "destroy", "PostController.php"),
+        ("/users/{user}", "GET", "show", "UserController.php"),
+        ("/users/{user}", "PUT", "update", "UserController.php"),
+    ];
+    for (path, verb, handler, file) in expected {
+        let endpoint = endpoints
+            .iter()
+            .find(|e| e.name == path && e.meta.get("verb") == Some(&verb.to_string()))
+            .map(|e| Node::new(NodeType::Endpoint, e.clone()))
+            .unwrap_or_else(|| panic!("{} {} endpoint not found", verb, path));
+        let handler_fn = graph
+            .find_nodes_by_name(NodeType::Function, handler)
+            .into_iter()
+            .find(|f| f.file.ends_with(file))
+            .map(|f| Node::new(NodeType::Function, f))
+            .unwrap_or_else(|| panic!("{} function not found in {}", handler, file));
+        assert!(
+            graph.has_edge(&endpoint, &handler_fn, EdgeType::Handler),
+            "Expected {} {} to be handled by {} in {}",
+            verb,
+            path,
+            handler,
+            file
+        );
+    }
+
+    assert!(
+        endpoints
+            .iter()
+            .any(|e| e.name == "/health" && e.meta.get("verb") == Some(&"GET".to_string())),
+        "Expected closure GET /health endpoint"
+    );
+
+    let index_fn = graph
+        .find_nodes_by_name(NodeType::Function, "index")
+        .into_iter()
+        .next()
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("index function not found");
+    let latest_fn = graph
+        .find_nodes_by_name(NodeType::Function, "latest")
+        .into_iter()
+        .next()
+        .map(|n| Node::new(NodeType::Function, n))
+        .expect("latest function not found");
+    assert!(
+        graph.has_edge(&index_fn, &latest_fn, EdgeType::Calls),
+        "Expected index to call PostService::latest"
+    );
+
+    let post_dm = data_models
+        .iter()
+        .find(|dm| dm.name == "Post")
+        .map(|dm| Node::new(NodeType::DataModel, dm.clone()))
+        .unwrap();
+    let show_post_fn = graph
+        .find_nodes_by_name(NodeType::Function, "show")
+        .into_iter()
+        .find(|f| f.file.ends_with("PostController.php"))
+        .map(|f| Node::new(NodeType::Function, f))
+        .unwrap();
+    assert!(
+        graph.has_edge(&show_post_fn, &post_dm, EdgeType::Contains),
+        "Expected PostController::show to use the Post data model"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_php() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_php_generic::<ArrayGraph>().await.unwrap();
+    test_php_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_php_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,16 @@
+<?php
+
+use App\Http\Controllers\PostController;
+use App\Http\Controllers\UserController;
+use Illuminate\Support\Facades\Route;
+
+Route::get('/posts', [PostController::class, 'index']);
+Route::post('/posts', [PostController::class, 'store']);
+Route::get('/posts/{post}', [PostController::class, 'show']);
+Route::delete('/posts/{post}', [PostController::class, 'destroy']);
+Route::get('/users/{user}', [UserController::class, 'show']);
+Route::put('/users/{user}', 'UserController@update');
+
+Route::get('/health', function () {
+    return ['status' => 'ok'];
+});
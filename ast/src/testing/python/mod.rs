@@ -57,14 +57,14 @@ pub async fn test_python_generic<G: Graph>() -> Result<()> {
     edges_count += implements;
     assert_eq!(implements, 1, "Expected 1 implements edges");
 
+    // includes create_person and create_new_person -> CreateOrEditPerson from their type hints
     let contains = graph.count_edges_of_type(EdgeType::Contains);
-    assert_eq!(contains, 102, "Expected 102 contains edges");
+    assert_eq!(contains, 104, "Expected 104 contains edges");
     edges_count += contains;
 
     let handlers = graph.count_edges_of_type(EdgeType::Handler);
     edges_count += handlers;
-    //FIXME: this ough t o be 6 hadndlers
-    assert_eq!(handlers, 4, "Expected 4 handler edges");
+    assert_eq!(handlers, 6, "Expected 6 handler edges");
 
     let uses = graph.count_edges_of_type(EdgeType::Uses);
     edges_count += uses;
@@ -146,8 +146,15 @@ from flask_app.routes import flask_bp"#
 
     let data_models = graph.find_nodes_by_type(NodeType::DataModel);
     nodes_count += data_models.len();
-    //should be 3, but some classes are picked up as datamodels
     assert_eq!(data_models.len(), 5, "Expected 5 data models");
+    let model_kind = |name: &str| {
+        data_models
+            .iter()
+            .find(|dm| dm.name == name)
+            .and_then(|dm| dm.meta.get("model").cloned())
+    };
+    assert_eq!(model_kind("PersonSummary"), Some("dataclass".to_string()));
+    assert_eq!(model_kind("PersonAudit"), Some("attrs".to_string()));
 
     let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
     nodes_count += endpoints.len();
@@ -211,6 +218,28 @@ from flask_app.routes import flask_bp"#
         ),
         "Expected 'model.py' file to contain 'CreateOrEditPerson' DataModel"
     );
+    assert_eq!(
+        create_or_edit_person_dm.node_data.meta.get("model"),
+        Some(&"pydantic".to_string())
+    );
+    assert_eq!(
+        create_or_edit_person_dm.node_data.meta.get("fields"),
+        Some(&"id:Optional[int];name:str;email:str".to_string())
+    );
+    let person_dm = graph
+        .find_nodes_by_name(NodeType::DataModel, "Person")
+        .into_iter()
+        .next()
+        .expect("Person DataModel not found");
+    assert_eq!(person_dm.meta.get("model"), Some(&"sqlalchemy".to_string()));
+    assert_eq!(
+        person_dm.meta.get("fields"),
+        Some(&"id:Integer;name:String;email:String".to_string())
+    );
+    assert!(
+        graph.has_edge(&create_person_fn, &create_or_edit_person_dm, EdgeType::Contains),
+        "Expected FastAPI create_person to use its CreateOrEditPerson parameter type"
+    );
     assert!(
         graph.has_edge(&fastapi_post_endpoint, &create_person_fn, EdgeType::Handler),
         "Expected FastAPI '/person/' POST endpoint to be handled by 'create_person'"
@@ -372,6 +401,10 @@ from flask_app.routes import flask_bp"#
         graph.has_edge(&db_file, &create_new_person_fn, EdgeType::Contains),
         "Expected db.py to contain create_new_person function"
     );
+    assert!(
+        graph.has_edge(&create_new_person_fn, &create_or_edit_person_dm, EdgeType::Contains),
+        "Expected create_new_person to use its CreateOrEditPerson parameter type"
+    );
 
     // Test function calls between database operations and route handlers
     assert!(
@@ -3,6 +3,8 @@ from database import Base
 from pydantic import BaseModel
 from typing import Optional
 from abc import ABC, abstractmethod
+from dataclasses import dataclass
+import attr
 
 
 class Person(Base):
@@ -44,6 +46,21 @@ class PersonResponse(BaseModel):
     email: str
 
 
+@dataclass
+class PersonSummary:
+    """
+    PersonSummary for listing people
+    """
+    id: int
+    name: str
+
+
+@attr.s
+class PersonAudit:
+    person_id = attr.ib(type=int)
+    action = attr.ib(type=str)
+
+
 class Animal(ABC):
 
     @abstractmethod
@@ -0,0 +1,12 @@
+from django.urls import include, path
+from rest_framework.routers import DefaultRouter
+from blog import views
+
+router = DefaultRouter()
+router.register(r'posts', views.PostViewSet, basename='post')
+
+urlpatterns = [
+    path('stats/', views.stats, name='stats'),
+    path('authors/', views.AuthorList.as_view(), name='authors'),
+    path('', include(router.urls)),
+]
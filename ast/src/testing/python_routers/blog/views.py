@@ -0,0 +1,28 @@
+from django.http import JsonResponse
+from rest_framework import generics, viewsets
+from rest_framework.decorators import action
+from rest_framework.response import Response
+
+
+class PostViewSet(viewsets.ModelViewSet):
+    def list(self, request):
+        return Response([])
+
+    def retrieve(self, request, pk=None):
+        return Response({"id": pk})
+
+    @action(detail=True, methods=['post'])
+    def publish(self, request, pk=None):
+        return Response({"id": pk, "published": True})
+
+
+class AuthorList(generics.ListAPIView):
+    def get(self, request):
+        return Response([])
+
+    def post(self, request):
+        return Response(request.data, status=201)
+
+
+def stats(request):
+    return JsonResponse({"posts": 0})
@@ -0,0 +1,92 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::error::Result;
+use std::str::FromStr;
+
+pub async fn test_python_routers_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/python_routers",
+        Lang::from_str("python").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 12, "Expected 12 endpoints");
+    let endpoint = |name: &str, verb: &str| {
+        endpoints
+            .iter()
+            .find(|e| e.name == name && e.meta.get("verb") == Some(&verb.to_string()))
+            .map(|e| Node::new(NodeType::Endpoint, e.clone()))
+            .unwrap_or_else(|| panic!("{} {} endpoint not found", verb, name))
+    };
+    let function = |name: &str, file: &str| {
+        graph
+            .find_nodes_by_name(NodeType::Function, name)
+            .into_iter()
+            .find(|f| f.file.ends_with(file))
+            .map(|f| Node::new(NodeType::Function, f))
+            .unwrap_or_else(|| panic!("{} function not found in {}", name, file))
+    };
+    let views = "blog/views.py";
+
+    // django include() prefixes
+    let stats = endpoint("api/stats/", "GET");
+    assert!(graph.has_edge(&stats, &function("stats", views), EdgeType::Handler));
+
+    // DRF router.register expands into the viewset actions
+    let list_posts = endpoint("api/posts/", "GET");
+    let get_post = endpoint("api/posts/<pk>/", "GET");
+    let publish_post = endpoint("api/posts/<pk>/publish/", "POST");
+    endpoint("api/posts/", "POST");
+    endpoint("api/posts/<pk>/", "PUT");
+    endpoint("api/posts/<pk>/", "PATCH");
+    let delete_post = endpoint("api/posts/<pk>/", "DELETE");
+    assert_eq!(
+        delete_post.node_data.meta.get("handler"),
+        Some(&"PostViewSet.destroy".to_string())
+    );
+    assert!(graph.has_edge(&list_posts, &function("list", views), EdgeType::Handler));
+    assert!(graph.has_edge(&get_post, &function("retrieve", views), EdgeType::Handler));
+    assert!(graph.has_edge(&publish_post, &function("publish", views), EdgeType::Handler));
+
+    // class based views with as_view()
+    let list_authors = endpoint("api/authors/", "GET");
+    let create_author = endpoint("api/authors/", "POST");
+    assert!(graph.has_edge(&list_authors, &function("get", views), EdgeType::Handler));
+    assert!(graph.has_edge(&create_author, &function("post", views), EdgeType::Handler));
+
+    // fastapi APIRouter and include_router prefixes
+    let users = "service/routers/users.py";
+    let get_user = endpoint("/api/v1/users/{id}", "GET");
+    let create_user = endpoint("/api/v1/users", "POST");
+    assert!(graph.has_edge(&get_user, &function("get_user", users), EdgeType::Handler));
+    assert!(graph.has_edge(&create_user, &function("create_user", users), EdgeType::Handler));
+
+    let handlers = graph.count_edges_of_type(EdgeType::Handler);
+    assert_eq!(handlers, 8, "Expected 8 handler edges");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_python_routers() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_python_routers_generic::<ArrayGraph>().await.unwrap();
+    test_python_routers_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_python_routers_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,5 @@
+from fastapi import APIRouter
+from service.routers import users
+
+api_router = APIRouter(prefix="/v1")
+api_router.include_router(users.router, prefix="/users")
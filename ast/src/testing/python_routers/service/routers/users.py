@@ -0,0 +1,13 @@
+from fastapi import APIRouter
+
+router = APIRouter()
+
+
+@router.get("/{id}")
+async def get_user(id: int):
+    return {"id": id}
+
+
+@router.post("/")
+async def create_user(user: dict):
+    return user
@@ -0,0 +1,103 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_python_tasks_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/python_tasks",
+        Lang::from_str("python").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let jobs = graph.find_nodes_by_type(NodeType::Job);
+    assert_eq!(jobs.len(), 5, "Expected 3 tasks and 2 beat entries");
+    let job = |name: &str| {
+        jobs.iter()
+            .find(|j| j.name == name)
+            .map(|n| Node::new(NodeType::Job, n.clone()))
+            .unwrap_or_else(|| panic!("{} job not found", name))
+    };
+    let meta = |job: &Node, key: &str| job.node_data.meta.get(key).cloned().unwrap_or_default();
+
+    let cleanup = job("cleanup");
+    assert_eq!(meta(&cleanup, "framework"), "celery");
+    assert_eq!(meta(&cleanup, "task"), "proj.tasks.cleanup");
+    assert_eq!(meta(&cleanup, "queue"), "celery");
+    let build_report = job("build_report");
+    assert_eq!(meta(&build_report, "task"), "reports.build");
+    assert_eq!(meta(&build_report, "queue"), "reports");
+    let send_welcome = job("send_welcome");
+    assert_eq!(meta(&send_welcome, "framework"), "dramatiq");
+    assert_eq!(meta(&send_welcome, "queue"), "emails");
+
+    let nightly = job("nightly-report");
+    assert_eq!(meta(&nightly, "framework"), "celery_beat");
+    assert_eq!(meta(&nightly, "schedule"), "crontab(hour=3, minute=0)");
+    let every_5_minutes = job("cleanup every 5 minutes");
+    assert_eq!(meta(&every_5_minutes, "schedule"), "300.0");
+
+    let function = |name: &str, path: &str| {
+        graph
+            .find_nodes_by_name(NodeType::Function, name)
+            .into_iter()
+            .find(|f| f.file.ends_with(path))
+            .map(|n| Node::new(NodeType::Function, n))
+            .unwrap_or_else(|| panic!("{} function not found in {}", name, path))
+    };
+    let cleanup_fn = function("cleanup", "proj/tasks.py");
+    let build_report_fn = function("build_report", "proj/tasks.py");
+    let send_welcome_fn = function("send_welcome", "proj/emails.py");
+
+    let handlers = graph.count_edges_of_type(EdgeType::Handler);
+    assert_eq!(handlers, 5, "Expected 5 job handler edges");
+    assert!(graph.has_edge(&cleanup, &cleanup_fn, EdgeType::Handler));
+    assert!(graph.has_edge(&build_report, &build_report_fn, EdgeType::Handler));
+    assert!(graph.has_edge(&send_welcome, &send_welcome_fn, EdgeType::Handler));
+    assert!(
+        graph.has_edge(&nightly, &build_report_fn, EdgeType::Handler),
+        "Expected the nightly-report beat entry to fire reports.build"
+    );
+    assert!(graph.has_edge(&every_5_minutes, &cleanup_fn, EdgeType::Handler));
+
+    let create_report = function("create_report", "proj/views.py");
+    let signup = function("signup", "proj/views.py");
+    let purge = function("purge", "proj/views.py");
+    assert!(
+        graph.has_edge(&create_report, &build_report_fn, EdgeType::Calls),
+        "Expected create_report to enqueue build_report with delay()"
+    );
+    assert!(
+        graph.has_edge(&signup, &send_welcome_fn, EdgeType::Calls),
+        "Expected signup to send the send_welcome actor"
+    );
+    assert!(
+        graph.has_edge(&purge, &cleanup_fn, EdgeType::Calls),
+        "Expected purge to send_task proj.tasks.cleanup"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_python_tasks() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_python_tasks_generic::<ArrayGraph>().await.unwrap();
+    test_python_tasks_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_python_tasks_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,19 @@
+from celery import Celery
+from celery.schedules import crontab
+
+from proj.tasks import cleanup
+
+app = Celery("proj", broker="redis://localhost:6379/0")
+
+app.conf.beat_schedule = {
+    "nightly-report": {
+        "task": "reports.build",
+        "schedule": crontab(hour=3, minute=0),
+        "args": (1,),
+    },
+}
+
+
+@app.on_after_configure.connect
+def setup_periodic_tasks(sender, **kwargs):
+    sender.add_periodic_task(300.0, cleanup.s(), name="cleanup every 5 minutes")
@@ -0,0 +1,6 @@
+import dramatiq
+
+
+@dramatiq.actor(queue_name="emails", max_retries=3)
+def send_welcome(user_id):
+    print(f"welcome {user_id}")
@@ -0,0 +1,15 @@
+from celery import shared_task
+
+from proj.celery import app
+
+
+@shared_task
+def cleanup():
+    """Remove reports that were never finished."""
+    return 0
+
+
+@app.task(name="reports.build", queue="reports")
+def build_report(report_id):
+    """Render a report and store it."""
+    return report_id
@@ -0,0 +1,20 @@
+from celery import current_app
+from django.http import JsonResponse
+
+from proj.emails import send_welcome
+from proj.tasks import build_report
+
+
+def create_report(request):
+    result = build_report.delay(request.POST["id"])
+    return JsonResponse({"task": result.id})
+
+
+def signup(request):
+    send_welcome.send(request.user.id)
+    return JsonResponse({"ok": True})
+
+
+def purge(request):
+    current_app.send_task("proj.tasks.cleanup")
+    return JsonResponse({"ok": True})
@@ -58,7 +58,23 @@ pub async fn test_ruby_generic<G: Graph>() -> Result<()> {
 
     let libraries = graph.find_nodes_by_type(NodeType::Library);
     nodes_count += libraries.len();
-    assert_eq!(libraries.len(), 5, "Expected 5 library nodes, got {}", libraries.len());
+    // 5 from the Gemfile, and the other 61 gems of Gemfile.lock
+    assert_eq!(libraries.len(), 66, "Expected 66 library nodes, got {}", libraries.len());
+    let library = |name: &str| {
+        libraries
+            .iter()
+            .find(|l| l.name == name)
+            .map(|n| Node::new(NodeType::Library, n.clone()))
+            .unwrap_or_else(|| panic!("{} library not found", name))
+    };
+    let rails = library("rails");
+    assert_eq!(rails.node_data.meta.get("resolved_version"), Some(&"8.0.2".to_string()));
+    assert_eq!(rails.node_data.meta.get("direct"), Some(&"true".to_string()));
+    let railties = library("railties");
+    assert_eq!(railties.node_data.file, "src/testing/ruby/Gemfile.lock");
+    assert_eq!(railties.node_data.meta.get("direct"), Some(&"false".to_string()));
+    let sqlite3 = library("sqlite3");
+    assert_eq!(sqlite3.node_data.meta.get("resolved_version"), Some(&"2.6.0".to_string()));
 
     let pkg_files = graph.find_nodes_by_name(NodeType::File, "Gemfile");
     assert_eq!(pkg_files.len(), 1, "Expected 1 Gemfile");
@@ -265,7 +281,12 @@ pub async fn test_ruby_generic<G: Graph>() -> Result<()> {
 
     let contains = graph.count_edges_of_type(EdgeType::Contains);
     edges_count += contains;
-    assert_eq!(contains, 139, "Expected 139 Contains edges, got {}", contains);
+    assert_eq!(contains, 200, "Expected 200 Contains edges, got {}", contains);
+
+    let depends_on = graph.count_edges_of_type(EdgeType::DependsOn);
+    edges_count += depends_on;
+    assert_eq!(depends_on, 116, "Expected 116 DependsOn edges, got {}", depends_on);
+    assert!(graph.has_edge(&rails, &railties, EdgeType::DependsOn));
 
     let renders = graph.count_edges_of_type(EdgeType::Renders);
     edges_count += renders;
@@ -275,6 +296,10 @@ pub async fn test_ruby_generic<G: Graph>() -> Result<()> {
     edges_count += operands;
     assert_eq!(operands, 18, "Expected 18 operand edges, got {}", operands);
 
+    let references = graph.count_edges_of_type(EdgeType::References);
+    edges_count += references;
+    assert_eq!(references, 1, "Expected 1 references edge, got {}", references);
+
     let classes = graph.find_nodes_by_type(NodeType::Class);
     nodes_count += classes.len();
     assert_eq!(classes.len(), 16, "Expected 16 class nodes, got {}", classes.len());
@@ -504,6 +529,24 @@ pub async fn test_ruby_generic<G: Graph>() -> Result<()> {
             .contains("t.integer \"person_id\", null: false"),
         "articles table should have person_id foreign key"
     );
+    assert_eq!(
+        articles_table.meta.get("foreign_keys"),
+        Some(&"person_id -> people.id".to_string()),
+        "articles table should reference people"
+    );
+    assert_eq!(
+        people_table.meta.get("indexes"),
+        Some(&"index_people_on_email(email) unique".to_string()),
+        "people table should have index metadata"
+    );
+    assert!(
+        graph.has_edge(
+            &Node::new(NodeType::DataModel, articles_table.clone()),
+            &Node::new(NodeType::DataModel, people_table.clone()),
+            EdgeType::References
+        ),
+        "articles should reference people"
+    );
 
     let create_people_migration = classes
         .iter()
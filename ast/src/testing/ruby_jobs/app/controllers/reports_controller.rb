@@ -0,0 +1,19 @@
+class ReportsController < ApplicationController
+  def create
+    report = Report.create!(report_params)
+    ReportJob.perform_later(report.id)
+    Admin::CleanupJob.set(wait: 1.hour).perform_later
+    render json: report
+  end
+
+  def destroy
+    HardWorker.perform_async(params[:id])
+    head :no_content
+  end
+
+  private
+
+  def report_params
+    params.require(:report).permit(:title)
+  end
+end
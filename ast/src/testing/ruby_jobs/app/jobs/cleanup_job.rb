@@ -0,0 +1,7 @@
+module Admin
+  class CleanupJob < ApplicationJob
+    def perform
+      Report.stale.destroy_all
+    end
+  end
+end
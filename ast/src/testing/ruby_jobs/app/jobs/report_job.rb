@@ -0,0 +1,8 @@
+class ReportJob < ApplicationJob
+  queue_as :reports
+
+  def perform(report_id)
+    report = Report.find(report_id)
+    report.generate!
+  end
+end
@@ -0,0 +1,8 @@
+class HardWorker
+  include Sidekiq::Worker
+  sidekiq_options queue: "critical", retry: 3
+
+  def perform(report_id)
+    Report.find(report_id).archive!
+  end
+end
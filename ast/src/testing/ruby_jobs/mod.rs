@@ -0,0 +1,118 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_ruby_jobs_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/ruby_jobs",
+        Lang::from_str("ruby").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let jobs = graph.find_nodes_by_type(NodeType::Job);
+    assert_eq!(jobs.len(), 3, "Expected 3 jobs");
+    let job = |name: &str| {
+        jobs.iter()
+            .find(|j| j.name == name)
+            .map(|n| Node::new(NodeType::Job, n.clone()))
+            .unwrap_or_else(|| panic!("{} job not found", name))
+    };
+    let report_job = job("ReportJob");
+    let cleanup_job = job("CleanupJob");
+    let hard_worker = job("HardWorker");
+    assert!(
+        !jobs.iter().any(|j| j.name == "ApplicationJob"),
+        "ApplicationJob has nothing to perform"
+    );
+    let meta = |job: &Node, key: &str| job.node_data.meta.get(key).cloned().unwrap_or_default();
+    assert_eq!(meta(&report_job, "framework"), "activejob");
+    assert_eq!(meta(&report_job, "queue"), "reports");
+    assert_eq!(meta(&cleanup_job, "queue"), "default");
+    assert_eq!(meta(&hard_worker, "framework"), "sidekiq");
+    assert_eq!(meta(&hard_worker, "queue"), "critical");
+
+    let queues = graph.find_nodes_by_type(NodeType::Queue);
+    let mut queue_names: Vec<&str> = queues.iter().map(|q| q.name.as_str()).collect();
+    queue_names.sort();
+    assert_eq!(queue_names, vec!["critical", "default", "reports"]);
+    let queue = |name: &str| {
+        queues
+            .iter()
+            .find(|q| q.name == name)
+            .map(|n| Node::new(NodeType::Queue, n.clone()))
+            .unwrap()
+    };
+    assert_eq!(graph.count_edges_of_type(EdgeType::OnQueue), 3);
+    assert!(graph.has_edge(&report_job, &queue("reports"), EdgeType::OnQueue));
+    assert!(graph.has_edge(&cleanup_job, &queue("default"), EdgeType::OnQueue));
+    assert!(graph.has_edge(&hard_worker, &queue("critical"), EdgeType::OnQueue));
+    assert!(!graph.has_edge(&report_job, &queue("default"), EdgeType::OnQueue));
+
+    let report_job_file = graph
+        .find_nodes_by_file_ends_with(NodeType::File, "app/jobs/report_job.rb")
+        .into_iter()
+        .next()
+        .map(|n| Node::new(NodeType::File, n))
+        .expect("report_job.rb file not found");
+    assert!(graph.has_edge(&report_job_file, &report_job, EdgeType::Contains));
+
+    let function = |name: &str, path: &str| {
+        graph
+            .find_nodes_by_name(NodeType::Function, name)
+            .into_iter()
+            .find(|f| f.file.ends_with(path))
+            .map(|n| Node::new(NodeType::Function, n))
+            .unwrap_or_else(|| panic!("{} function not found in {}", name, path))
+    };
+    let report_perform = function("perform", "app/jobs/report_job.rb");
+    let cleanup_perform = function("perform", "app/jobs/cleanup_job.rb");
+    let hard_perform = function("perform", "app/workers/hard_worker.rb");
+    let create = function("create", "app/controllers/reports_controller.rb");
+    let destroy = function("destroy", "app/controllers/reports_controller.rb");
+
+    let handlers = graph.count_edges_of_type(EdgeType::Handler);
+    assert_eq!(handlers, 3, "Expected 3 job handler edges");
+    assert!(graph.has_edge(&report_job, &report_perform, EdgeType::Handler));
+    assert!(graph.has_edge(&cleanup_job, &cleanup_perform, EdgeType::Handler));
+    assert!(graph.has_edge(&hard_worker, &hard_perform, EdgeType::Handler));
+
+    assert!(
+        graph.has_edge(&create, &report_perform, EdgeType::Calls),
+        "Expected create to enqueue ReportJob"
+    );
+    assert!(
+        graph.has_edge(&create, &cleanup_perform, EdgeType::Calls),
+        "Expected create to enqueue Admin::CleanupJob"
+    );
+    assert!(
+        graph.has_edge(&destroy, &hard_perform, EdgeType::Calls),
+        "Expected destroy to enqueue HardWorker"
+    );
+    assert!(!graph.has_edge(&destroy, &report_perform, EdgeType::Calls));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_ruby_jobs() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_ruby_jobs_generic::<ArrayGraph>().await.unwrap();
+    test_ruby_jobs_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_ruby_jobs_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
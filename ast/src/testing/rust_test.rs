@@ -93,7 +93,26 @@ pub async fn test_rust_generic<G: Graph>() -> Result<()> {
     let libraries = graph.find_nodes_by_type(NodeType::Library);
     nodes_count += libraries.len();
 
-    assert_eq!(libraries.len(), 9, "Expected 9 library nodes");
+    // 9 from Cargo.toml, and the other 319 packages of Cargo.lock
+    assert_eq!(libraries.len(), 328, "Expected 328 library nodes");
+
+    let library = |name: &str| {
+        libraries
+            .iter()
+            .find(|l| l.name == name)
+            .map(|n| Node::new(NodeType::Library, n.clone()))
+            .unwrap_or_else(|| panic!("{} library not found", name))
+    };
+    let axum = library("axum");
+    assert_eq!(axum.node_data.file, "src/testing/rust/Cargo.toml");
+    assert_eq!(axum.node_data.meta.get("resolved_version"), Some(&"0.6.20".to_string()));
+    assert_eq!(axum.node_data.meta.get("direct"), Some(&"true".to_string()));
+    let axum_core = library("axum-core");
+    assert_eq!(axum_core.node_data.file, "src/testing/rust/Cargo.lock");
+    assert_eq!(axum_core.node_data.meta.get("version"), Some(&"0.3.4".to_string()));
+    assert_eq!(axum_core.node_data.meta.get("direct"), Some(&"false".to_string()));
+    let hyper = library("hyper");
+    assert_eq!(hyper.node_data.meta.get("resolved_version"), Some(&"0.14.32".to_string()));
 
     let main_import_body = format!(
         r#"use crate::db::init_db;
@@ -192,7 +211,13 @@ use std::net::SocketAddr;"#
 
     let contains_edges = graph.count_edges_of_type(EdgeType::Contains);
     edges_count += contains_edges;
-    assert_eq!(contains_edges, 76, "Expected 76 contains edges");
+    assert_eq!(contains_edges, 395, "Expected 395 contains edges");
+
+    let depends_on = graph.count_edges_of_type(EdgeType::DependsOn);
+    edges_count += depends_on;
+    assert_eq!(depends_on, 906, "Expected 906 depends_on edges");
+    assert!(graph.has_edge(&axum, &axum_core, EdgeType::DependsOn));
+    assert!(graph.has_edge(&axum, &hyper, EdgeType::DependsOn));
 
     let functions = graph.find_nodes_by_type(NodeType::Function);
     nodes_count += functions.len();
@@ -365,3 +390,140 @@ async fn test_rust() {
         test_rust_generic::<Neo4jGraph>().await.unwrap();
     }
 }
+
+pub async fn test_rust_tests_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/rust_tests",
+        Lang::from_str("rust").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let libraries = graph.find_nodes_by_type(NodeType::Library);
+    assert_eq!(libraries.len(), 3, "Expected 3 libraries");
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 6, "Expected 6 functions");
+    assert!(
+        functions.iter().all(|f| f.name != "creates_an_item"
+            && f.name != "rejects_blank_names"
+            && f.name != "health_returns_ok"),
+        "Tests should not be collected as functions"
+    );
+    assert!(
+        functions
+            .iter()
+            .any(|f| f.name == "item_body" && f.file.ends_with("tests/api.rs")),
+        "Helpers in tests/ should stay functions"
+    );
+
+    let unit_tests = graph.find_nodes_by_type(NodeType::UnitTest);
+    assert_eq!(unit_tests.len(), 2, "Expected 2 unit tests");
+    assert!(
+        unit_tests.iter().all(|t| t.file.ends_with("src/store.rs")),
+        "Expected the unit tests to come from the cfg(test) module in store.rs"
+    );
+    let next_id_test = unit_tests
+        .iter()
+        .find(|t| t.name == "next_id_follows_the_highest_id")
+        .expect("Unit test 'next_id_follows_the_highest_id' not found");
+    assert!(
+        next_id_test.body.starts_with("fn next_id_follows_the_highest_id()"),
+        "Test body should start at the function, got {}",
+        next_id_test.body
+    );
+
+    let integration_tests = graph.find_nodes_by_type(NodeType::IntegrationTest);
+    assert_eq!(integration_tests.len(), 4, "Expected 4 integration tests");
+    let mut integration_names: Vec<&str> =
+        integration_tests.iter().map(|t| t.name.as_str()).collect();
+    integration_names.sort();
+    assert_eq!(
+        integration_names,
+        vec![
+            "creates_an_item",
+            "health_path_is_stable",
+            "health_returns_ok",
+            "rejects_a_blank_item"
+        ],
+        "Integration tests mismatch"
+    );
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 2, "Expected 2 endpoints");
+
+    let health_endpoint = endpoints
+        .iter()
+        .find(|e| e.name == "/health" && e.meta.get("verb") == Some(&"GET".to_string()))
+        .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+        .expect("GET /health endpoint not found");
+    let create_item_endpoint = endpoints
+        .iter()
+        .find(|e| e.name == "/items" && e.meta.get("verb") == Some(&"POST".to_string()))
+        .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+        .expect("POST /items endpoint not found");
+
+    let integration_test = |name: &str| {
+        integration_tests
+            .iter()
+            .find(|t| t.name == name)
+            .map(|n| Node::new(NodeType::IntegrationTest, n.clone()))
+            .expect("Integration test not found")
+    };
+
+    assert!(
+        graph.has_edge(
+            &integration_test("health_returns_ok"),
+            &health_endpoint,
+            EdgeType::Calls
+        ),
+        "Expected 'health_returns_ok' to call GET /health"
+    );
+    assert!(
+        graph.has_edge(
+            &integration_test("creates_an_item"),
+            &create_item_endpoint,
+            EdgeType::Calls
+        ),
+        "Expected 'creates_an_item' to call POST /items"
+    );
+    assert!(
+        graph.has_edge(
+            &integration_test("rejects_a_blank_item"),
+            &create_item_endpoint,
+            EdgeType::Calls
+        ),
+        "Expected 'rejects_a_blank_item' to call POST /items"
+    );
+    assert!(
+        !graph.has_edge(
+            &integration_test("health_path_is_stable"),
+            &health_endpoint,
+            EdgeType::Calls
+        ),
+        "'health_path_is_stable' sends no request"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_rust_tests() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_rust_tests_generic::<ArrayGraph>().await.unwrap();
+    test_rust_tests_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_rust_tests_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
@@ -0,0 +1,42 @@
+use axum::{
+    extract::Json,
+    http::StatusCode,
+    routing::{get, post},
+    Router,
+};
+
+use crate::store::{validate_name, Item};
+
+pub fn app() -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/items", post(create_item))
+}
+
+async fn health() -> &'static str {
+    "ok"
+}
+
+async fn create_item(Json(item): Json<Item>) -> StatusCode {
+    if validate_name(&item.name) {
+        StatusCode::CREATED
+    } else {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn health_returns_ok() {
+        let response = app()
+            .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Item {
+    pub id: u32,
+    pub name: String,
+}
+
+pub fn validate_name(name: &str) -> bool {
+    !name.trim().is_empty() && name.len() <= 64
+}
+
+pub fn next_id(items: &[Item]) -> u32 {
+    items.iter().map(|item| item.id).max().unwrap_or(0) + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_blank_names() {
+        assert!(!validate_name("   "));
+    }
+
+    #[test]
+    fn next_id_follows_the_highest_id() {
+        let items = vec![
+            Item { id: 4, name: "lamp".to_string() },
+            Item { id: 2, name: "desk".to_string() },
+        ];
+        assert_eq!(next_id(&items), 5);
+    }
+}
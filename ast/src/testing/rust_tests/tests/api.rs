@@ -0,0 +1,40 @@
+use axum::{
+    body::Body,
+    http::{Method, Request, StatusCode},
+};
+use rust_tests::routes::app;
+use tower::ServiceExt;
+
+fn item_body(name: &str) -> Body {
+    Body::from(format!(r#"{{"id":1,"name":"{}"}}"#, name))
+}
+
+#[tokio::test]
+async fn creates_an_item() {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/items")
+        .header("content-type", "application/json")
+        .body(item_body("lamp"))
+        .unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+#[ignore]
+async fn rejects_a_blank_item() {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/items")
+        .header("content-type", "application/json")
+        .body(item_body("  "))
+        .unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[test]
+fn health_path_is_stable() {
+    assert_eq!("/health".len(), 7);
+}
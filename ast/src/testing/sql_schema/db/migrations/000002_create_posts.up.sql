@@ -0,0 +1,8 @@
+CREATE TABLE posts (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    title VARCHAR(200) NOT NULL,
+    body TEXT
+);
+
+CREATE INDEX idx_posts_user_id ON posts (user_id);
@@ -0,0 +1,11 @@
+ALTER TABLE posts ADD COLUMN published_at TIMESTAMP;
+
+CREATE TABLE comments (
+    id BIGSERIAL,
+    post_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    body TEXT NOT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT fk_comments_post FOREIGN KEY (post_id) REFERENCES posts (id),
+    CONSTRAINT fk_comments_user FOREIGN KEY (user_id) REFERENCES users (id)
+);
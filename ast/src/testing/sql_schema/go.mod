@@ -0,0 +1,5 @@
+module sqlschemademo
+
+go 1.22
+
+require gorm.io/gorm v1.25.10
@@ -0,0 +1,110 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_sql_schema_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/sql_schema",
+        Lang::from_str("go").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let data_models = graph.find_nodes_by_type(NodeType::DataModel);
+    assert_eq!(data_models.len(), 6, "Expected 6 data models");
+
+    let table = |name: &str| {
+        data_models
+            .iter()
+            .find(|dm| dm.name == name && dm.meta.get("table") == Some(&"true".to_string()))
+            .map(|n| Node::new(NodeType::DataModel, n.clone()))
+            .unwrap_or_else(|| panic!("{} table not found", name))
+    };
+    let model = |name: &str| {
+        data_models
+            .iter()
+            .find(|dm| dm.name == name && dm.file.ends_with("models.go"))
+            .map(|n| Node::new(NodeType::DataModel, n.clone()))
+            .unwrap_or_else(|| panic!("{} model not found", name))
+    };
+
+    let users = table("users");
+    assert_eq!(
+        users.node_data.file,
+        "src/testing/sql_schema/db/migrations/000001_create_users.up.sql"
+    );
+    assert_eq!(
+        users.node_data.meta.get("columns"),
+        Some(&"id bigserial;email varchar(255);name text;created_at timestamp".to_string())
+    );
+    assert_eq!(
+        users.node_data.meta.get("indexes"),
+        Some(&"idx_users_email(email) unique".to_string())
+    );
+
+    // the column added by a later migration, and nothing from the down migrations
+    let posts = table("posts");
+    assert_eq!(
+        posts.node_data.meta.get("columns"),
+        Some(&"id bigserial;user_id bigint;title varchar(200);body text;published_at timestamp".to_string())
+    );
+    assert_eq!(
+        posts.node_data.meta.get("foreign_keys"),
+        Some(&"user_id -> users.id".to_string())
+    );
+
+    let comments = table("comments");
+    assert_eq!(comments.node_data.meta.get("primary_key"), Some(&"id".to_string()));
+    assert_eq!(
+        comments.node_data.meta.get("foreign_keys"),
+        Some(&"post_id -> posts.id;user_id -> users.id".to_string())
+    );
+
+    let references = graph.count_edges_of_type(EdgeType::References);
+    assert_eq!(references, 5, "Expected 5 references edges");
+    assert!(
+        graph.has_edge(&posts, &users, EdgeType::References),
+        "Expected posts to reference users"
+    );
+    assert!(
+        graph.has_edge(&comments, &posts, EdgeType::References),
+        "Expected comments to reference posts"
+    );
+    assert!(
+        graph.has_edge(&comments, &users, EdgeType::References),
+        "Expected comments to reference users"
+    );
+    assert!(
+        graph.has_edge(&model("User"), &users, EdgeType::References),
+        "Expected the User model to map to the users table"
+    );
+    assert!(
+        graph.has_edge(&model("Post"), &posts, EdgeType::References),
+        "Expected the Post model to map to the posts table"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_sql_schema() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_sql_schema_generic::<ArrayGraph>().await.unwrap();
+    test_sql_schema_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_sql_schema_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
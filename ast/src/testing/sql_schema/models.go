@@ -0,0 +1,32 @@
+package main
+
+import (
+	"time"
+
+	"gorm.io/gorm"
+)
+
+type User struct {
+	ID        uint
+	Email     string `gorm:"uniqueIndex"`
+	Name      string
+	CreatedAt time.Time
+}
+
+type Post struct {
+	ID          uint
+	UserID      uint
+	Title       string
+	Body        string
+	PublishedAt *time.Time
+}
+
+type Tag struct {
+	Name string
+}
+
+func LatestPosts(db *gorm.DB) ([]Post, error) {
+	var posts []Post
+	err := db.Order("published_at desc").Limit(10).Find(&posts).Error
+	return posts, err
+}
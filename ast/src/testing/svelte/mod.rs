@@ -1,5 +1,5 @@
-use crate::lang::graphs::NodeType;
-use crate::lang::Graph;
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
 use crate::{lang::Lang, repo::Repo};
 use shared::error::Result;
 use std::str::FromStr;
@@ -20,8 +20,8 @@ pub async fn test_svelte_generic<G: Graph>() -> Result<()> {
 
     graph.analysis();
 
-    assert_eq!(num_nodes, 56, "Expected 56 nodes");
-    assert_eq!(num_edges, 55, "Expected 55 edges");
+    assert_eq!(num_nodes, 61, "Expected 61 nodes");
+    assert_eq!(num_edges, 59, "Expected 59 edges");
 
     let language_nodes = graph.find_nodes_by_type(NodeType::Language);
     assert_eq!(language_nodes.len(), 1, "Expected 1 language node");
@@ -45,7 +45,7 @@ pub async fn test_svelte_generic<G: Graph>() -> Result<()> {
     assert_eq!(classes[0].body, "", "Class body should be empty");
 
     let functions = graph.find_nodes_by_type(NodeType::Function);
-    assert_eq!(functions.len(), 6, "Expected 6 functions");
+    assert_eq!(functions.len(), 8, "Expected 8 functions");
 
     let mut sorted_functions = functions.clone();
     sorted_functions.sort_by(|a, b| a.name.cmp(&b.name));
@@ -56,6 +56,29 @@ pub async fn test_svelte_generic<G: Graph>() -> Result<()> {
         "Expected 'addPerson' function not found"
     );
 
+    let pages = graph.find_nodes_by_type(NodeType::Page);
+    assert_eq!(pages.len(), 1, "Expected 1 page");
+    assert_eq!(pages[0].name, "/", "Page route should be '/'");
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 2, "Expected 2 endpoints");
+    for verb in ["GET", "POST"] {
+        let endpoint = endpoints
+            .iter()
+            .find(|e| e.meta.get("verb") == Some(&verb.to_string()))
+            .expect("endpoint not found");
+        assert_eq!(endpoint.name, "/api/people");
+        let handler = functions
+            .iter()
+            .find(|f| f.name == verb && f.file.ends_with("+server.js"))
+            .expect("handler not found");
+        assert!(graph.has_edge(
+            &Node::new(NodeType::Endpoint, endpoint.clone()),
+            &Node::new(NodeType::Function, handler.clone()),
+            EdgeType::Handler
+        ));
+    }
+
     let data_models = graph.find_nodes_by_type(NodeType::DataModel);
     assert_eq!(data_models.len(), 17, "Expected 17 data models");
 
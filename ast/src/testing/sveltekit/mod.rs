@@ -0,0 +1,89 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::error::Result;
+use std::str::FromStr;
+use test_log::test;
+
+pub async fn test_sveltekit_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/sveltekit",
+        Lang::from_str("svelte").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let pages = graph.find_nodes_by_type(NodeType::Page);
+    assert_eq!(pages.len(), 4, "Expected 4 pages");
+    let page = |route: &str, file: &str| {
+        pages
+            .iter()
+            .find(|p| p.name == route && p.file.ends_with(file))
+            .map(|p| Node::new(NodeType::Page, p.clone()))
+            .unwrap_or_else(|| panic!("{} page not found", route))
+    };
+    let home = page("/", "src/routes/+page.svelte");
+    let layout = page("/", "src/routes/+layout.svelte");
+    let blog_post = page("/blog/[slug]", "src/routes/blog/[slug]/+page.svelte");
+    page("/about", "src/routes/(marketing)/about/+page.svelte");
+    assert_eq!(layout.node_data.meta.get("layout"), Some(&"true".to_string()));
+    assert!(home.node_data.meta.get("layout").is_none());
+
+    let function = |name: &str, file: &str| {
+        graph
+            .find_nodes_by_name(NodeType::Function, name)
+            .into_iter()
+            .find(|f| f.file.ends_with(file))
+            .map(|f| Node::new(NodeType::Function, f))
+            .unwrap_or_else(|| panic!("{} function not found in {}", name, file))
+    };
+    let post_load = function("load", "src/routes/blog/[slug]/+page.ts");
+    let layout_load = function("load", "src/routes/+layout.server.ts");
+    assert!(
+        graph.has_edge(&blog_post, &post_load, EdgeType::Calls),
+        "Expected the blog post page to call its load function"
+    );
+    assert!(
+        graph.has_edge(&layout, &layout_load, EdgeType::Calls),
+        "Expected the layout to call its load function"
+    );
+    assert!(!graph.has_edge(&home, &layout_load, EdgeType::Calls));
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 2, "Expected 2 endpoints");
+    let endpoint = |verb: &str| {
+        endpoints
+            .iter()
+            .find(|e| e.name == "/api/posts/[id]" && e.meta.get("verb") == Some(&verb.to_string()))
+            .map(|e| Node::new(NodeType::Endpoint, e.clone()))
+            .unwrap_or_else(|| panic!("{} /api/posts/[id] endpoint not found", verb))
+    };
+    let get_post = endpoint("GET");
+    let delete_post = endpoint("DELETE");
+    let server = "src/routes/api/posts/[id]/+server.ts";
+    assert_eq!(get_post.node_data.start, 4);
+    assert!(graph.has_edge(&get_post, &function("GET", server), EdgeType::Handler));
+    assert!(graph.has_edge(&delete_post, &function("DELETE", server), EdgeType::Handler));
+    assert!(
+        graph.has_edge(&function("GET", server), &function("findPost", server), EdgeType::Calls),
+        "Expected the GET handler to call the module's helper"
+    );
+
+    let handlers = graph.count_edges_of_type(EdgeType::Handler);
+    assert_eq!(handlers, 2, "Expected 2 handler edges");
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn test_sveltekit() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_sveltekit_generic::<ArrayGraph>().await.unwrap();
+    test_sveltekit_generic::<BTreeMapGraph>().await.unwrap();
+}
@@ -0,0 +1,10 @@
+export interface Post {
+	slug: string;
+	title: string;
+	body: string;
+}
+
+export const posts: Post[] = [
+	{ slug: 'hello', title: 'Hello', body: 'First post' },
+	{ slug: 'again', title: 'Again', body: 'Second post' }
+];
@@ -0,0 +1,5 @@
+import type { LayoutServerLoad } from './$types';
+
+export async function load({ locals }) {
+	return { user: locals.user ?? null };
+}
@@ -0,0 +1,21 @@
+import { error, json } from '@sveltejs/kit';
+import { posts } from '$lib/posts';
+import type { RequestHandler } from './$types';
+
+export async function GET({ params }) {
+	const post = findPost(params.id);
+	if (!post) {
+		error(404, 'Not found');
+	}
+	return json(post);
+}
+
+export const DELETE: RequestHandler = async ({ params }) => {
+	const index = posts.findIndex((p) => p.slug === params.id);
+	posts.splice(index, 1);
+	return new Response(null, { status: 204 });
+};
+
+function findPost(slug: string) {
+	return posts.find((p) => p.slug === slug);
+}
@@ -0,0 +1,10 @@
+import { error } from '@sveltejs/kit';
+import type { PageLoad } from './$types';
+
+export const load: PageLoad = async ({ params, fetch }) => {
+	const res = await fetch(`/api/posts/${params.slug}`);
+	if (!res.ok) {
+		error(404, 'Not found');
+	}
+	return { post: await res.json() };
+};
@@ -0,0 +1,8 @@
+import { expect, test } from "@playwright/test";
+
+test.describe("items page", () => {
+  test("shows the created item", async ({ page }) => {
+    await page.goto("http://localhost:3000/items");
+    await expect(page.getByText("lamp")).toBeVisible();
+  });
+});
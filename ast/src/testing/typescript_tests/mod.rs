@@ -0,0 +1,84 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::Result;
+use std::str::FromStr;
+
+pub async fn test_typescript_tests_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/typescript_tests",
+        Lang::from_str("ts").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 3, "Expected 3 functions");
+
+    let unit_tests = graph.find_nodes_by_type(NodeType::UnitTest);
+    assert_eq!(unit_tests.len(), 2, "Expected 2 unit tests");
+    let mut unit_names: Vec<&str> = unit_tests.iter().map(|t| t.name.as_str()).collect();
+    unit_names.sort();
+    assert_eq!(unit_names, vec!["keeps inner spaces", "normalizeName"]);
+
+    let integration_tests = graph.find_nodes_by_type(NodeType::IntegrationTest);
+    assert_eq!(integration_tests.len(), 1, "Expected 1 integration test");
+    let items_api = integration_tests
+        .iter()
+        .find(|t| t.name == "items api" && t.file.ends_with("test/items.api.test.ts"))
+        .map(|n| Node::new(NodeType::IntegrationTest, n.clone()))
+        .expect("Integration test 'items api' not found");
+
+    let e2e_tests = graph.find_nodes_by_type(NodeType::E2eTest);
+    assert_eq!(e2e_tests.len(), 1, "Expected 1 e2e test");
+    assert_eq!(e2e_tests[0].name, "items page");
+
+    let endpoints = graph.find_nodes_by_type(NodeType::Endpoint);
+    assert_eq!(endpoints.len(), 2, "Expected 2 endpoints");
+
+    let list_items_endpoint = endpoints
+        .iter()
+        .find(|e| e.name == "/items" && e.meta.get("verb") == Some(&"GET".to_string()))
+        .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+        .expect("GET /items endpoint not found");
+    let create_item_endpoint = endpoints
+        .iter()
+        .find(|e| e.name == "/items" && e.meta.get("verb") == Some(&"POST".to_string()))
+        .map(|n| Node::new(NodeType::Endpoint, n.clone()))
+        .expect("POST /items endpoint not found");
+
+    assert!(
+        graph.has_edge(&items_api, &list_items_endpoint, EdgeType::Calls),
+        "Expected supertest get('/items') to call GET /items"
+    );
+    assert!(
+        graph.has_edge(&items_api, &create_item_endpoint, EdgeType::Calls),
+        "Expected supertest post('/items') to call POST /items"
+    );
+
+    let requests = graph.find_nodes_by_type(NodeType::Request);
+    assert_eq!(requests.len(), 0, "Supertest calls should not become Request nodes");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_typescript_tests() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_typescript_tests_generic::<ArrayGraph>().await.unwrap();
+    test_typescript_tests_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_typescript_tests_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
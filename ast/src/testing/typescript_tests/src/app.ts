@@ -0,0 +1,8 @@
+import express from "express";
+import { createItem, listItems } from "./items";
+
+export const app = express();
+app.use(express.json());
+
+app.get("/items", listItems);
+app.post("/items", createItem);
@@ -0,0 +1,6 @@
+import { expect, test } from "vitest";
+import { normalizeName } from "./items";
+
+test("keeps inner spaces", () => {
+  expect(normalizeName(" Desk Lamp ")).toBe("desk lamp");
+});
@@ -0,0 +1,7 @@
+import { normalizeName } from "./items";
+
+describe("normalizeName", () => {
+  it("trims and lowercases", () => {
+    expect(normalizeName("  Lamp ")).toBe("lamp");
+  });
+});
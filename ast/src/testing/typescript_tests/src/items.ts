@@ -0,0 +1,22 @@
+import { Request, Response } from "express";
+
+export interface Item {
+  id: number;
+  name: string;
+}
+
+const items: Item[] = [];
+
+export function normalizeName(name: string): string {
+  return name.trim().toLowerCase();
+}
+
+export function listItems(req: Request, res: Response) {
+  res.json(items);
+}
+
+export function createItem(req: Request, res: Response) {
+  const item: Item = { id: items.length + 1, name: normalizeName(req.body.name) };
+  items.push(item);
+  res.status(201).json(item);
+}
@@ -0,0 +1,13 @@
+import request from "supertest";
+import { app } from "../src/app";
+
+describe("items api", () => {
+  it("creates an item", async () => {
+    const res = await request(app).post("/items").send({ name: "Lamp" });
+    expect(res.status).toBe(201);
+  });
+
+  it("lists items", async () => {
+    await request(app).get("/items").expect(200);
+  });
+});
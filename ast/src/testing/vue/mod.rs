@@ -0,0 +1,190 @@
+use crate::lang::graphs::{EdgeType, NodeType};
+use crate::lang::{Graph, Node};
+use crate::{lang::Lang, repo::Repo};
+use shared::error::Result;
+use std::str::FromStr;
+
+pub async fn test_vue_generic<G: Graph>() -> Result<()> {
+    let repo = Repo::new(
+        "src/testing/vue",
+        Lang::from_str("vue").unwrap(),
+        false,
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+
+    let graph = repo.build_graph_inner::<G>().await?;
+
+    graph.analysis();
+
+    let language_nodes = graph.find_nodes_by_name(NodeType::Language, "vue");
+    assert_eq!(language_nodes.len(), 1, "Expected 1 language node");
+
+    let files = graph.find_nodes_by_type(NodeType::File);
+    assert_eq!(files.len(), 10, "Expected 10 files");
+
+    let libraries = graph.find_nodes_by_type(NodeType::Library);
+    assert_eq!(libraries.len(), 6, "Expected 6 libraries");
+    assert!(
+        libraries.iter().any(|l| l.name == "vue-router"),
+        "Expected vue-router library"
+    );
+
+    let imports = graph.find_nodes_by_type(NodeType::Import);
+    assert_eq!(imports.len(), 8, "Expected 8 imports");
+
+    let variables = graph.find_nodes_by_type(NodeType::Var);
+    assert_eq!(variables.len(), 1, "Expected 1 variable");
+    assert_eq!(variables[0].name, "routes");
+
+    let data_models = graph.find_nodes_by_type(NodeType::DataModel);
+    assert_eq!(data_models.len(), 2, "Expected 2 data models");
+
+    let functions = graph.find_nodes_by_type(NodeType::Function);
+    assert_eq!(functions.len(), 11, "Expected 11 functions");
+
+    // every .vue file is a component named after its file
+    for (name, file) in [
+        ("App", "src/testing/vue/src/App.vue"),
+        ("HomeView", "src/testing/vue/src/views/HomeView.vue"),
+        ("UserList", "src/testing/vue/src/views/UserList.vue"),
+        ("UserDetail", "src/testing/vue/src/views/UserDetail.vue"),
+        ("UserCard", "src/testing/vue/src/components/UserCard.vue"),
+    ] {
+        let component = functions
+            .iter()
+            .find(|f| f.name == name)
+            .unwrap_or_else(|| panic!("{} component not found", name));
+        assert_eq!(component.file, file, "{} component file is incorrect", name);
+        assert_eq!(
+            component.meta.get("component"),
+            Some(&"true".to_string()),
+            "{} should be marked as a component",
+            name
+        );
+    }
+
+    let load_stats = functions
+        .iter()
+        .find(|f| f.name == "loadStats")
+        .expect("loadStats function not found");
+    assert!(
+        load_stats
+            .docs
+            .as_ref()
+            .map(|d| d.contains("Load the user count"))
+            .unwrap_or(false),
+        "loadStats docs are incorrect"
+    );
+
+    let pages = graph.find_nodes_by_type(NodeType::Page);
+    assert_eq!(pages.len(), 3, "Expected 3 pages");
+
+    let renders = graph.count_edges_of_type(EdgeType::Renders);
+    assert_eq!(renders, 3, "Expected 3 renders edges");
+
+    for (path, component) in [
+        ("/", "HomeView"),
+        ("/users", "UserList"),
+        ("/users/:id", "UserDetail"),
+    ] {
+        let page = pages
+            .iter()
+            .find(|p| p.name == path)
+            .map(|p| Node::new(NodeType::Page, p.clone()))
+            .unwrap_or_else(|| panic!("{} page not found", path));
+        let comp = functions
+            .iter()
+            .find(|f| f.name == component)
+            .map(|f| Node::new(NodeType::Function, f.clone()))
+            .unwrap();
+        assert!(
+            graph.has_edge(&page, &comp, EdgeType::Renders),
+            "Expected page {} to render {}",
+            path,
+            component
+        );
+    }
+
+    let requests = graph.find_nodes_by_type(NodeType::Request);
+    assert_eq!(requests.len(), 5, "Expected 5 requests");
+    for (name, verb) in [
+        ("/api/stats", "GET"),
+        ("/api/users", "GET"),
+        ("/api/users", "POST"),
+        ("/api/users/${id}", "GET"),
+        ("/api/users/${route.params.id}", "DELETE"),
+    ] {
+        assert!(
+            requests
+                .iter()
+                .any(|r| r.name == name && r.meta.get("verb") == Some(&verb.to_string())),
+            "Expected {} {} request",
+            verb,
+            name
+        );
+    }
+
+    let add_user = functions
+        .iter()
+        .find(|f| f.name == "addUser")
+        .map(|f| Node::new(NodeType::Function, f.clone()))
+        .expect("addUser method not found");
+    let create_user = functions
+        .iter()
+        .find(|f| f.name == "createUser")
+        .map(|f| Node::new(NodeType::Function, f.clone()))
+        .expect("createUser function not found");
+    assert!(
+        graph.has_edge(&add_user, &create_user, EdgeType::Calls),
+        "Expected addUser to call createUser"
+    );
+
+    let user_detail = functions
+        .iter()
+        .find(|f| f.name == "UserDetail")
+        .map(|f| Node::new(NodeType::Function, f.clone()))
+        .unwrap();
+    let load_user = functions
+        .iter()
+        .find(|f| f.name == "loadUser")
+        .map(|f| Node::new(NodeType::Function, f.clone()))
+        .expect("loadUser function not found");
+    assert!(
+        graph.has_edge(&user_detail, &load_user, EdgeType::Calls),
+        "Expected UserDetail component to call loadUser"
+    );
+
+    let user_dm = data_models
+        .iter()
+        .find(|dm| dm.name == "User")
+        .map(|dm| Node::new(NodeType::DataModel, dm.clone()))
+        .expect("User data model not found");
+    let user_card = functions
+        .iter()
+        .find(|f| f.name == "UserCard")
+        .map(|f| Node::new(NodeType::Function, f.clone()))
+        .unwrap();
+    assert!(
+        graph.has_edge(&user_card, &user_dm, EdgeType::Contains),
+        "Expected UserCard component to use the User data model"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_vue() {
+    use crate::lang::graphs::{ArrayGraph, BTreeMapGraph};
+    test_vue_generic::<ArrayGraph>().await.unwrap();
+    test_vue_generic::<BTreeMapGraph>().await.unwrap();
+
+    #[cfg(feature = "neo4j")]
+    {
+        use crate::lang::graphs::Neo4jGraph;
+        let graph = Neo4jGraph::default();
+        graph.clear().await.unwrap();
+        test_vue_generic::<Neo4jGraph>().await.unwrap();
+    }
+}
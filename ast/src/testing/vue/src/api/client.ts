@@ -0,0 +1,7 @@
+import axios from "axios";
+import { NewUser, User } from "../types/user";
+
+export async function createUser(user: NewUser): Promise<User> {
+  const res = await axios.post("/api/users", user);
+  return res.data;
+}
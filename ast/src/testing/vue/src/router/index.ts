@@ -0,0 +1,20 @@
+import { createRouter, createWebHistory, RouteRecordRaw } from "vue-router";
+import HomeView from "../views/HomeView.vue";
+import UserList from "../views/UserList.vue";
+
+const routes: RouteRecordRaw[] = [
+  { path: "/", name: "home", component: HomeView },
+  { path: "/users", name: "users", component: UserList },
+  {
+    path: "/users/:id",
+    name: "user-detail",
+    component: () => import("../views/UserDetail.vue"),
+  },
+];
+
+const router = createRouter({
+  history: createWebHistory(),
+  routes,
+});
+
+export default router;
@@ -18,9 +18,13 @@ pub enum Language {
     Svelte,
     Angular,
     Cpp,
+    C,
+    CSharp,
+    Php,
+    Vue,
 }
 
-pub const PROGRAMMING_LANGUAGES: [Language; 12] = [
+pub const PROGRAMMING_LANGUAGES: [Language; 17] = [
     Language::Rust,
     Language::Go,
     Language::Typescript,
@@ -33,13 +37,18 @@ pub const PROGRAMMING_LANGUAGES: [Language; 12] = [
     Language::Svelte,
     Language::Angular,
     Language::Cpp,
+    Language::C,
+    Language::CSharp,
+    Language::Php,
+    Language::Vue,
+    Language::Bash,
 ];
 
 impl Language {
     pub fn is_frontend(&self) -> bool {
         matches!(
             self,
-            Self::Typescript | Self::React | Self::Kotlin | Self::Swift
+            Self::Typescript | Self::React | Self::Vue | Self::Kotlin | Self::Swift
         )
     }
     pub fn pkg_files(&self) -> Vec<&'static str> {
@@ -57,6 +66,10 @@ impl Language {
             Self::Svelte => vec!["package.json"],
             Self::Angular => vec!["package.json"],
             Self::Cpp => vec!["CMakeLists.txt"],
+            Self::C => vec!["Makefile", "CMakeLists.txt", "meson.build"],
+            Self::CSharp => vec![".csproj", ".sln"],
+            Self::Php => vec!["composer.json"],
+            Self::Vue => vec!["package.json"],
         }
     }
 
@@ -69,7 +82,7 @@ impl Language {
             Self::Kotlin => vec!["kt", "kts", "java"],
             Self::Swift => vec!["swift", "plist"],
             Self::Java => vec!["java", "gradle", "gradlew"],
-            Self::Bash => vec!["sh"],
+            Self::Bash => vec!["sh", "bash"],
             Self::Toml => vec!["toml"],
             // how to separate ts and js?
             Self::Typescript => vec!["ts", "js"],
@@ -77,6 +90,10 @@ impl Language {
             Self::Svelte => vec!["svelte", "ts", "js", "html", "css"],
             Self::Angular => vec!["ts", "js", "html", "css"],
             Self::Cpp => vec!["cpp", "h"],
+            Self::C => vec!["c", "h"],
+            Self::CSharp => vec!["cs"],
+            Self::Php => vec!["php"],
+            Self::Vue => vec!["vue", "ts", "js", "html", "css"],
         }
     }
 
@@ -86,6 +103,7 @@ impl Language {
             Self::React => vec![Self::Typescript, Self::Svelte, Self::Angular],
             Self::Svelte => vec![Self::Typescript],
             Self::Angular => vec![Self::Typescript],
+            Self::Vue => vec![Self::Typescript, Self::React, Self::Svelte, Self::Angular],
             _ => Vec::new(),
         }
     }
@@ -105,6 +123,10 @@ impl Language {
             Self::Svelte => vec![".git", " node_modules"],
             Self::Angular => vec![".git", " node_modules"],
             Self::Cpp => vec![".git", "build", "out", "CMakeFiles"],
+            Self::C => vec![".git", "build", "out", "CMakeFiles"],
+            Self::CSharp => vec![".git", "obj", ".vs"],
+            Self::Php => vec![".git", "vendor"],
+            Self::Vue => vec![".git", "node_modules"],
         }
     }
 
@@ -113,6 +135,7 @@ impl Language {
             Self::Typescript | Self::React => vec![".min.js"],
             Self::Svelte => vec![".config.ts", ".config.ts"],
             Self::Angular => vec!["spec.ts"],
+            Self::Vue => vec![".min.js"],
             Self::Kotlin => vec!["gradlew"],
             _ => Vec::new(),
         }
@@ -133,6 +156,10 @@ impl Language {
             Self::Svelte => Vec::new(),
             Self::Angular => Vec::new(),
             Self::Cpp => Vec::new(),
+            Self::C => Vec::new(),
+            Self::CSharp => Vec::new(),
+            Self::Php => Vec::new(),
+            Self::Vue => Vec::new(),
         }
     }
 
@@ -157,6 +184,10 @@ impl Language {
             Self::Svelte => false,
             Self::Angular => false,
             Self::Cpp => false,
+            Self::C => false,
+            Self::CSharp => false,
+            Self::Php => false,
+            Self::Vue => false,
         }
     }
 
@@ -175,6 +206,10 @@ impl Language {
             Self::Svelte => "svelte-language-server",
             Self::Angular => "angular-language-server",
             Self::Cpp => "",
+            Self::C => "clangd",
+            Self::CSharp => "csharp-ls",
+            Self::Php => "intelephense",
+            Self::Vue => "vue-language-server",
         }
         .to_string()
     }
@@ -194,6 +229,10 @@ impl Language {
             Self::Svelte => "--version",
             Self::Angular => "--version",
             Self::Cpp => "--version",
+            Self::C => "--version",
+            Self::CSharp => "--version",
+            Self::Php => "--version",
+            Self::Vue => "--version",
         }
         .to_string()
     }
@@ -213,6 +252,10 @@ impl Language {
             Self::Svelte => Vec::new(),
             Self::Angular => Vec::new(),
             Self::Cpp => Vec::new(),
+            Self::C => Vec::new(),
+            Self::CSharp => Vec::new(),
+            Self::Php => vec!["--stdio".to_string()],
+            Self::Vue => vec!["--stdio".to_string()],
         }
     }
 
@@ -232,6 +275,10 @@ impl Language {
             Self::Svelte => "svelte",
             Self::Angular => "angular",
             Self::Cpp => "cpp",
+            Self::C => "c",
+            Self::CSharp => "csharp",
+            Self::Php => "php",
+            Self::Vue => "vue",
         }
         .to_string()
     }
@@ -268,12 +315,16 @@ impl Language {
             Self::Svelte => Vec::new(),
             Self::Angular => Vec::new(),
             Self::Cpp => Vec::new(),
+            Self::C => Vec::new(),
+            Self::CSharp => Vec::new(),
+            Self::Php => Vec::new(),
+            Self::Vue => Vec::new(),
         }
     }
 
     pub fn test_id_regex(&self) -> Option<&'static str> {
         match self {
-            Self::Typescript | Self::React => {
+            Self::Typescript | Self::React | Self::Vue => {
                 Some(r#"data-testid=(?:["']([^"']+)["']|\{['"`]([^'"`]+)['"`]\})"#)
             }
             Self::Python => Some("get_by_test_id"),
@@ -359,6 +410,19 @@ impl FromStr for Language {
             "Cpp" => Ok(Language::Cpp),
             "c++" => Ok(Language::Cpp),
             "C++" => Ok(Language::Cpp),
+            "c" => Ok(Language::C),
+            "C" => Ok(Language::C),
+            "csharp" => Ok(Language::CSharp),
+            "CSharp" => Ok(Language::CSharp),
+            "c#" => Ok(Language::CSharp),
+            "C#" => Ok(Language::CSharp),
+            "php" => Ok(Language::Php),
+            "Php" => Ok(Language::Php),
+            "PHP" => Ok(Language::Php),
+            "laravel" => Ok(Language::Php),
+            "Laravel" => Ok(Language::Php),
+            "vue" => Ok(Language::Vue),
+            "Vue" => Ok(Language::Vue),
 
             _ => Err(Error::Custom(format!("unsupported language"))),
         }
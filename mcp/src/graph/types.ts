@@ -50,6 +50,9 @@ export type NodeType =
   | "Datamodel"
   | "Page"
   | "Var"
+  | "Service"
+  | "Job"
+  | "Queue"
   | "Message"
   | "Person"
   | "Video"
@@ -141,6 +144,9 @@ export function all_node_types(): NodeType[] {
     "Datamodel",
     "Page",
     "Var",
+    "Service",
+    "Job",
+    "Queue",
     "Message",
     "Person",
     "Video",
@@ -193,6 +199,11 @@ export function node_type_descriptions(): { [k in NodeType]: string } {
       "A structured representation of data within a system, typically defining entities, relationships, attribute types, and corresponding SQL table definitions.",
     Page: "A webpage or route within an application, representing a specific view or section of the system. It can serve as the starting point for a codemap.",
     Var: "A variable in source code, representing a value that can be used in the code.",
+    Service:
+      "A deployable service from docker-compose or a Dockerfile, with its image, ports and environment variables.",
+    Job:
+      "A background job or worker, like an ActiveJob or Sidekiq class, with the queue it runs on.",
+    Queue: "A named queue that background jobs are enqueued on.",
     Message:
       "A message in a conversation between developers, projects managers, or other stakeholders.",
     Person: "A person working on the project.",
@@ -249,6 +260,9 @@ export enum Language {
   Swift = "swift",
   Java = "java",
   Cpp = "cpp",
+  C = "c",
+  CSharp = "csharp",
+  Php = "php",
 }
 
 export const LANGUAGE_PACKAGE_FILES: Record<Language, string[]> = {
@@ -261,6 +275,9 @@ export const LANGUAGE_PACKAGE_FILES: Record<Language, string[]> = {
   [Language.Swift]: ["Podfile", "Cartfile"],
   [Language.Java]: ["pom.xml"],
   [Language.Cpp]: ["CMakeLists.txt"],
+  [Language.C]: ["Makefile", "CMakeLists.txt", "meson.build"],
+  [Language.CSharp]: [".csproj", ".sln"],
+  [Language.Php]: ["composer.json"],
 };
 
 export const EXTENSIONS: Record<Language, string[]> = {
@@ -273,6 +290,9 @@ export const EXTENSIONS: Record<Language, string[]> = {
   [Language.Swift]: ["swift", "plist"],
   [Language.Java]: ["java", "gradle", "gradlew"],
   [Language.Cpp]: ["cpp", "h"],
+  [Language.C]: ["c", "h"],
+  [Language.CSharp]: ["cs"],
+  [Language.Php]: ["php"],
 };
 
 export const LANGUAGE_ENV_REGEX: Record<Language, RegExp> = {
@@ -285,6 +305,9 @@ export const LANGUAGE_ENV_REGEX: Record<Language, RegExp> = {
   [Language.Swift]: /ProcessInfo\.processInfo\.environment\["([^"]+)"\]/g,
   [Language.Java]: /System\.getProperty\("([^"]+)"\)/g,
   [Language.Cpp]: /getenv\("([^"]+)"\)/g,
+  [Language.C]: /getenv\("([^"]+)"\)/g,
+  [Language.CSharp]: /Environment\.GetEnvironmentVariable\("([^"]+)"\)/g,
+  [Language.Php]: /(?:env|getenv)\(['"]([^'"]+)['"]/g,
 };
 
 export const IGNORE_DIRECTORIES = [